@@ -1,6 +1,6 @@
 use std::time::Duration;
 
-use exc_core::{transport::http::endpoint::Endpoint as HttpEndpoint, Exc};
+use exc_core::{transport::http::endpoint::Endpoint as HttpEndpoint, weight::WeightLimits, Exc};
 use tower::{buffer::Buffer, ready_cache::ReadyCache, util::Either, ServiceBuilder};
 
 use crate::{
@@ -21,6 +21,7 @@ pub struct Endpoint {
     pub(crate) key: Option<BinanceKey>,
     pub(crate) http: (RestEndpoint, HttpEndpoint),
     pub(crate) ws: WsEndpoint,
+    weight_limits: Option<WeightLimits>,
     buffer: usize,
 }
 
@@ -31,6 +32,7 @@ impl Endpoint {
             key: None,
             http: (RestEndpoint::UsdMarginFutures, HttpEndpoint::default()),
             ws: BinanceWebsocketApi::usd_margin_futures(),
+            weight_limits: None,
             buffer: CAP,
         }
     }
@@ -41,6 +43,7 @@ impl Endpoint {
             key: None,
             http: (RestEndpoint::Spot(options), HttpEndpoint::default()),
             ws: BinanceWebsocketApi::spot(),
+            weight_limits: None,
             buffer: CAP,
         }
     }
@@ -51,6 +54,7 @@ impl Endpoint {
             key: None,
             http: (RestEndpoint::EuropeanOptions, HttpEndpoint::default()),
             ws: BinanceWebsocketApi::european_options(),
+            weight_limits: None,
             buffer: CAP,
         }
     }
@@ -91,6 +95,18 @@ impl Endpoint {
         self
     }
 
+    /// Limit http requests with the default weight limits of the endpoint.
+    pub fn http_weight_limited(&mut self) -> &mut Self {
+        self.weight_limits = Some(self.http.0.weight_limits());
+        self
+    }
+
+    /// Set the weight limits of http requests.
+    pub fn http_weight_limits(&mut self, limits: WeightLimits) -> &mut Self {
+        self.weight_limits = Some(limits);
+        self
+    }
+
     /// Private mode.
     pub fn private(&mut self, key: BinanceKey) -> &mut Self {
         self.key = Some(key);
@@ -114,15 +130,18 @@ impl Endpoint {
         if let Some(key) = self.key.as_ref() {
             layer = layer.key(key.clone());
         }
+        if let Some(limits) = self.weight_limits.as_ref() {
+            layer = layer.weight_limits(limits.clone());
+        }
         let http = ServiceBuilder::default()
             .layer(layer)
             .service(self.http.1.connect_https());
-        let ws = if self.key.is_some() {
-            let private = http.clone();
-            self.ws.clone().private(private).connect()
-        } else {
-            self.ws.connect()
-        };
+        let mut ws = self.ws.clone();
+        ws.rest(http.clone());
+        if self.key.is_some() {
+            ws.private(http.clone());
+        }
+        let ws = ws.connect();
         let mut svcs = ReadyCache::default();
         svcs.push(HTTP_KEY, Either::A(http));
         svcs.push(WS_KEY, Either::B(ws));
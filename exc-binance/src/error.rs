@@ -29,6 +29,7 @@ impl From<Error> for ExchangeError {
             Error::Rest(err) => match err {
                 RestError::Http(_) | RestError::Hyper(_) => Self::Unavailable(err.into()),
                 RestError::Exchange(err) => err,
+                RestError::UnsupportedEndpoint(err) => Self::Unsupported(err),
                 _ => Self::Other(err.into()),
             },
             Error::Ws(err) => match &err {
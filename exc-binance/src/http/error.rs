@@ -46,6 +46,9 @@ pub enum RestError {
     /// Place Zero size.
     #[error("trying to place an order with zero size")]
     PlaceZeroSize,
+    /// Amend without both new size and new price.
+    #[error("both new size and new price are required to amend an order")]
+    IncompleteAmend,
     /// Parse Symbol Error.
     #[error("parse symbol error: {0}")]
     ParseSymbol(#[from] exc_core::ParseSymbolError),
@@ -1,4 +1,6 @@
 use exc_core::retry::RetryPolicy;
+use exc_core::weight::{WeightLimiter, WeightLimits};
+use exc_core::ExchangeError;
 use futures::future::BoxFuture;
 use futures::{FutureExt, TryFutureExt};
 use tower::retry::Retry;
@@ -6,6 +8,7 @@ use tower::retry::Retry;
 use crate::types::key::BinanceKey;
 
 use super::error::RestError;
+use super::limit;
 use super::request::{Payload, Rest, RestEndpoint, RestRequest};
 use super::response::{Data, RestResponse};
 use std::task::{Context, Poll};
@@ -19,6 +22,7 @@ pub struct BinanceRestApiLayer {
     retry: Policy,
     endpoint: RestEndpoint,
     key: Option<BinanceKey>,
+    limiter: Option<WeightLimiter>,
 }
 
 impl BinanceRestApiLayer {
@@ -33,6 +37,7 @@ impl BinanceRestApiLayer {
             endpoint,
             retry: RetryPolicy::default().retry_on(RestError::is_temporary),
             key: None,
+            limiter: None,
         }
     }
 
@@ -41,6 +46,14 @@ impl BinanceRestApiLayer {
         self.key = Some(key);
         self
     }
+
+    /// Wait for the budget of the weight limits before sending each request.
+    ///
+    /// The services created by this layer share the same counters.
+    pub fn weight_limits(mut self, limits: WeightLimits) -> Self {
+        self.limiter = Some(WeightLimiter::new(limits));
+        self
+    }
 }
 
 impl<S> Layer<S> for BinanceRestApiLayer {
@@ -53,6 +66,7 @@ impl<S> Layer<S> for BinanceRestApiLayer {
                 http,
                 endpoint: self.endpoint,
                 key: self.key.clone(),
+                limiter: self.limiter.clone(),
             });
         BinanceRestApi { inner }
     }
@@ -64,12 +78,14 @@ pub struct BinanceRestApiInner<S> {
     endpoint: RestEndpoint,
     http: S,
     key: Option<BinanceKey>,
+    limiter: Option<WeightLimiter>,
 }
 
 impl<S, R> Service<RestRequest<R>> for BinanceRestApiInner<S>
 where
     R: Rest,
     S: Service<http::Request<hyper::Body>, Response = http::Response<hyper::Body>>,
+    S: Clone + Send + 'static,
     S::Future: Send + 'static,
     S::Error: 'static,
     RestError: From<S::Error>,
@@ -83,14 +99,33 @@ where
     }
 
     fn call(&mut self, req: RestRequest<R>) -> Self::Future {
+        let costs = req.costs(&self.endpoint);
         match req.to_http(&self.endpoint, self.key.as_ref()) {
             Ok(req) => {
-                tracing::trace!("sent http request: {}", req.uri());
-                self.http
-                    .call(req)
-                    .map_err(RestError::from)
-                    .and_then(RestResponse::from_http)
-                    .boxed()
+                let Some(limiter) = self.limiter.clone() else {
+                    tracing::trace!("sent http request: {}", req.uri());
+                    return self
+                        .http
+                        .call(req)
+                        .map_err(RestError::from)
+                        .and_then(RestResponse::from_http)
+                        .boxed();
+                };
+                // Take the service that has been driven to ready.
+                let clone = self.http.clone();
+                let mut http = std::mem::replace(&mut self.http, clone);
+                async move {
+                    limiter.acquire(&costs).await;
+                    tracing::trace!("sent http request: {}", req.uri());
+                    let resp = http.call(req).await?;
+                    limiter.sync(&limit::usage(resp.headers()));
+                    let res = RestResponse::from_http(resp).await;
+                    if let Err(RestError::Exchange(ExchangeError::RateLimited(_))) = &res {
+                        limiter.exhaust(&costs);
+                    }
+                    res
+                }
+                .boxed()
             }
             Err(err) => futures::future::ready(Err(err)).boxed(),
         }
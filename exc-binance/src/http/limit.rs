@@ -0,0 +1,86 @@
+use std::time::Duration;
+
+use exc_core::weight::{Usage, WeightLimits};
+use http::HeaderMap;
+
+use super::request::RestEndpoint;
+
+/// The request weight per minute (per IP).
+pub const WEIGHT_1M: &str = "weight-1m";
+
+/// The number of orders per 10 seconds (per account).
+pub const ORDERS_10S: &str = "orders-10s";
+
+/// The number of orders per minute (per account).
+pub const ORDERS_1M: &str = "orders-1m";
+
+/// The number of orders per day (per account).
+pub const ORDERS_1D: &str = "orders-1d";
+
+const HEADERS: [(&str, &str); 4] = [
+    ("x-mbx-used-weight-1m", WEIGHT_1M),
+    ("x-mbx-order-count-10s", ORDERS_10S),
+    ("x-mbx-order-count-1m", ORDERS_1M),
+    ("x-mbx-order-count-1d", ORDERS_1D),
+];
+
+impl RestEndpoint {
+    /// The default weight limits of the endpoint.
+    pub fn weight_limits(&self) -> WeightLimits {
+        let mut limits = WeightLimits::default();
+        match self {
+            Self::UsdMarginFutures => limits
+                .limit(WEIGHT_1M, 2400, Duration::from_secs(60))
+                .limit(ORDERS_10S, 300, Duration::from_secs(10))
+                .limit(ORDERS_1M, 1200, Duration::from_secs(60)),
+            Self::Spot(_) => limits
+                .limit(WEIGHT_1M, 6000, Duration::from_secs(60))
+                .limit(ORDERS_10S, 100, Duration::from_secs(10))
+                .limit(ORDERS_1D, 200_000, Duration::from_secs(86_400)),
+            Self::EuropeanOptions => limits.limit(WEIGHT_1M, 400, Duration::from_secs(60)),
+        };
+        limits
+    }
+}
+
+/// Read the usage of the limits from the response headers.
+pub(crate) fn usage(headers: &HeaderMap) -> Vec<Usage> {
+    HEADERS
+        .iter()
+        .filter_map(|(name, limit)| {
+            let used = headers.get(*name)?.to_str().ok()?.parse().ok()?;
+            Some(Usage {
+                limit,
+                scope: None,
+                used,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use exc_core::weight::{Cost, WeightLimiter};
+    use http::HeaderValue;
+
+    use super::*;
+
+    #[test]
+    fn test_weight_limit() {
+        let mut headers = HeaderMap::new();
+        headers.insert("X-MBX-USED-WEIGHT-1M", HeaderValue::from_static("2398"));
+        headers.insert("X-MBX-ORDER-COUNT-10S", HeaderValue::from_static("1"));
+        let usage = usage(&headers);
+        assert_eq!(usage.len(), 2);
+
+        let limiter = WeightLimiter::new(RestEndpoint::UsdMarginFutures.weight_limits());
+        let costs = [Cost::new(WEIGHT_1M, 5)];
+        assert!(limiter.try_acquire(&costs).is_ok());
+        limiter.sync(&usage);
+        assert!(limiter.try_acquire(&costs).is_err());
+        // A cost against a limit that is not set is ignored.
+        assert!(limiter.try_acquire(&[Cost::new(ORDERS_1D, 1)]).is_ok());
+        limiter.exhaust(&[Cost::new(ORDERS_1M, 1)]);
+        assert!(limiter.try_acquire(&[Cost::new(ORDERS_1M, 1)]).is_err());
+    }
+}
@@ -258,3 +258,93 @@ impl Rest for GetSubAccountFuturesPositions {
         super::Payload::new(self.clone())
     }
 }
+
+/// Get the balances of the current account.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetBalances {}
+
+impl Rest for GetBalances {
+    fn method(&self, _endpoint: &RestEndpoint) -> Result<http::Method, RestError> {
+        Ok(http::Method::GET)
+    }
+
+    fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
+        match endpoint {
+            RestEndpoint::UsdMarginFutures => Ok("/fapi/v2/balance".to_string()),
+            RestEndpoint::Spot(_options) => Ok("/api/v3/account".to_string()),
+            RestEndpoint::EuropeanOptions => Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                "`GetBalances` is not available on `binance-e`"
+            ))),
+        }
+    }
+
+    fn need_apikey(&self) -> bool {
+        true
+    }
+
+    fn need_sign(&self) -> bool {
+        true
+    }
+
+    fn serialize(&self, _endpoint: &RestEndpoint) -> Result<serde_json::Value, RestError> {
+        Ok(serde_json::to_value(self)?)
+    }
+
+    fn weight(&self, endpoint: &RestEndpoint) -> u64 {
+        match endpoint {
+            RestEndpoint::Spot(_) => 20,
+            _ => 5,
+        }
+    }
+
+    fn to_payload(&self) -> super::Payload {
+        super::Payload::new(self.clone())
+    }
+}
+
+/// Get the positions of the current USD-M futures account.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPositions {
+    /// Symbol.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+}
+
+impl Rest for GetPositions {
+    fn method(&self, _endpoint: &RestEndpoint) -> Result<http::Method, RestError> {
+        Ok(http::Method::GET)
+    }
+
+    fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
+        match endpoint {
+            RestEndpoint::UsdMarginFutures => Ok("/fapi/v2/positionRisk".to_string()),
+            RestEndpoint::Spot(_) | RestEndpoint::EuropeanOptions => {
+                Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                    "`GetPositions` only available on `binance-u`"
+                )))
+            }
+        }
+    }
+
+    fn need_apikey(&self) -> bool {
+        true
+    }
+
+    fn need_sign(&self) -> bool {
+        true
+    }
+
+    fn serialize(&self, _endpoint: &RestEndpoint) -> Result<serde_json::Value, RestError> {
+        Ok(serde_json::to_value(self)?)
+    }
+
+    fn weight(&self, _endpoint: &RestEndpoint) -> u64 {
+        5
+    }
+
+    fn to_payload(&self) -> super::Payload {
+        super::Payload::new(self.clone())
+    }
+}
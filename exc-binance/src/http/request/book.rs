@@ -0,0 +1,51 @@
+use serde::Serialize;
+
+use super::{Rest, RestEndpoint, RestError};
+
+/// Get the order book snapshot.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetDepth {
+    /// Symbol.
+    pub symbol: String,
+    /// Limit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+impl Rest for GetDepth {
+    fn method(&self, _endpoint: &RestEndpoint) -> Result<http::Method, RestError> {
+        Ok(http::Method::GET)
+    }
+
+    fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
+        let qs = serde_urlencoded::to_string(self)?;
+        match endpoint {
+            RestEndpoint::UsdMarginFutures => Ok(format!("/fapi/v1/depth?{qs}")),
+            RestEndpoint::Spot(_) => Ok(format!("/api/v3/depth?{qs}")),
+            RestEndpoint::EuropeanOptions => Ok(format!("/eapi/v1/depth?{qs}")),
+        }
+    }
+
+    fn weight(&self, endpoint: &RestEndpoint) -> u64 {
+        match endpoint {
+            RestEndpoint::UsdMarginFutures => match self.limit.unwrap_or(500) {
+                0..=50 => 2,
+                51..=100 => 5,
+                101..=500 => 10,
+                _ => 20,
+            },
+            RestEndpoint::Spot(_) => match self.limit.unwrap_or(100) {
+                0..=100 => 5,
+                101..=500 => 25,
+                501..=1000 => 50,
+                _ => 250,
+            },
+            RestEndpoint::EuropeanOptions => 1,
+        }
+    }
+
+    fn to_payload(&self) -> super::Payload {
+        super::Payload::new(self.clone())
+    }
+}
@@ -1,3 +1,4 @@
+use exc_core::types::CandleSource;
 use serde::Serialize;
 
 use super::{Rest, RestEndpoint, RestError};
@@ -52,6 +53,29 @@ pub enum Interval {
     Mon1,
 }
 
+impl Interval {
+    /// Interval name used in the api.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::M1 => "1m",
+            Self::M3 => "3m",
+            Self::M5 => "5m",
+            Self::M15 => "15m",
+            Self::M30 => "30m",
+            Self::H1 => "1h",
+            Self::H2 => "2h",
+            Self::H4 => "4h",
+            Self::H6 => "6h",
+            Self::H8 => "8h",
+            Self::H12 => "12h",
+            Self::D1 => "1d",
+            Self::D3 => "3d",
+            Self::W1 => "1w",
+            Self::Mon1 => "1M",
+        }
+    }
+}
+
 /// Query candles.
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -69,6 +93,41 @@ pub struct QueryCandles {
     /// Limit.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<usize>,
+    /// Price source.
+    #[serde(skip)]
+    pub source: CandleSource,
+}
+
+/// Query index price candles, which is named by `pair`.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct QueryIndexCandles<'a> {
+    pair: &'a str,
+    interval: Interval,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<usize>,
+}
+
+impl QueryCandles {
+    fn to_qs(&self) -> Result<String, RestError> {
+        if matches!(self.source, CandleSource::Index) {
+            // The pair of a delivery contract (e.g. `BTCUSDT_230630`) is the part before `_`.
+            let pair = self.symbol.split('_').next().unwrap_or_default();
+            Ok(serde_urlencoded::to_string(QueryIndexCandles {
+                pair,
+                interval: self.interval,
+                start_time: self.start_time,
+                end_time: self.end_time,
+                limit: self.limit,
+            })?)
+        } else {
+            Ok(serde_urlencoded::to_string(self)?)
+        }
+    }
 }
 
 impl Rest for QueryCandles {
@@ -77,11 +136,40 @@ impl Rest for QueryCandles {
     }
 
     fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
-        let qs = serde_urlencoded::to_string(self)?;
+        let qs = self.to_qs()?;
+        match (endpoint, self.source) {
+            (RestEndpoint::UsdMarginFutures, CandleSource::Trade) => {
+                Ok(format!("/fapi/v1/klines?{qs}"))
+            }
+            (RestEndpoint::UsdMarginFutures, CandleSource::Mark) => {
+                Ok(format!("/fapi/v1/markPriceKlines?{qs}"))
+            }
+            (RestEndpoint::UsdMarginFutures, CandleSource::Index) => {
+                Ok(format!("/fapi/v1/indexPriceKlines?{qs}"))
+            }
+            (RestEndpoint::UsdMarginFutures, CandleSource::Premium) => {
+                Ok(format!("/fapi/v1/premiumIndexKlines?{qs}"))
+            }
+            (RestEndpoint::Spot(_), CandleSource::Trade) => Ok(format!("/api/v3/klines?{qs}")),
+            (RestEndpoint::EuropeanOptions, CandleSource::Trade) => {
+                Ok(format!("/eapi/v1/klines?{qs}"))
+            }
+            (_, source) => Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                "`{source}` candles are only available on `binance-u`"
+            ))),
+        }
+    }
+
+    fn weight(&self, endpoint: &RestEndpoint) -> u64 {
         match endpoint {
-            RestEndpoint::UsdMarginFutures => Ok(format!("/fapi/v1/klines?{qs}")),
-            RestEndpoint::Spot(_) => Ok(format!("/api/v3/klines?{qs}")),
-            RestEndpoint::EuropeanOptions => Ok(format!("/eapi/v1/klines?{qs}")),
+            RestEndpoint::UsdMarginFutures => match self.limit.unwrap_or(500) {
+                0..=99 => 1,
+                100..=499 => 2,
+                500..=1000 => 5,
+                _ => 10,
+            },
+            RestEndpoint::Spot(_) => 2,
+            RestEndpoint::EuropeanOptions => 1,
         }
     }
 
@@ -0,0 +1,135 @@
+use serde::Serialize;
+
+use super::{Interval, Rest, RestEndpoint, RestError};
+
+/// Fetch the funding rate history (most recent first in the time range).
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRateHistory {
+    /// Symbol.
+    pub symbol: String,
+    /// Start time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<i64>,
+    /// End time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<i64>,
+    /// Limit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+impl Rest for FundingRateHistory {
+    fn method(&self, _endpoint: &RestEndpoint) -> Result<http::Method, RestError> {
+        Ok(http::Method::GET)
+    }
+
+    fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
+        let qs = serde_urlencoded::to_string(self)?;
+        match endpoint {
+            RestEndpoint::UsdMarginFutures => Ok(format!("/fapi/v1/fundingRate?{qs}")),
+            _ => Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                "only support usd-margin futures"
+            ))),
+        }
+    }
+
+    fn to_payload(&self) -> super::Payload {
+        super::Payload::new(self.clone())
+    }
+}
+
+/// Get the mark price, index price and funding rate.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPremiumIndex {
+    /// Symbol.
+    pub symbol: String,
+}
+
+impl Rest for GetPremiumIndex {
+    fn method(&self, _endpoint: &RestEndpoint) -> Result<http::Method, RestError> {
+        Ok(http::Method::GET)
+    }
+
+    fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
+        let qs = serde_urlencoded::to_string(self)?;
+        match endpoint {
+            RestEndpoint::UsdMarginFutures => Ok(format!("/fapi/v1/premiumIndex?{qs}")),
+            _ => Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                "only support usd-margin futures"
+            ))),
+        }
+    }
+
+    fn to_payload(&self) -> super::Payload {
+        super::Payload::new(self.clone())
+    }
+}
+
+/// Get the current open interest.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetOpenInterest {
+    /// Symbol.
+    pub symbol: String,
+}
+
+impl Rest for GetOpenInterest {
+    fn method(&self, _endpoint: &RestEndpoint) -> Result<http::Method, RestError> {
+        Ok(http::Method::GET)
+    }
+
+    fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
+        let qs = serde_urlencoded::to_string(self)?;
+        match endpoint {
+            RestEndpoint::UsdMarginFutures => Ok(format!("/fapi/v1/openInterest?{qs}")),
+            _ => Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                "only support usd-margin futures"
+            ))),
+        }
+    }
+
+    fn to_payload(&self) -> super::Payload {
+        super::Payload::new(self.clone())
+    }
+}
+
+/// Fetch the open interest statistics (most recent first in the time range).
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterestHistory {
+    /// Symbol.
+    pub symbol: String,
+    /// Period.
+    pub period: Interval,
+    /// Start time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<i64>,
+    /// End time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<i64>,
+    /// Limit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+impl Rest for OpenInterestHistory {
+    fn method(&self, _endpoint: &RestEndpoint) -> Result<http::Method, RestError> {
+        Ok(http::Method::GET)
+    }
+
+    fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
+        let qs = serde_urlencoded::to_string(self)?;
+        match endpoint {
+            RestEndpoint::UsdMarginFutures => Ok(format!("/futures/data/openInterestHist?{qs}")),
+            _ => Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                "only support usd-margin futures"
+            ))),
+        }
+    }
+
+    fn to_payload(&self) -> super::Payload {
+        super::Payload::new(self.clone())
+    }
+}
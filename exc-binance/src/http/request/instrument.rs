@@ -19,6 +19,13 @@ impl Rest for ExchangeInfo {
         }
     }
 
+    fn weight(&self, endpoint: &RestEndpoint) -> u64 {
+        match endpoint {
+            RestEndpoint::Spot(_) => 20,
+            _ => 1,
+        }
+    }
+
     fn to_payload(&self) -> super::Payload {
         super::Payload::new(*self)
     }
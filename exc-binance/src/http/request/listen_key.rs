@@ -29,6 +29,13 @@ impl Rest for CurrentListenKey {
         true
     }
 
+    fn weight(&self, endpoint: &RestEndpoint) -> u64 {
+        match endpoint {
+            RestEndpoint::Spot(_) => 2,
+            _ => 1,
+        }
+    }
+
     fn to_payload(&self) -> super::Payload {
         super::Payload::new(*self)
     }
@@ -70,6 +77,13 @@ impl Rest for DeleteListenKey {
         true
     }
 
+    fn weight(&self, endpoint: &RestEndpoint) -> u64 {
+        match endpoint {
+            RestEndpoint::Spot(_) => 2,
+            _ => 1,
+        }
+    }
+
     fn to_payload(&self) -> super::Payload {
         super::Payload::new(self.clone())
     }
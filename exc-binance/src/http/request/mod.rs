@@ -1,10 +1,14 @@
 use std::fmt;
 
+use exc_core::weight::Cost;
 use http::{HeaderValue, Method, Request};
 
 use crate::types::key::BinanceKey;
 
-use super::error::RestError;
+use super::{
+    error::RestError,
+    limit::{ORDERS_10S, ORDERS_1D, ORDERS_1M, WEIGHT_1M},
+};
 
 /// Utils.
 pub mod utils;
@@ -15,6 +19,9 @@ pub mod instrument;
 /// Candle.
 pub mod candle;
 
+/// Book.
+pub mod book;
+
 /// Listen key.
 pub mod listen_key;
 
@@ -24,12 +31,17 @@ pub mod trading;
 /// Account.
 pub mod account;
 
+/// Derivative.
+pub mod derivative;
+
 pub use self::{
     account::{
-        GetSubAccountAssets, GetSubAccountFutures, GetSubAccountFuturesPositions,
-        GetSubAccountMargin, ListSubAccounts,
+        GetBalances, GetPositions, GetSubAccountAssets, GetSubAccountFutures,
+        GetSubAccountFuturesPositions, GetSubAccountMargin, ListSubAccounts,
     },
+    book::GetDepth,
     candle::{Interval, QueryCandles},
+    derivative::{FundingRateHistory, GetOpenInterest, GetPremiumIndex, OpenInterestHistory},
     instrument::ExchangeInfo,
     listen_key::{CurrentListenKey, DeleteListenKey},
 };
@@ -66,6 +78,16 @@ pub trait Rest: Send + Sync + 'static {
         Ok(serde_json::json!({}))
     }
 
+    /// Request weight.
+    fn weight(&self, _endpoint: &RestEndpoint) -> u64 {
+        1
+    }
+
+    /// The number of orders counted.
+    fn order_count(&self) -> u64 {
+        0
+    }
+
     /// Clone.
     fn to_payload(&self) -> Payload;
 }
@@ -122,6 +144,14 @@ impl Rest for Payload {
         self.inner.serialize(endpoint)
     }
 
+    fn weight(&self, endpoint: &RestEndpoint) -> u64 {
+        self.inner.weight(endpoint)
+    }
+
+    fn order_count(&self) -> u64 {
+        self.inner.order_count()
+    }
+
     fn to_payload(&self) -> Payload {
         self.clone()
     }
@@ -211,6 +241,20 @@ impl RestRequest<Payload> {
 }
 
 impl<T: Rest> RestRequest<T> {
+    /// The costs of the request against the weight limits.
+    pub fn costs(&self, endpoint: &RestEndpoint) -> Vec<Cost> {
+        let mut costs = vec![Cost::new(WEIGHT_1M, self.payload.weight(endpoint))];
+        let orders = self.payload.order_count();
+        if orders > 0 {
+            costs.extend(
+                [ORDERS_10S, ORDERS_1M, ORDERS_1D]
+                    .into_iter()
+                    .map(|limit| Cost::new(limit, orders)),
+            );
+        }
+        costs
+    }
+
     pub(crate) fn to_http(
         &self,
         endpoint: &RestEndpoint,
@@ -297,6 +341,7 @@ mod test {
                 start_time: None,
                 end_time: None,
                 limit: None,
+                source: Default::default(),
             }))
             .await?
             .into_response::<response::Candles>()?;
@@ -86,12 +86,25 @@ impl<'a> TryFrom<&'a types::PlaceOrder> for PlaceOrder {
                 Some(TimeInForce::Gtc),
                 Some(true),
             ),
+            types::OrderKind::StopMarket(_)
+            | types::OrderKind::StopLimit(_, _)
+            | types::OrderKind::TakeProfit(_)
+            | types::OrderKind::TrailingStop(_, _) => {
+                return Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                    "conditional orders are not supported by options"
+                )));
+            }
         };
+        if req.opts.position_side() != types::PositionSide::Net {
+            return Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                "position side is not supported by options"
+            )));
+        }
         Ok(Self {
             symbol: req.opts.instrument().to_uppercase(),
             side,
             order_type,
-            reduce_only: None,
+            reduce_only: req.opts.reduce_only().then_some(true),
             quantity: place.size.abs(),
             price,
             client_order_id: req.opts.client_id().map(|s| s.to_string()),
@@ -106,6 +106,475 @@ impl Rest for PlaceOrder {
         Ok(serde_json::to_value(self.dispatch(endpoint)?)?)
     }
 
+    fn order_count(&self) -> u64 {
+        1
+    }
+
+    fn to_payload(&self) -> super::Payload {
+        super::Payload::new(self.clone())
+    }
+}
+
+/// Place a batch of orders.
+#[derive(Debug, Clone)]
+pub struct PlaceOrders {
+    pub(crate) inner: Vec<exc_core::types::PlaceOrder>,
+}
+
+impl Rest for PlaceOrders {
+    fn method(&self, _endpoint: &RestEndpoint) -> Result<http::Method, RestError> {
+        Ok(http::Method::POST)
+    }
+
+    fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
+        match endpoint {
+            RestEndpoint::UsdMarginFutures => Ok("/fapi/v1/batchOrders".to_string()),
+            _ => Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                "batch orders are only supported by usd-margin futures"
+            ))),
+        }
+    }
+
+    fn need_apikey(&self) -> bool {
+        true
+    }
+
+    fn need_sign(&self) -> bool {
+        true
+    }
+
+    fn serialize(&self, endpoint: &RestEndpoint) -> Result<serde_json::Value, RestError> {
+        match endpoint {
+            RestEndpoint::UsdMarginFutures => {
+                if self.inner.len() > usd_margin_futures::MAX_BATCH_ORDERS {
+                    return Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                        "at most {} orders can be placed in a batch",
+                        usd_margin_futures::MAX_BATCH_ORDERS
+                    )));
+                }
+                let orders = self
+                    .inner
+                    .iter()
+                    .map(usd_margin_futures::PlaceOrder::try_from)
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(serde_json::json!({
+                    "batchOrders": serde_json::to_string(&orders)?,
+                }))
+            }
+            _ => Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                "batch orders are only supported by usd-margin futures"
+            ))),
+        }
+    }
+
+    fn weight(&self, _endpoint: &RestEndpoint) -> u64 {
+        5
+    }
+
+    fn order_count(&self) -> u64 {
+        self.inner.len() as u64
+    }
+
+    fn to_payload(&self) -> super::Payload {
+        super::Payload::new(self.clone())
+    }
+}
+
+/// Cancel a batch of orders of the same symbol.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelOrders {
+    /// Symbol.
+    pub symbol: String,
+    /// Client ids.
+    #[serde(serialize_with = "serialize_as_json")]
+    pub orig_client_order_id_list: Vec<String>,
+}
+
+fn serialize_as_json<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+    T: Serialize,
+{
+    let s = serde_json::to_string(value).map_err(serde::ser::Error::custom)?;
+    serializer.serialize_str(&s)
+}
+
+impl Rest for CancelOrders {
+    fn method(&self, _endpoint: &RestEndpoint) -> Result<http::Method, RestError> {
+        Ok(http::Method::DELETE)
+    }
+
+    fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
+        match endpoint {
+            RestEndpoint::UsdMarginFutures => Ok("/fapi/v1/batchOrders".to_string()),
+            _ => Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                "batch orders are only supported by usd-margin futures"
+            ))),
+        }
+    }
+
+    fn need_apikey(&self) -> bool {
+        true
+    }
+
+    fn need_sign(&self) -> bool {
+        true
+    }
+
+    fn serialize(&self, endpoint: &RestEndpoint) -> Result<serde_json::Value, RestError> {
+        match endpoint {
+            RestEndpoint::UsdMarginFutures => {
+                if self.orig_client_order_id_list.len() > usd_margin_futures::MAX_BATCH_CANCELS {
+                    return Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                        "at most {} orders can be cancelled in a batch",
+                        usd_margin_futures::MAX_BATCH_CANCELS
+                    )));
+                }
+                Ok(serde_json::to_value(self)?)
+            }
+            _ => Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                "batch orders are only supported by usd-margin futures"
+            ))),
+        }
+    }
+
+    fn to_payload(&self) -> super::Payload {
+        super::Payload::new(self.clone())
+    }
+}
+
+/// Cancel all open orders of a symbol.
+#[derive(Debug, Clone, Serialize)]
+pub struct CancelAllOrders {
+    /// Symbol.
+    pub symbol: String,
+}
+
+impl Rest for CancelAllOrders {
+    fn method(&self, _endpoint: &RestEndpoint) -> Result<http::Method, RestError> {
+        Ok(http::Method::DELETE)
+    }
+
+    fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
+        match endpoint {
+            RestEndpoint::UsdMarginFutures => Ok("/fapi/v1/allOpenOrders".to_string()),
+            RestEndpoint::EuropeanOptions => Ok("/eapi/v1/allOpenOrders".to_string()),
+            RestEndpoint::Spot(options) => {
+                if options.margin.is_some() {
+                    Ok("/sapi/v1/margin/openOrders".to_string())
+                } else {
+                    Ok("/api/v3/openOrders".to_string())
+                }
+            }
+        }
+    }
+
+    fn need_apikey(&self) -> bool {
+        true
+    }
+
+    fn need_sign(&self) -> bool {
+        true
+    }
+
+    fn serialize(&self, _endpoint: &RestEndpoint) -> Result<serde_json::Value, RestError> {
+        Ok(serde_json::to_value(self)?)
+    }
+
+    fn to_payload(&self) -> super::Payload {
+        super::Payload::new(self.clone())
+    }
+}
+
+/// Cancel all open orders of a symbol after the countdown.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CountdownCancelAll {
+    /// Symbol.
+    pub symbol: String,
+    /// Countdown time in milliseconds (`0` to cancel the timer).
+    pub countdown_time: u64,
+}
+
+impl Rest for CountdownCancelAll {
+    fn method(&self, _endpoint: &RestEndpoint) -> Result<http::Method, RestError> {
+        Ok(http::Method::POST)
+    }
+
+    fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
+        match endpoint {
+            RestEndpoint::UsdMarginFutures => Ok("/fapi/v1/countdownCancelAll".to_string()),
+            _ => Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                "countdown cancel all is only supported by usd-margin futures"
+            ))),
+        }
+    }
+
+    fn need_apikey(&self) -> bool {
+        true
+    }
+
+    fn need_sign(&self) -> bool {
+        true
+    }
+
+    fn serialize(&self, _endpoint: &RestEndpoint) -> Result<serde_json::Value, RestError> {
+        Ok(serde_json::to_value(self)?)
+    }
+
+    fn weight(&self, _endpoint: &RestEndpoint) -> u64 {
+        10
+    }
+
+    fn to_payload(&self) -> super::Payload {
+        super::Payload::new(self.clone())
+    }
+}
+
+/// Fetch open orders.
+#[derive(Debug, Clone, Serialize)]
+pub struct FetchOpenOrders {
+    /// Symbol (all symbols if `None`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+}
+
+impl Rest for FetchOpenOrders {
+    fn method(&self, _endpoint: &RestEndpoint) -> Result<http::Method, RestError> {
+        Ok(http::Method::GET)
+    }
+
+    fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
+        match endpoint {
+            RestEndpoint::UsdMarginFutures => Ok("/fapi/v1/openOrders".to_string()),
+            RestEndpoint::EuropeanOptions => Ok("/eapi/v1/openOrders".to_string()),
+            RestEndpoint::Spot(options) => {
+                if options.margin.is_some() {
+                    Ok("/sapi/v1/margin/openOrders".to_string())
+                } else {
+                    Ok("/api/v3/openOrders".to_string())
+                }
+            }
+        }
+    }
+
+    fn need_apikey(&self) -> bool {
+        true
+    }
+
+    fn need_sign(&self) -> bool {
+        true
+    }
+
+    fn serialize(&self, _endpoint: &RestEndpoint) -> Result<serde_json::Value, RestError> {
+        Ok(serde_json::to_value(self)?)
+    }
+
+    fn weight(&self, endpoint: &RestEndpoint) -> u64 {
+        match (endpoint, self.symbol.is_some()) {
+            (RestEndpoint::Spot(_), true) => 6,
+            (RestEndpoint::Spot(_), false) => 80,
+            (_, true) => 1,
+            (_, false) => 40,
+        }
+    }
+
+    fn to_payload(&self) -> super::Payload {
+        super::Payload::new(self.clone())
+    }
+}
+
+/// Fetch all orders (most recent first in the time range).
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FetchAllOrders {
+    /// Symbol.
+    pub symbol: String,
+    /// Start time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<i64>,
+    /// End time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<i64>,
+    /// Limit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+impl Rest for FetchAllOrders {
+    fn method(&self, _endpoint: &RestEndpoint) -> Result<http::Method, RestError> {
+        Ok(http::Method::GET)
+    }
+
+    fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
+        match endpoint {
+            RestEndpoint::UsdMarginFutures => Ok("/fapi/v1/allOrders".to_string()),
+            RestEndpoint::EuropeanOptions => Ok("/eapi/v1/historyOrders".to_string()),
+            RestEndpoint::Spot(options) => {
+                if options.margin.is_some() {
+                    Ok("/sapi/v1/margin/allOrders".to_string())
+                } else {
+                    Ok("/api/v3/allOrders".to_string())
+                }
+            }
+        }
+    }
+
+    fn need_apikey(&self) -> bool {
+        true
+    }
+
+    fn need_sign(&self) -> bool {
+        true
+    }
+
+    fn serialize(&self, _endpoint: &RestEndpoint) -> Result<serde_json::Value, RestError> {
+        Ok(serde_json::to_value(self)?)
+    }
+
+    fn weight(&self, endpoint: &RestEndpoint) -> u64 {
+        match endpoint {
+            RestEndpoint::Spot(_) => 20,
+            _ => 5,
+        }
+    }
+
+    fn to_payload(&self) -> super::Payload {
+        super::Payload::new(self.clone())
+    }
+}
+
+/// Fetch the trades of the account (most recent first in the time range).
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserTrades {
+    /// Symbol.
+    pub symbol: String,
+    /// Start time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<i64>,
+    /// End time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<i64>,
+    /// Limit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+impl Rest for UserTrades {
+    fn method(&self, _endpoint: &RestEndpoint) -> Result<http::Method, RestError> {
+        Ok(http::Method::GET)
+    }
+
+    fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
+        match endpoint {
+            RestEndpoint::UsdMarginFutures => Ok("/fapi/v1/userTrades".to_string()),
+            RestEndpoint::Spot(options) => {
+                if options.margin.is_some() {
+                    Ok("/sapi/v1/margin/myTrades".to_string())
+                } else {
+                    Ok("/api/v3/myTrades".to_string())
+                }
+            }
+            RestEndpoint::EuropeanOptions => Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                "user trades are not supported by options"
+            ))),
+        }
+    }
+
+    fn need_apikey(&self) -> bool {
+        true
+    }
+
+    fn need_sign(&self) -> bool {
+        true
+    }
+
+    fn serialize(&self, _endpoint: &RestEndpoint) -> Result<serde_json::Value, RestError> {
+        Ok(serde_json::to_value(self)?)
+    }
+
+    fn weight(&self, endpoint: &RestEndpoint) -> u64 {
+        match endpoint {
+            RestEndpoint::Spot(_) => 20,
+            _ => 5,
+        }
+    }
+
+    fn to_payload(&self) -> super::Payload {
+        super::Payload::new(self.clone())
+    }
+}
+
+/// Amend order.
+#[derive(Debug, Clone)]
+pub struct AmendOrder {
+    pub(crate) inner: exc_core::types::AmendOrder,
+}
+
+/// Amend order kind.
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum AmendOrderKind {
+    /// Usd-Margin futures.
+    UsdMarginFutures(usd_margin_futures::AmendOrder),
+    /// Spot.
+    Spot(spot::CancelReplace),
+}
+
+impl AmendOrder {
+    fn dispatch(&self, endpoint: &RestEndpoint) -> Result<AmendOrderKind, RestError> {
+        match endpoint {
+            RestEndpoint::UsdMarginFutures => Ok(AmendOrderKind::UsdMarginFutures(
+                usd_margin_futures::AmendOrder::try_from(&self.inner)?,
+            )),
+            RestEndpoint::Spot(options) if options.margin.is_none() => Ok(AmendOrderKind::Spot(
+                spot::CancelReplace::try_from(&self.inner)?,
+            )),
+            _ => Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                "amending orders is only supported by usd-margin futures and spot"
+            ))),
+        }
+    }
+}
+
+impl Rest for AmendOrder {
+    fn method(&self, endpoint: &RestEndpoint) -> Result<http::Method, RestError> {
+        match endpoint {
+            RestEndpoint::UsdMarginFutures => Ok(http::Method::PUT),
+            _ => Ok(http::Method::POST),
+        }
+    }
+
+    fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
+        match endpoint {
+            RestEndpoint::UsdMarginFutures => Ok("/fapi/v1/order".to_string()),
+            RestEndpoint::Spot(options) if options.margin.is_none() => {
+                Ok("/api/v3/order/cancelReplace".to_string())
+            }
+            _ => Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                "amending orders is only supported by usd-margin futures and spot"
+            ))),
+        }
+    }
+
+    fn need_apikey(&self) -> bool {
+        true
+    }
+
+    fn need_sign(&self) -> bool {
+        true
+    }
+
+    fn serialize(&self, endpoint: &RestEndpoint) -> Result<serde_json::Value, RestError> {
+        Ok(serde_json::to_value(self.dispatch(endpoint)?)?)
+    }
+
+    fn order_count(&self) -> u64 {
+        1
+    }
+
     fn to_payload(&self) -> super::Payload {
         super::Payload::new(self.clone())
     }
@@ -241,6 +710,13 @@ impl Rest for GetOrder {
         Ok(serde_json::to_value(self.dispatch(endpoint)?)?)
     }
 
+    fn weight(&self, endpoint: &RestEndpoint) -> u64 {
+        match endpoint {
+            RestEndpoint::Spot(_) => 4,
+            _ => 1,
+        }
+    }
+
     fn to_payload(&self) -> super::Payload {
         super::Payload::new(self.clone())
     }
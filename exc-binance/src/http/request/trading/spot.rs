@@ -88,7 +88,20 @@ impl<'a> TryFrom<&'a exc_core::types::PlaceOrder> for PlaceOrder {
                 (OrderType::Limit, Some(price), tif)
             }
             types::OrderKind::PostOnly(price) => (OrderType::LimitMaker, Some(price), None),
+            types::OrderKind::StopMarket(_)
+            | types::OrderKind::StopLimit(_, _)
+            | types::OrderKind::TakeProfit(_)
+            | types::OrderKind::TrailingStop(_, _) => {
+                return Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                    "conditional orders are not supported by spot"
+                )));
+            }
         };
+        if req.opts.reduce_only() || req.opts.position_side() != types::PositionSide::Net {
+            return Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                "reduce-only and position side are not supported by spot"
+            )));
+        }
         Ok(Self {
             symbol: req.opts.instrument().to_uppercase(),
             side,
@@ -103,3 +116,66 @@ impl<'a> TryFrom<&'a exc_core::types::PlaceOrder> for PlaceOrder {
         })
     }
 }
+
+/// Cancel-replace mode.
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CancelReplaceMode {
+    /// Do not place the new order if the cancellation fails.
+    StopOnFailure,
+    /// Place the new order even if the cancellation fails.
+    AllowFailure,
+}
+
+/// Cancel an existing order and place a new order.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelReplace {
+    /// Symbol.
+    pub symbol: String,
+    /// Side.
+    pub side: OrderSide,
+    /// Order type.
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+    /// Cancel-replace mode.
+    pub cancel_replace_mode: CancelReplaceMode,
+    /// Time-In-Force.
+    pub time_in_force: TimeInForce,
+    /// Quantity.
+    pub quantity: Decimal,
+    /// Price.
+    pub price: Decimal,
+    /// Client id of the order to cancel.
+    pub cancel_orig_client_order_id: String,
+    /// New order response type.
+    pub new_order_resp_type: RespType,
+}
+
+impl<'a> TryFrom<&'a types::AmendOrder> for CancelReplace {
+    type Error = RestError;
+
+    fn try_from(req: &'a types::AmendOrder) -> Result<Self, Self::Error> {
+        let (Some(size), Some(price)) = (req.new_size, req.new_price) else {
+            return Err(RestError::IncompleteAmend);
+        };
+        let side = if size.is_zero() {
+            return Err(RestError::PlaceZeroSize);
+        } else if size.is_sign_positive() {
+            OrderSide::Buy
+        } else {
+            OrderSide::Sell
+        };
+        Ok(Self {
+            symbol: req.instrument.to_uppercase(),
+            side,
+            order_type: OrderType::Limit,
+            cancel_replace_mode: CancelReplaceMode::StopOnFailure,
+            time_in_force: TimeInForce::Gtc,
+            quantity: size.abs(),
+            price,
+            cancel_orig_client_order_id: req.id.as_str().to_string(),
+            new_order_resp_type: RespType::Result,
+        })
+    }
+}
@@ -7,11 +7,17 @@ use crate::{
         error::RestError,
         request::{Payload, Rest, RestEndpoint},
     },
-    types::trading::{OrderSide, OrderType, PositionSide, TimeInForce},
+    types::trading::{self, OrderSide, OrderType, PositionSide, TimeInForce},
 };
 
 pub use super::RespType;
 
+/// The maximum number of orders in a batch placement.
+pub const MAX_BATCH_ORDERS: usize = 5;
+
+/// The maximum number of orders in a batch cancellation.
+pub const MAX_BATCH_CANCELS: usize = 10;
+
 /// Place order.
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -75,6 +81,10 @@ impl<'a> TryFrom<&'a types::PlaceOrder> for PlaceOrder {
         } else {
             OrderSide::Sell
         };
+        let mut stop_price = None;
+        let mut working_type = None;
+        let mut activation_price = None;
+        let mut callback_rate = None;
         let (order_type, price, tif) = match place.kind {
             types::OrderKind::Market => (OrderType::Market, None, None),
             types::OrderKind::Limit(price, tif) => {
@@ -88,22 +98,46 @@ impl<'a> TryFrom<&'a types::PlaceOrder> for PlaceOrder {
             types::OrderKind::PostOnly(price) => {
                 (OrderType::Limit, Some(price), Some(TimeInForce::Gtx))
             }
+            types::OrderKind::StopMarket(trigger) => {
+                stop_price = Some(trigger.price);
+                working_type = Some(trading::working_type(trigger.source)?.to_string());
+                (OrderType::StopMarket, None, None)
+            }
+            types::OrderKind::StopLimit(trigger, price) => {
+                stop_price = Some(trigger.price);
+                working_type = Some(trading::working_type(trigger.source)?.to_string());
+                (OrderType::Stop, Some(price), Some(TimeInForce::Gtc))
+            }
+            types::OrderKind::TakeProfit(trigger) => {
+                stop_price = Some(trigger.price);
+                working_type = Some(trading::working_type(trigger.source)?.to_string());
+                (OrderType::TakeProfitMarket, None, None)
+            }
+            types::OrderKind::TrailingStop(activation, rate) => {
+                if let Some(trigger) = activation {
+                    activation_price = Some(trigger.price);
+                    working_type = Some(trading::working_type(trigger.source)?.to_string());
+                }
+                // The callback rate is given in percentage.
+                callback_rate = Some((rate * Decimal::ONE_HUNDRED).normalize());
+                (OrderType::TrailingStopMarket, None, None)
+            }
         };
         Ok(Self {
             symbol: req.opts.instrument().to_uppercase(),
             side,
-            position_side: PositionSide::Both,
+            position_side: req.opts.position_side().into(),
             order_type,
-            reduce_only: None,
+            reduce_only: req.opts.reduce_only().then_some(true),
             quantity: Some(place.size.abs()),
             price,
             new_client_order_id: req.opts.client_id().map(|s| s.to_string()),
-            stop_price: None,
+            stop_price,
             close_position: None,
-            activation_price: None,
-            callback_rate: None,
+            activation_price,
+            callback_rate,
             time_in_force: tif,
-            working_type: None,
+            working_type,
             price_protect: None,
             new_order_resp_type: None,
         })
@@ -140,3 +174,66 @@ impl Rest for PlaceOrder {
         Payload::new(self.clone())
     }
 }
+
+/// Amend order.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AmendOrder {
+    /// Symbol.
+    pub symbol: String,
+    /// Order Id.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<i64>,
+    /// Client Id.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orig_client_order_id: Option<String>,
+    /// Side.
+    pub side: OrderSide,
+    /// Quantity.
+    pub quantity: Decimal,
+    /// Price.
+    pub price: Decimal,
+}
+
+impl<'a> TryFrom<&'a types::AmendOrder> for AmendOrder {
+    type Error = RestError;
+
+    fn try_from(req: &'a types::AmendOrder) -> Result<Self, Self::Error> {
+        let (Some(size), Some(price)) = (req.new_size, req.new_price) else {
+            return Err(RestError::IncompleteAmend);
+        };
+        let side = if size.is_zero() {
+            return Err(RestError::PlaceZeroSize);
+        } else if size.is_sign_positive() {
+            OrderSide::Buy
+        } else {
+            OrderSide::Sell
+        };
+        Ok(Self {
+            symbol: req.instrument.to_uppercase(),
+            order_id: None,
+            orig_client_order_id: Some(req.id.as_str().to_string()),
+            side,
+            quantity: size.abs(),
+            price,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_hedge_mode_place() -> anyhow::Result<()> {
+        let mut opts = types::PlaceOrderOptions::new("btcusdt");
+        opts.with_reduce_only(true)
+            .with_position_side(types::PositionSide::Short);
+        let req = types::Place::with_size(dec!(1)).into_request(&opts);
+        let value = serde_json::to_value(PlaceOrder::try_from(&req)?)?;
+        assert_eq!(value["positionSide"], "SHORT");
+        assert_eq!(value["reduceOnly"], true);
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+use exc_core::Asset;
 use rust_decimal::Decimal;
 use serde::Deserialize;
 
@@ -313,3 +314,117 @@ impl TryFrom<Data> for SubAccountFuturesPositions {
         }
     }
 }
+
+/// Balance of an asset in the USD-M futures account.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesBalance {
+    /// Asset.
+    pub asset: Asset,
+    /// Wallet balance.
+    pub balance: Decimal,
+    /// Cross wallet balance.
+    pub cross_wallet_balance: Decimal,
+    /// Unrealized profit of crossed positions.
+    pub cross_un_pnl: Decimal,
+    /// Available balance.
+    pub available_balance: Decimal,
+    /// Max withdraw amount.
+    pub max_withdraw_amount: Decimal,
+    /// Update time.
+    pub update_time: i64,
+}
+
+/// Balance of an asset in the spot account.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotBalance {
+    /// Asset.
+    pub asset: Asset,
+    /// Free.
+    pub free: Decimal,
+    /// Locked.
+    pub locked: Decimal,
+}
+
+/// Spot account information.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotAccount {
+    /// Account type.
+    pub account_type: String,
+    /// Can trade?
+    pub can_trade: bool,
+    /// Update time.
+    pub update_time: i64,
+    /// Balances.
+    pub balances: Vec<SpotBalance>,
+}
+
+/// Balances of the current account.
+#[derive(Debug, Clone)]
+pub enum Balances {
+    /// USD-M Futures.
+    UsdMarginFutures(Vec<FuturesBalance>),
+    /// Spot.
+    Spot(SpotAccount),
+}
+
+impl TryFrom<Data> for Balances {
+    type Error = RestError;
+
+    fn try_from(value: Data) -> Result<Self, Self::Error> {
+        match value {
+            Data::FuturesBalances(data) => Ok(Self::UsdMarginFutures(data)),
+            Data::SpotAccount(data) => Ok(Self::Spot(data)),
+            // An empty list is always parsed as empty candles.
+            Data::Candles(data) if data.is_empty() => Ok(Self::UsdMarginFutures(Vec::new())),
+            _ => Err(RestError::UnexpectedResponseType(anyhow::anyhow!(
+                "{value:?}"
+            ))),
+        }
+    }
+}
+
+/// Position risk of an USD-M futures position.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionRisk {
+    /// Symbol.
+    pub symbol: String,
+    /// Position amount.
+    pub position_amt: Decimal,
+    /// Entry price.
+    pub entry_price: Decimal,
+    /// Mark price.
+    pub mark_price: Decimal,
+    /// Unrealized profit.
+    pub un_realized_profit: Decimal,
+    /// Liquidation price.
+    pub liquidation_price: Decimal,
+    /// Leverage.
+    pub leverage: Decimal,
+    /// Margin type.
+    pub margin_type: String,
+    /// Isolated margin.
+    pub isolated_margin: Decimal,
+    /// Position side.
+    pub position_side: String,
+    /// Update time.
+    pub update_time: i64,
+}
+
+impl TryFrom<Data> for Vec<PositionRisk> {
+    type Error = RestError;
+
+    fn try_from(value: Data) -> Result<Self, Self::Error> {
+        match value {
+            Data::PositionRisks(data) => Ok(data),
+            // An empty list is always parsed as empty candles.
+            Data::Candles(data) if data.is_empty() => Ok(Vec::new()),
+            _ => Err(RestError::UnexpectedResponseType(anyhow::anyhow!(
+                "{value:?}"
+            ))),
+        }
+    }
+}
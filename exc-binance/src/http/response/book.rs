@@ -0,0 +1,37 @@
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::http::error::RestError;
+
+use super::Data;
+
+/// Order book snapshot.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DepthSnapshot {
+    /// Last update id.
+    pub last_update_id: usize,
+    /// Event time.
+    #[serde(rename = "E")]
+    pub event_timestamp: Option<i64>,
+    /// Transaction time.
+    #[serde(rename = "T")]
+    pub trade_timestamp: Option<i64>,
+    /// Bids.
+    pub bids: Vec<(Decimal, Decimal)>,
+    /// Asks.
+    pub asks: Vec<(Decimal, Decimal)>,
+}
+
+impl TryFrom<Data> for DepthSnapshot {
+    type Error = RestError;
+
+    fn try_from(value: Data) -> Result<Self, Self::Error> {
+        match value {
+            Data::DepthSnapshot(snapshot) => Ok(snapshot),
+            _ => Err(RestError::UnexpectedResponseType(anyhow::anyhow!(
+                "{value:?}"
+            ))),
+        }
+    }
+}
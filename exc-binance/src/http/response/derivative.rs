@@ -0,0 +1,131 @@
+use exc_core::ExchangeError;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::http::error::RestError;
+
+use super::Data;
+
+/// Funding rate record.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRateRecord {
+    /// Symbol.
+    pub symbol: String,
+    /// Funding rate.
+    pub funding_rate: Decimal,
+    /// Funding time.
+    pub funding_time: i64,
+}
+
+impl TryFrom<Data> for Vec<FundingRateRecord> {
+    type Error = RestError;
+
+    fn try_from(value: Data) -> Result<Self, Self::Error> {
+        match value {
+            Data::FundingRates(rates) => Ok(rates),
+            // An empty list is always parsed as empty candles.
+            Data::Candles(data) if data.is_empty() => Ok(Vec::new()),
+            Data::Error(msg) => Err(RestError::Exchange(ExchangeError::Api(anyhow::anyhow!(
+                "{msg:?}"
+            )))),
+            _ => Err(RestError::UnexpectedResponseType(anyhow::anyhow!(
+                "{value:?}"
+            ))),
+        }
+    }
+}
+
+/// Premium index.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PremiumIndex {
+    /// Symbol.
+    pub symbol: String,
+    /// Mark price.
+    pub mark_price: Decimal,
+    /// Index price.
+    pub index_price: Decimal,
+    /// Last funding rate (empty for delivery contracts).
+    pub last_funding_rate: String,
+    /// Next funding time.
+    pub next_funding_time: i64,
+    /// Time.
+    pub time: i64,
+}
+
+impl TryFrom<Data> for PremiumIndex {
+    type Error = RestError;
+
+    fn try_from(value: Data) -> Result<Self, Self::Error> {
+        match value {
+            Data::PremiumIndex(index) => Ok(index),
+            Data::Error(msg) => Err(RestError::Exchange(ExchangeError::Api(anyhow::anyhow!(
+                "{msg:?}"
+            )))),
+            _ => Err(RestError::UnexpectedResponseType(anyhow::anyhow!(
+                "{value:?}"
+            ))),
+        }
+    }
+}
+
+/// Open interest.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterest {
+    /// Symbol.
+    pub symbol: String,
+    /// Open interest.
+    pub open_interest: Decimal,
+    /// Time.
+    pub time: i64,
+}
+
+impl TryFrom<Data> for OpenInterest {
+    type Error = RestError;
+
+    fn try_from(value: Data) -> Result<Self, Self::Error> {
+        match value {
+            Data::OpenInterest(oi) => Ok(oi),
+            Data::Error(msg) => Err(RestError::Exchange(ExchangeError::Api(anyhow::anyhow!(
+                "{msg:?}"
+            )))),
+            _ => Err(RestError::UnexpectedResponseType(anyhow::anyhow!(
+                "{value:?}"
+            ))),
+        }
+    }
+}
+
+/// Open interest statistics record.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterestRecord {
+    /// Symbol.
+    pub symbol: String,
+    /// Total open interest.
+    pub sum_open_interest: Decimal,
+    /// Total open interest value.
+    pub sum_open_interest_value: Decimal,
+    /// Timestamp.
+    pub timestamp: i64,
+}
+
+impl TryFrom<Data> for Vec<OpenInterestRecord> {
+    type Error = RestError;
+
+    fn try_from(value: Data) -> Result<Self, Self::Error> {
+        match value {
+            Data::OpenInterests(records) => Ok(records),
+            // An empty list is always parsed as empty candles.
+            Data::Candles(data) if data.is_empty() => Ok(Vec::new()),
+            Data::Error(msg) => Err(RestError::Exchange(ExchangeError::Api(anyhow::anyhow!(
+                "{msg:?}"
+            )))),
+            _ => Err(RestError::UnexpectedResponseType(anyhow::anyhow!(
+                "{value:?}"
+            ))),
+        }
+    }
+}
@@ -176,7 +176,7 @@ impl UFSymbol {
                     let date = self
                         .symbol
                         .split('_')
-                        .last()
+                        .next_back()
                         .ok_or(RestError::MissingDateForFutures)?;
                     ExcSymbol::futures_with_str(&self.base_asset, &self.quote_asset, date)
                         .ok_or(RestError::FailedToBuildExcSymbol)
@@ -11,6 +11,9 @@ pub mod instrument;
 /// Candle.
 pub mod candle;
 
+/// Book.
+pub mod book;
+
 /// Listen key.
 pub mod listen_key;
 
@@ -23,12 +26,17 @@ pub mod trading;
 /// Account.
 pub mod account;
 
+/// Derivative.
+pub mod derivative;
+
 pub use self::{
     account::{
-        SubAccountBalances, SubAccountFutures, SubAccountFuturesPositions, SubAccountMargin,
-        SubAccounts,
+        Balances, FuturesBalance, PositionRisk, SpotAccount, SpotBalance, SubAccountBalances,
+        SubAccountFutures, SubAccountFuturesPositions, SubAccountMargin, SubAccounts,
     },
+    book::DepthSnapshot,
     candle::Candle,
+    derivative::{FundingRateRecord, OpenInterest, OpenInterestRecord, PremiumIndex},
     error_message::ErrorMessage,
     instrument::{ExchangeInfo, SpotExchangeInfo, UFExchangeInfo},
     listen_key::ListenKey,
@@ -58,6 +66,28 @@ pub enum Data {
     Error(ErrorMessage),
     /// Order.
     Order(Order),
+    /// Spot cancel-replace.
+    CancelReplace(trading::CancelReplace),
+    /// Spot account.
+    SpotAccount(SpotAccount),
+    /// USD-M Futures balances.
+    FuturesBalances(Vec<FuturesBalance>),
+    /// USD-M Futures position risks.
+    PositionRisks(Vec<PositionRisk>),
+    /// Batch orders.
+    BatchOrders(Vec<trading::BatchOrder>),
+    /// Trades of the account.
+    UserTrades(Vec<trading::UserTrade>),
+    /// Funding rate history.
+    FundingRates(Vec<FundingRateRecord>),
+    /// Open interest history.
+    OpenInterests(Vec<OpenInterestRecord>),
+    /// Order book snapshot.
+    DepthSnapshot(DepthSnapshot),
+    /// Premium index.
+    PremiumIndex(PremiumIndex),
+    /// Open interest.
+    OpenInterest(OpenInterest),
     /// Sub-accounts.
     SubAccounts(SubAccounts),
     /// Sub-account balances.
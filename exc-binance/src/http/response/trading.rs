@@ -8,7 +8,7 @@ use crate::{
     types::trading::{OrderSide, OrderType, PositionSide, Status, TimeInForce},
 };
 
-use super::Data;
+use super::{Data, ErrorMessage};
 
 /// Order.
 #[derive(Debug, Clone, Deserialize)]
@@ -63,6 +63,15 @@ impl Order {
             Self::EuropeanOptions(order) => order.state.as_ref().map(|s| s.update_time),
         }
     }
+
+    /// Get created time.
+    pub fn created(&self) -> Option<i64> {
+        match self {
+            Self::UsdMarginFutures(order) => order.time,
+            Self::Spot(order) => order.ack.time,
+            Self::EuropeanOptions(order) => order.state.as_ref().map(|s| s.create_time),
+        }
+    }
 }
 
 impl TryFrom<Data> for Order {
@@ -71,6 +80,14 @@ impl TryFrom<Data> for Order {
     fn try_from(value: Data) -> Result<Self, Self::Error> {
         match value {
             Data::Order(order) => Ok(order),
+            Data::CancelReplace(replace) => {
+                replace.new_order_response.map(Order::Spot).ok_or_else(|| {
+                    RestError::Exchange(ExchangeError::Api(anyhow::anyhow!(
+                        "the new order is not placed: {:?}",
+                        replace.new_order_result
+                    )))
+                })
+            }
             Data::Error(msg) => match msg.code {
                 -2013 => Err(RestError::Exchange(ExchangeError::OrderNotFound)),
                 _ => Err(RestError::Exchange(ExchangeError::Api(anyhow::anyhow!(
@@ -84,6 +101,110 @@ impl TryFrom<Data> for Order {
     }
 }
 
+/// Acknowledgement of a request without useful data.
+#[derive(Debug, Clone, Copy)]
+pub struct Ack;
+
+impl TryFrom<Data> for Ack {
+    type Error = RestError;
+
+    fn try_from(value: Data) -> Result<Self, Self::Error> {
+        match value {
+            // USD-M futures use this to report success.
+            Data::Error(msg) if msg.code == 200 => Ok(Self),
+            Data::Error(msg) => Err(RestError::Exchange(ExchangeError::Api(anyhow::anyhow!(
+                "{msg:?}"
+            )))),
+            _ => Ok(Self),
+        }
+    }
+}
+
+/// Result of an order in a batch.
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum BatchOrder {
+    /// Order.
+    Order(Order),
+    /// Error.
+    Error(ErrorMessage),
+}
+
+impl BatchOrder {
+    /// Convert into an order.
+    pub fn into_order(self) -> Result<Order, RestError> {
+        match self {
+            Self::Order(order) => Ok(order),
+            Self::Error(msg) => Order::try_from(Data::Error(msg)),
+        }
+    }
+}
+
+impl TryFrom<Data> for Vec<BatchOrder> {
+    type Error = RestError;
+
+    fn try_from(value: Data) -> Result<Self, Self::Error> {
+        match value {
+            Data::BatchOrders(orders) => Ok(orders),
+            // An empty list is always parsed as empty candles.
+            Data::Candles(data) if data.is_empty() => Ok(Vec::new()),
+            Data::Error(msg) => Err(RestError::Exchange(ExchangeError::Api(anyhow::anyhow!(
+                "{msg:?}"
+            )))),
+            _ => Err(RestError::UnexpectedResponseType(anyhow::anyhow!(
+                "{value:?}"
+            ))),
+        }
+    }
+}
+
+/// Trade of the account.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserTrade {
+    /// Symbol.
+    pub symbol: String,
+    /// Trade id.
+    pub id: i64,
+    /// Order id.
+    pub order_id: i64,
+    /// Price.
+    pub price: Decimal,
+    /// Size.
+    pub qty: Decimal,
+    /// Fee.
+    pub commission: Decimal,
+    /// Fee asset.
+    pub commission_asset: Asset,
+    /// Timestamp.
+    pub time: i64,
+    /// Is buyer.
+    #[serde(alias = "isBuyer")]
+    pub buyer: bool,
+    /// Is maker.
+    #[serde(alias = "isMaker")]
+    pub maker: bool,
+}
+
+impl TryFrom<Data> for Vec<UserTrade> {
+    type Error = RestError;
+
+    fn try_from(value: Data) -> Result<Self, Self::Error> {
+        match value {
+            Data::UserTrades(trades) => Ok(trades),
+            // An empty list is always parsed as empty candles.
+            Data::Candles(data) if data.is_empty() => Ok(Vec::new()),
+            Data::Error(msg) => Err(RestError::Exchange(ExchangeError::Api(anyhow::anyhow!(
+                "{msg:?}"
+            )))),
+            _ => Err(RestError::UnexpectedResponseType(anyhow::anyhow!(
+                "{value:?}"
+            ))),
+        }
+    }
+}
+
 /// Usd-Margin Futures Order.
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -123,12 +244,18 @@ pub struct UsdMarginFuturesOrder {
     /// Order type.
     #[serde(rename = "type")]
     pub order_type: OrderType,
+    /// Original order type.
+    #[serde(default)]
+    pub orig_type: Option<OrderType>,
     /// Active price.
     pub activate_price: Option<Decimal>,
     /// Price rate.
     pub price_rate: Option<Decimal>,
     /// Update timestamp.
     pub update_time: i64,
+    /// Creation timestamp (only available in queries).
+    #[serde(default)]
+    pub time: Option<i64>,
     /// Working type.
     pub working_type: String,
     /// Price protect.
@@ -152,6 +279,9 @@ pub struct SpotAck {
     /// Update timestamp.
     #[serde(alias = "updateTime")]
     pub transact_time: Option<i64>,
+    /// Creation timestamp (only available in queries).
+    #[serde(default)]
+    pub time: Option<i64>,
 }
 
 impl SpotAck {
@@ -204,6 +334,31 @@ pub struct SpotOrder {
     pub fills: Vec<SpotFill>,
 }
 
+/// Cancel-replace result.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CancelReplaceResult {
+    /// Success.
+    Success,
+    /// Failure.
+    Failure,
+    /// Not attempted.
+    NotAttempted,
+}
+
+/// Spot cancel-replace response.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelReplace {
+    /// Cancel result.
+    pub cancel_result: CancelReplaceResult,
+    /// New order result.
+    pub new_order_result: CancelReplaceResult,
+    /// New order.
+    #[serde(default)]
+    pub new_order_response: Option<SpotOrder>,
+}
+
 /// Spot fill.
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -258,7 +413,6 @@ pub struct OptionsOrder {
 #[serde(rename_all = "camelCase")]
 pub struct OptionsOrderState {
     /// Create time.
-    #[allow(unused)]
     pub(crate) create_time: i64,
     /// Update time.
     pub(crate) update_time: i64,
@@ -275,3 +429,195 @@ pub struct OptionsOrderState {
     /// Status.
     pub(crate) status: Status,
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_spot_cancel_replace() -> anyhow::Result<()> {
+        let json = r#"{
+            "cancelResult": "SUCCESS",
+            "newOrderResult": "SUCCESS",
+            "cancelResponse": {
+                "symbol": "BTCUSDT",
+                "origClientOrderId": "old",
+                "orderId": 9,
+                "orderListId": -1,
+                "clientOrderId": "cancel",
+                "price": "0.01000000",
+                "origQty": "0.000100",
+                "executedQty": "0.00000000",
+                "cummulativeQuoteQty": "0.00000000",
+                "status": "CANCELED",
+                "timeInForce": "GTC",
+                "type": "LIMIT",
+                "side": "SELL"
+            },
+            "newOrderResponse": {
+                "symbol": "BTCUSDT",
+                "orderId": 10,
+                "orderListId": -1,
+                "clientOrderId": "new",
+                "transactTime": 1652928801803,
+                "price": "0.02000000",
+                "origQty": "0.040000",
+                "executedQty": "0.00000000",
+                "cummulativeQuoteQty": "0.00000000",
+                "status": "NEW",
+                "timeInForce": "GTC",
+                "type": "LIMIT",
+                "side": "BUY"
+            }
+        }"#;
+        let data = serde_json::from_str::<Data>(json)?;
+        assert!(matches!(data, Data::CancelReplace(_)));
+        let order = Order::try_from(data)?;
+        assert_eq!(order.id(), 10);
+        assert_eq!(order.client_id(), "new");
+        Ok(())
+    }
+
+    #[test]
+    fn test_usd_margin_futures_batch_orders() -> anyhow::Result<()> {
+        let json = r#"[
+            {
+                "clientOrderId": "a",
+                "cumQty": "0",
+                "cumQuote": "0",
+                "executedQty": "0",
+                "orderId": 22542179,
+                "avgPrice": "0.00000",
+                "origQty": "10",
+                "price": "0",
+                "reduceOnly": false,
+                "side": "BUY",
+                "positionSide": "BOTH",
+                "status": "NEW",
+                "stopPrice": "0",
+                "closePosition": false,
+                "symbol": "BTCUSDT",
+                "timeInForce": "GTC",
+                "type": "LIMIT",
+                "origType": "LIMIT",
+                "updateTime": 1566818724722,
+                "workingType": "CONTRACT_PRICE",
+                "priceProtect": false
+            },
+            {
+                "code": -2022,
+                "msg": "ReduceOnly Order is rejected."
+            }
+        ]"#;
+        let data = serde_json::from_str::<Data>(json)?;
+        let mut orders = Vec::<BatchOrder>::try_from(data)?.into_iter();
+        assert_eq!(orders.next().unwrap().into_order()?.client_id(), "a");
+        assert!(orders.next().unwrap().into_order().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_spot_all_orders() -> anyhow::Result<()> {
+        let json = r#"[
+            {
+                "symbol": "LTCBTC",
+                "orderId": 1,
+                "orderListId": -1,
+                "clientOrderId": "myOrder1",
+                "price": "0.1",
+                "origQty": "1.0",
+                "executedQty": "0.0",
+                "cummulativeQuoteQty": "0.0",
+                "status": "NEW",
+                "timeInForce": "GTC",
+                "type": "LIMIT",
+                "side": "BUY",
+                "stopPrice": "0.0",
+                "icebergQty": "0.0",
+                "time": 1499827319559,
+                "updateTime": 1499827319560,
+                "isWorking": true,
+                "origQuoteOrderQty": "0.000000"
+            }
+        ]"#;
+        let data = serde_json::from_str::<Data>(json)?;
+        let order = Vec::<BatchOrder>::try_from(data)?
+            .pop()
+            .unwrap()
+            .into_order()?;
+        assert_eq!(order.created(), Some(1499827319559));
+        assert_eq!(order.updated(), Some(1499827319560));
+        Ok(())
+    }
+
+    #[test]
+    fn test_usd_margin_futures_user_trades() -> anyhow::Result<()> {
+        let json = r#"[
+            {
+                "buyer": false,
+                "commission": "-0.07819010",
+                "commissionAsset": "USDT",
+                "id": 698759,
+                "maker": false,
+                "orderId": 25851813,
+                "price": "7819.01",
+                "qty": "0.002",
+                "quoteQty": "15.63802",
+                "realizedPnl": "-0.91539999",
+                "side": "SELL",
+                "positionSide": "SHORT",
+                "symbol": "BTCUSDT",
+                "time": 1569514978020
+            }
+        ]"#;
+        let data = serde_json::from_str::<Data>(json)?;
+        let trades = Vec::<UserTrade>::try_from(data)?;
+        assert_eq!(trades.len(), 1);
+        assert_eq!(trades[0].order_id, 25851813);
+        assert!(!trades[0].buyer);
+        Ok(())
+    }
+
+    #[test]
+    fn test_usd_margin_futures_stop_market() -> anyhow::Result<()> {
+        let json = r#"{
+            "avgPrice": "0.00000",
+            "clientOrderId": "abc",
+            "cumQuote": "0",
+            "executedQty": "0",
+            "orderId": 1917641,
+            "origQty": "0.40",
+            "origType": "STOP_MARKET",
+            "price": "0",
+            "reduceOnly": false,
+            "side": "SELL",
+            "positionSide": "BOTH",
+            "status": "NEW",
+            "stopPrice": "9300",
+            "closePosition": false,
+            "symbol": "BTCUSDT",
+            "time": 1579276756075,
+            "timeInForce": "GTC",
+            "type": "STOP_MARKET",
+            "updateTime": 1579276756075,
+            "workingType": "MARK_PRICE",
+            "priceProtect": false
+        }"#;
+        let order = match serde_json::from_str::<Data>(json)? {
+            Data::Order(order) => exc_core::types::Order::try_from(order)?,
+            data => anyhow::bail!("unexpected data: {data:?}"),
+        };
+        assert_eq!(
+            order.target.kind,
+            exc_core::types::OrderKind::StopMarket(
+                exc_core::types::Trigger::new(rust_decimal_macros::dec!(9300))
+                    .with_source(exc_core::types::TriggerSource::Mark)
+            )
+        );
+        assert_eq!(
+            order.state.status,
+            exc_core::types::OrderStatus::Untriggered
+        );
+        Ok(())
+    }
+}
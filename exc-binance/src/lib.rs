@@ -1,6 +1,7 @@
 //! Exc-binance: Binance exchange services.
 
 #![deny(missing_docs)]
+#![allow(clippy::result_large_err)]
 
 cfg_if::cfg_if! {
     if #[cfg(any(feature = "rustls-tls", feature = "native-tls"))] {
@@ -0,0 +1,123 @@
+use exc_core::{types, Adaptor, ExchangeError, Str};
+use futures::{stream, FutureExt, StreamExt, TryStreamExt};
+use rust_decimal::Decimal;
+
+use crate::{
+    http::{
+        request::{GetBalances, GetPositions},
+        response::{Balances, PositionRisk},
+    },
+    types::Name,
+    websocket::protocol::frame::account::{BalanceUpdateFrame, PositionsUpdateFrame},
+    Request,
+};
+
+impl Adaptor<types::FetchBalances> for Request {
+    fn from_request(_req: types::FetchBalances) -> Result<Self, ExchangeError> {
+        Ok(Self::with_rest_payload(GetBalances::default()))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<types::FetchBalances as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            match resp.into_response::<Balances>()? {
+                Balances::UsdMarginFutures(balances) => balances
+                    .into_iter()
+                    .map(|b| {
+                        Ok(types::Balance {
+                            ts: super::from_timestamp(b.update_time)?,
+                            asset: b.asset,
+                            total: b.balance.normalize(),
+                            available: b.available_balance.normalize(),
+                            frozen: (b.balance - b.available_balance)
+                                .max(Decimal::ZERO)
+                                .normalize(),
+                        })
+                    })
+                    .collect(),
+                Balances::Spot(account) => {
+                    let ts = super::from_timestamp(account.update_time)?;
+                    Ok(account
+                        .balances
+                        .into_iter()
+                        .map(|b| types::Balance {
+                            ts,
+                            asset: b.asset,
+                            total: (b.free + b.locked).normalize(),
+                            available: b.free.normalize(),
+                            frozen: b.locked.normalize(),
+                        })
+                        .collect())
+                }
+            }
+        }
+        .boxed())
+    }
+}
+
+impl Adaptor<types::SubscribeBalances> for Request {
+    fn from_request(_req: types::SubscribeBalances) -> Result<Self, ExchangeError> {
+        Ok(Self::subscribe(Name::balance_update()))
+    }
+
+    fn into_response(resp: Self::Response) -> Result<types::BalanceStream, ExchangeError> {
+        let stream = resp.into_stream::<BalanceUpdateFrame>()?;
+        Ok(stream
+            .map_err(ExchangeError::from)
+            .and_then(|update| async move { Vec::<types::Balance>::try_from(update) })
+            .map_ok(|balances| stream::iter(balances.into_iter().map(Ok)))
+            .try_flatten()
+            .boxed())
+    }
+}
+
+impl Adaptor<types::FetchPositions> for Request {
+    fn from_request(req: types::FetchPositions) -> Result<Self, ExchangeError> {
+        Ok(Self::with_rest_payload(GetPositions {
+            symbol: req.instrument.map(|inst| inst.to_uppercase()),
+        }))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<types::FetchPositions as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            resp.into_response::<Vec<PositionRisk>>()?
+                .into_iter()
+                .filter(|p| !p.position_amt.is_zero())
+                .map(|p| {
+                    Ok(types::Position {
+                        ts: super::from_timestamp(p.update_time)?,
+                        instrument: Str::new(p.symbol.to_lowercase()),
+                        size: p.position_amt.normalize(),
+                        entry_price: p.entry_price.normalize(),
+                        mark_price: Some(p.mark_price.normalize()),
+                        unrealized_pnl: p.un_realized_profit.normalize(),
+                        liquidation_price: (!p.liquidation_price.is_zero())
+                            .then(|| p.liquidation_price.normalize()),
+                        margin_mode: super::parse_margin_type(&p.margin_type)?,
+                        leverage: Some(p.leverage.normalize()),
+                    })
+                })
+                .collect()
+        }
+        .boxed())
+    }
+}
+
+impl Adaptor<types::SubscribePositions> for Request {
+    fn from_request(_req: types::SubscribePositions) -> Result<Self, ExchangeError> {
+        Ok(Self::subscribe(Name::position_update()))
+    }
+
+    fn into_response(resp: Self::Response) -> Result<types::PositionStream, ExchangeError> {
+        let stream = resp.into_stream::<PositionsUpdateFrame>()?;
+        Ok(stream
+            .map_err(ExchangeError::from)
+            .and_then(|update| async move { Vec::<types::Position>::try_from(update) })
+            .map_ok(|positions| stream::iter(positions.into_iter().map(Ok)))
+            .try_flatten()
+            .boxed())
+    }
+}
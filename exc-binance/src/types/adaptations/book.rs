@@ -1,8 +1,17 @@
-use exc_core::{types, Adaptor, ExchangeError};
+use async_stream::try_stream;
+use exc_core::{
+    types::{self, LocalOrderBook},
+    Adaptor, ExchangeError,
+};
 use futures::{StreamExt, TryStreamExt};
+use rust_decimal::Decimal;
+use time::OffsetDateTime;
 
 use crate::{
-    websocket::{protocol::frame::DepthFrame, request::WsRequest},
+    websocket::{
+        protocol::frame::{depth::OrderBookFrame, DepthFrame},
+        request::WsRequest,
+    },
     Request,
 };
 
@@ -19,3 +28,138 @@ impl Adaptor<types::SubscribeBidAsk> for Request {
             .boxed())
     }
 }
+
+fn normalize(levels: Vec<(Decimal, Decimal)>) -> impl Iterator<Item = (Decimal, Decimal)> {
+    levels
+        .into_iter()
+        .map(|(p, s)| (p.normalize(), s.normalize()))
+}
+
+impl Adaptor<types::SubscribeOrderBook> for Request {
+    fn from_request(req: types::SubscribeOrderBook) -> Result<Self, ExchangeError> {
+        Ok(WsRequest::dispatch_order_book(req).into())
+    }
+
+    fn into_response(resp: Self::Response) -> Result<types::OrderBookStream, ExchangeError> {
+        let frames = resp.into_stream::<OrderBookFrame>()?;
+        let stream = try_stream! {
+            let mut book = LocalOrderBook::default();
+            // Final update id of the last applied event (or the snapshot).
+            let mut last = None;
+            let mut synced = false;
+            for await frame in frames {
+                match frame? {
+                    OrderBookFrame::Snapshot(snapshot) => {
+                        book.clear();
+                        book.update_bids(normalize(snapshot.bids));
+                        book.update_asks(normalize(snapshot.asks));
+                        last = Some(snapshot.last_update_id);
+                        synced = false;
+                        let ts = match snapshot.trade_timestamp.or(snapshot.event_timestamp) {
+                            Some(ts) => super::from_timestamp(ts)?,
+                            None => OffsetDateTime::now_utc(),
+                        };
+                        yield book.snapshot(ts, None);
+                    }
+                    OrderBookFrame::Update(depth) => {
+                        let Some(last_id) = last else {
+                            Err(ExchangeError::OutOfSync(anyhow::anyhow!(
+                                "received a depth update before the snapshot"
+                            )))?;
+                            continue;
+                        };
+                        if depth.id <= last_id {
+                            continue;
+                        }
+                        let first_id = depth.first_id.unwrap_or(depth.id);
+                        let consistent = if synced {
+                            match depth.prev_id {
+                                Some(prev_id) => prev_id == last_id,
+                                None => first_id == last_id + 1,
+                            }
+                        } else {
+                            first_id <= last_id + 1
+                        };
+                        if !consistent {
+                            Err(ExchangeError::OutOfSync(anyhow::anyhow!(
+                                "depth update gap: last={last_id} first={first_id} final={}",
+                                depth.id
+                            )))?;
+                        }
+                        synced = true;
+                        last = Some(depth.id);
+                        book.update_bids(normalize(depth.bids));
+                        book.update_asks(normalize(depth.asks));
+                        let ts = super::from_timestamp(
+                            depth.trade_timestamp.unwrap_or(depth.event_timestamp),
+                        )?;
+                        yield book.snapshot(ts, None);
+                    }
+                }
+            }
+        };
+        Ok(stream.boxed())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use exc_core::{types::SubscribeOrderBook, Adaptor, ExchangeError};
+    use futures::{StreamExt, TryStreamExt};
+
+    use crate::{
+        http::response::DepthSnapshot,
+        types::response::Response,
+        websocket::{
+            protocol::frame::{StreamFrame, StreamFrameKind},
+            response::WsResponse,
+        },
+        Request,
+    };
+
+    fn update(json: &str) -> StreamFrame {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_order_book_stitching() -> anyhow::Result<()> {
+        let snapshot: DepthSnapshot = serde_json::from_str(
+            r#"{"lastUpdateId":100,"E":1,"T":1,"bids":[["10.0","1"],["9.0","2"]],"asks":[["11.0","1"],["12.0","3"]]}"#,
+        )?;
+        let frames = vec![
+            StreamFrame {
+                stream: "btcusdt@depth@100ms".to_string(),
+                data: StreamFrameKind::DepthSnapshot(snapshot),
+            },
+            update(
+                r#"{"stream":"btcusdt@depth@100ms","data":{"e":"depthUpdate","E":2,"T":2,"s":"BTCUSDT","U":95,"u":99,"pu":94,"b":[["10.0","5"]],"a":[]}}"#,
+            ),
+            update(
+                r#"{"stream":"btcusdt@depth@100ms","data":{"e":"depthUpdate","E":3,"T":3,"s":"BTCUSDT","U":98,"u":103,"pu":97,"b":[["10.0","0"]],"a":[["10.5","2"]]}}"#,
+            ),
+            update(
+                r#"{"stream":"btcusdt@depth@100ms","data":{"e":"depthUpdate","E":4,"T":4,"s":"BTCUSDT","U":104,"u":105,"pu":103,"b":[["9.5","1"]],"a":[]}}"#,
+            ),
+            update(
+                r#"{"stream":"btcusdt@depth@100ms","data":{"e":"depthUpdate","E":5,"T":5,"s":"BTCUSDT","U":110,"u":111,"pu":109,"b":[],"a":[]}}"#,
+            ),
+        ];
+        let resp = Response::Ws(WsResponse::Stream(
+            futures::stream::iter(frames.into_iter().map(Ok)).boxed(),
+        ));
+        let mut books =
+            <Request as Adaptor<SubscribeOrderBook>>::into_response(resp)?.into_stream();
+        let book = books.try_next().await?.unwrap();
+        assert_eq!(book.best_bid().unwrap().0.to_string(), "10");
+        let book = books.try_next().await?.unwrap();
+        assert_eq!(book.best_bid().unwrap().0.to_string(), "9");
+        assert_eq!(book.best_ask().unwrap().0.to_string(), "10.5");
+        let book = books.try_next().await?.unwrap();
+        assert_eq!(book.best_bid().unwrap().0.to_string(), "9.5");
+        assert!(matches!(
+            books.try_next().await,
+            Err(ExchangeError::OutOfSync(_))
+        ));
+        Ok(())
+    }
+}
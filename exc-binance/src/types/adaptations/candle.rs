@@ -1,5 +1,6 @@
 use crate::{
     http::{request, response},
+    websocket::protocol::frame::{kline::Kline, Name},
     Request,
 };
 use either::Either;
@@ -7,6 +8,7 @@ use exc_core::{
     types::{self, CandleStream},
     Adaptor, ExchangeError,
 };
+use futures::{StreamExt, TryStreamExt};
 use std::{ops::RangeBounds, time::Duration};
 use time::UtcOffset;
 
@@ -44,7 +46,7 @@ impl TryFrom<types::Period> for request::Interval {
                 "unsupported period: {}",
                 period
             ))),
-            types::PeriodKind::Month => Ok(Self::M1),
+            types::PeriodKind::Month => Ok(Self::Mon1),
             types::PeriodKind::Duration(dur) => match dur {
                 M1 => Ok(Self::M1),
                 M3 => Ok(Self::M3),
@@ -56,7 +58,7 @@ impl TryFrom<types::Period> for request::Interval {
                 H4 => Ok(Self::H4),
                 H6 => Ok(Self::H6),
                 H8 => Ok(Self::H8),
-                H12 => Ok(Self::H6),
+                H12 => Ok(Self::H12),
                 D1 => Ok(Self::D1),
                 D3 => Ok(Self::D3),
                 W1 => Ok(Self::W1),
@@ -77,6 +79,7 @@ impl Adaptor<types::QueryFirstCandles> for Request {
             start_time: super::start_bound_to_timestamp(req.query().start_bound())?,
             end_time: super::end_bound_to_timestamp(req.query().end_bound())?,
             limit: Some(req.first()),
+            source: req.query().source(),
         }))
     }
 
@@ -111,3 +114,21 @@ impl Adaptor<types::QueryFirstCandles> for Request {
         Ok(CandleStream::new_forward(futures::stream::iter(candles)))
     }
 }
+
+impl Adaptor<types::SubscribeCandles> for Request {
+    fn from_request(req: types::SubscribeCandles) -> Result<Self, ExchangeError> {
+        let interval: request::Interval = req.period().try_into()?;
+        Ok(Self::subscribe(Name::kline(
+            &req.inst().to_lowercase(),
+            interval.as_str(),
+        )))
+    }
+
+    fn into_response(resp: Self::Response) -> Result<types::CandleUpdateStream, ExchangeError> {
+        let stream = resp.into_stream::<Kline>()?;
+        Ok(stream
+            .map_err(ExchangeError::from)
+            .and_then(|kline| async move { kline.try_into() })
+            .boxed())
+    }
+}
@@ -0,0 +1,174 @@
+use std::ops::{Bound, RangeBounds};
+
+use exc_core::{types, Adaptor, ExchangeError};
+use futures::{FutureExt, StreamExt, TryStreamExt};
+
+use crate::{
+    http::{
+        request::{FundingRateHistory, OpenInterestHistory},
+        response::{FundingRateRecord, OpenInterest, OpenInterestRecord},
+    },
+    websocket::{
+        protocol::frame::{force_order::ForceOrder, mark_price::MarkPrice, Name},
+        request::WsRequest,
+    },
+    Request,
+};
+
+impl Adaptor<types::SubscribeMarkPrice> for Request {
+    fn from_request(req: types::SubscribeMarkPrice) -> Result<Self, ExchangeError> {
+        Ok(Self::subscribe(Name::mark_price(&req.instrument)))
+    }
+
+    fn into_response(resp: Self::Response) -> Result<types::MarkPriceStream, ExchangeError> {
+        let stream = resp.into_stream::<MarkPrice>()?;
+        Ok(stream
+            .map_err(ExchangeError::from)
+            .and_then(|price| async move { price.try_into() })
+            .boxed())
+    }
+}
+
+impl Adaptor<types::SubscribeIndexPrice> for Request {
+    fn from_request(req: types::SubscribeIndexPrice) -> Result<Self, ExchangeError> {
+        Ok(Self::subscribe(Name::mark_price(&req.instrument)))
+    }
+
+    fn into_response(resp: Self::Response) -> Result<types::IndexPriceStream, ExchangeError> {
+        let stream = resp.into_stream::<MarkPrice>()?;
+        Ok(stream
+            .map_err(ExchangeError::from)
+            .and_then(|price| async move { price.try_into() })
+            .boxed())
+    }
+}
+
+impl Adaptor<types::SubscribeFundingRate> for Request {
+    fn from_request(req: types::SubscribeFundingRate) -> Result<Self, ExchangeError> {
+        Ok(Self::subscribe(Name::mark_price(&req.instrument)))
+    }
+
+    fn into_response(resp: Self::Response) -> Result<types::FundingRateStream, ExchangeError> {
+        let stream = resp.into_stream::<MarkPrice>()?;
+        Ok(stream
+            .map_err(ExchangeError::from)
+            .and_then(|price| async move { price.try_into() })
+            .boxed())
+    }
+}
+
+impl Adaptor<types::FetchFundingRateHistory> for Request {
+    fn from_request(req: types::FetchFundingRateHistory) -> Result<Self, ExchangeError> {
+        let start_time = match req.start_bound() {
+            Bound::Included(ts) => Some(super::to_timestamp(ts)?),
+            Bound::Excluded(ts) => Some(super::to_timestamp(ts)? + 1),
+            Bound::Unbounded => None,
+        };
+        let end_time = match req.end_bound() {
+            Bound::Included(ts) => Some(super::to_timestamp(ts)?),
+            Bound::Excluded(ts) => Some(super::to_timestamp(ts)? - 1),
+            Bound::Unbounded => None,
+        };
+        Ok(Self::with_rest_payload(FundingRateHistory {
+            symbol: req.instrument.to_uppercase(),
+            start_time,
+            end_time,
+            limit: req.last,
+        }))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<types::FetchFundingRateHistory as exc_core::Request>::Response, ExchangeError>
+    {
+        Ok(async move {
+            let mut rates = resp.into_response::<Vec<FundingRateRecord>>()?;
+            // Binance returns the funding rates in ascending order.
+            rates.sort_by_key(|rate| std::cmp::Reverse(rate.funding_time));
+            rates
+                .into_iter()
+                .map(|rate| {
+                    let ts = super::from_timestamp(rate.funding_time)?;
+                    Ok(types::FundingRate {
+                        ts,
+                        rate: rate.funding_rate.normalize(),
+                        funding_time: ts,
+                    })
+                })
+                .collect()
+        }
+        .boxed())
+    }
+}
+
+impl Adaptor<types::SubscribeOpenInterest> for Request {
+    fn from_request(req: types::SubscribeOpenInterest) -> Result<Self, ExchangeError> {
+        Ok(WsRequest::dispatch_open_interest(req).into())
+    }
+
+    fn into_response(resp: Self::Response) -> Result<types::OpenInterestStream, ExchangeError> {
+        let stream = resp.into_stream::<OpenInterest>()?;
+        Ok(stream
+            .map_err(ExchangeError::from)
+            .and_then(|oi| async move { oi.try_into() })
+            .boxed())
+    }
+}
+
+impl Adaptor<types::FetchOpenInterestHistory> for Request {
+    fn from_request(req: types::FetchOpenInterestHistory) -> Result<Self, ExchangeError> {
+        let start_time = match req.start_bound() {
+            Bound::Included(ts) => Some(super::to_timestamp(ts)?),
+            Bound::Excluded(ts) => Some(super::to_timestamp(ts)? + 1),
+            Bound::Unbounded => None,
+        };
+        let end_time = match req.end_bound() {
+            Bound::Included(ts) => Some(super::to_timestamp(ts)?),
+            Bound::Excluded(ts) => Some(super::to_timestamp(ts)? - 1),
+            Bound::Unbounded => None,
+        };
+        Ok(Self::with_rest_payload(OpenInterestHistory {
+            symbol: req.instrument.to_uppercase(),
+            period: req.period.try_into()?,
+            start_time,
+            end_time,
+            limit: req.last,
+        }))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<types::FetchOpenInterestHistory as exc_core::Request>::Response, ExchangeError>
+    {
+        Ok(async move {
+            let mut records = resp.into_response::<Vec<OpenInterestRecord>>()?;
+            // Binance returns the statistics in ascending order.
+            records.sort_by_key(|record| std::cmp::Reverse(record.timestamp));
+            records
+                .into_iter()
+                .map(|record| {
+                    Ok(types::OpenInterest {
+                        ts: super::from_timestamp(record.timestamp)?,
+                        size: record.sum_open_interest.normalize(),
+                        value: Some(record.sum_open_interest_value.normalize()),
+                    })
+                })
+                .collect()
+        }
+        .boxed())
+    }
+}
+
+impl Adaptor<types::SubscribeLiquidations> for Request {
+    fn from_request(req: types::SubscribeLiquidations) -> Result<Self, ExchangeError> {
+        Ok(Self::subscribe(Name::force_order(&req.instrument)))
+    }
+
+    fn into_response(resp: Self::Response) -> Result<types::LiquidationStream, ExchangeError> {
+        let stream = resp.into_stream::<ForceOrder>()?;
+        Ok(stream
+            .map_err(ExchangeError::from)
+            .and_then(|order| async move { order.try_into() })
+            .boxed())
+    }
+}
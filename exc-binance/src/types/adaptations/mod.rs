@@ -1,10 +1,12 @@
 use std::ops::Bound;
 
-use exc_core::ExchangeError;
+use exc_core::{types::MarginMode, ExchangeError};
 use time::OffsetDateTime;
 
+mod account;
 mod book;
 mod candle;
+mod derivative;
 mod instrument;
 mod trade;
 mod trading;
@@ -15,6 +17,14 @@ pub(crate) fn from_timestamp(ts: i64) -> Result<OffsetDateTime, ExchangeError> {
         .map_err(|err| ExchangeError::Other(anyhow!("parse timestamp error: {err}")))
 }
 
+pub(crate) fn parse_margin_type(ty: &str) -> Result<MarginMode, ExchangeError> {
+    match ty.to_lowercase().as_str() {
+        "cross" | "crossed" => Ok(MarginMode::Cross),
+        "isolated" => Ok(MarginMode::Isolated),
+        ty => Err(ExchangeError::Other(anyhow!("unknown margin type: {ty}"))),
+    }
+}
+
 pub(crate) fn to_timestamp(ts: &OffsetDateTime) -> Result<i64, ExchangeError> {
     let ts = ts.unix_timestamp_nanos() / 1_000_000;
     if ts > (i64::MAX as i128) {
@@ -1,4 +1,7 @@
-use std::{collections::HashMap, ops::Neg};
+use std::{
+    collections::HashMap,
+    ops::{Bound, Neg, RangeBounds},
+};
 
 use exc_core::{types, Adaptor, ExchangeError, Str};
 use futures::{FutureExt, StreamExt, TryStreamExt};
@@ -7,8 +10,12 @@ use time::OffsetDateTime;
 
 use crate::{
     http::{
-        request::trading::{CancelOrder, GetOrder, GetOrderInner, PlaceOrder},
-        response::trading::Order,
+        request::trading::{
+            AmendOrder, CancelAllOrders, CancelOrder, CancelOrders, CountdownCancelAll,
+            FetchAllOrders, FetchOpenOrders, GetOrder, GetOrderInner, PlaceOrder, PlaceOrders,
+            UserTrades,
+        },
+        response::trading::{Ack, BatchOrder, Order, UserTrade},
     },
     types::{
         trading::{self, OrderSide, Status, TimeInForce},
@@ -50,29 +57,20 @@ impl TryFrom<Order> for types::Order {
                         size.set_sign_positive(false);
                     }
                 }
-                let kind = match order.order_type {
-                    trading::OrderType::Limit => match order.time_in_force {
-                        TimeInForce::Gtc => types::OrderKind::Limit(
-                            order.price,
-                            types::TimeInForce::GoodTilCancelled,
-                        ),
-                        TimeInForce::Fok => {
-                            types::OrderKind::Limit(order.price, types::TimeInForce::FillOrKill)
-                        }
-                        TimeInForce::Ioc => types::OrderKind::Limit(
-                            order.price,
-                            types::TimeInForce::ImmediateOrCancel,
-                        ),
-                        TimeInForce::Gtx => types::OrderKind::PostOnly(order.price),
-                    },
-                    trading::OrderType::Market => types::OrderKind::Market,
-                    other => {
-                        return Err(ExchangeError::Other(anyhow!(
-                            "unsupported order type: {other:?}"
-                        )));
-                    }
-                };
+                let orig_type = order.orig_type.unwrap_or(order.order_type);
+                let kind = trading::usd_margin_futures_kind(
+                    orig_type,
+                    order.time_in_force,
+                    order.price,
+                    order.stop_price,
+                    &order.working_type,
+                    order.activate_price,
+                    order.price_rate,
+                )?;
                 let status = match order.status {
+                    Status::New if order.order_type.is_conditional() => {
+                        types::OrderStatus::Untriggered
+                    }
                     Status::New | Status::PartiallyFilled => types::OrderStatus::Pending,
                     Status::Canceled | Status::Expired | Status::Filled => {
                         types::OrderStatus::Finished
@@ -93,6 +91,7 @@ impl TryFrom<Order> for types::Order {
                         fees: HashMap::default(),
                     },
                     trade: None,
+                    position_side: order.position_side.into(),
                 })
             }
             Order::Spot(order) => {
@@ -168,6 +167,7 @@ impl TryFrom<Order> for types::Order {
                             fees,
                         },
                         trade: last_trade,
+                        position_side: types::PositionSide::Net,
                     })
                 } else {
                     Err(ExchangeError::Other(anyhow::anyhow!(
@@ -231,12 +231,50 @@ impl TryFrom<Order> for types::Order {
                     target: types::Place { size, kind },
                     state,
                     trade: None,
+                    position_side: types::PositionSide::Net,
                 })
             }
         }
     }
 }
 
+fn placed(order: Order) -> Result<types::Placed, ExchangeError> {
+    let id = types::OrderId::from(order.client_id().to_string());
+    Ok(types::Placed {
+        ts: order
+            .updated()
+            .map(super::from_timestamp)
+            .unwrap_or_else(|| Ok(OffsetDateTime::now_utc()))?,
+        id,
+        order: order
+            .try_into()
+            .map_err(|err| {
+                tracing::warn!(%err, "failed to convert order");
+            })
+            .ok(),
+    })
+}
+
+fn canceled(order: Order) -> Result<types::Canceled, ExchangeError> {
+    Ok(types::Canceled {
+        ts: order
+            .updated()
+            .map(super::from_timestamp)
+            .unwrap_or_else(|| Ok(OffsetDateTime::now_utc()))?,
+        order: Some(order.try_into()?),
+    })
+}
+
+fn updated(order: Order) -> Result<types::OrderUpdate, ExchangeError> {
+    Ok(types::OrderUpdate {
+        ts: order
+            .updated()
+            .map(super::from_timestamp)
+            .unwrap_or_else(|| Ok(OffsetDateTime::now_utc()))?,
+        order: order.try_into()?,
+    })
+}
+
 impl Adaptor<types::PlaceOrder> for Request {
     fn from_request(req: types::PlaceOrder) -> Result<Self, ExchangeError> {
         Ok(Self::with_rest_payload(PlaceOrder { inner: req }))
@@ -245,22 +283,24 @@ impl Adaptor<types::PlaceOrder> for Request {
     fn into_response(
         resp: Self::Response,
     ) -> Result<<types::PlaceOrder as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move { placed(resp.into_response::<Order>()?) }.boxed())
+    }
+}
+
+impl Adaptor<types::PlaceOrders> for Request {
+    fn from_request(req: types::PlaceOrders) -> Result<Self, ExchangeError> {
+        Ok(Self::with_rest_payload(PlaceOrders { inner: req.0 }))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<types::PlaceOrders as exc_core::Request>::Response, ExchangeError> {
         Ok(async move {
-            let order = resp.into_response::<Order>()?;
-            let id = types::OrderId::from(order.client_id().to_string());
-            Ok(types::Placed {
-                ts: order
-                    .updated()
-                    .map(super::from_timestamp)
-                    .unwrap_or_else(|| Ok(OffsetDateTime::now_utc()))?,
-                id,
-                order: order
-                    .try_into()
-                    .map_err(|err| {
-                        tracing::warn!(%err, "failed to convert order");
-                    })
-                    .ok(),
-            })
+            let orders = resp.into_response::<Vec<BatchOrder>>()?;
+            Ok(orders
+                .into_iter()
+                .map(|order| placed(order.into_order().map_err(crate::Error::from)?))
+                .collect())
         }
         .boxed())
     }
@@ -281,20 +321,126 @@ impl Adaptor<types::CancelOrder> for Request {
     fn into_response(
         resp: Self::Response,
     ) -> Result<<types::CancelOrder as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move { canceled(resp.into_response::<Order>()?) }.boxed())
+    }
+}
+
+impl Adaptor<types::CancelOrders> for Request {
+    fn from_request(req: types::CancelOrders) -> Result<Self, ExchangeError> {
+        let symbol = req
+            .0
+            .first()
+            .map(|req| req.instrument.to_uppercase())
+            .unwrap_or_default();
+        if req
+            .0
+            .iter()
+            .any(|req| req.instrument.to_uppercase() != symbol)
+        {
+            return Err(ExchangeError::Unsupported(anyhow!(
+                "orders in a batch cancellation must have the same instrument"
+            )));
+        }
+        Ok(Self::with_rest_payload(CancelOrders {
+            symbol,
+            orig_client_order_id_list: req
+                .0
+                .iter()
+                .map(|req| req.id.as_str().to_string())
+                .collect(),
+        }))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<types::CancelOrders as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            let orders = resp.into_response::<Vec<BatchOrder>>()?;
+            Ok(orders
+                .into_iter()
+                .map(|order| canceled(order.into_order().map_err(crate::Error::from)?))
+                .collect())
+        }
+        .boxed())
+    }
+}
+
+impl Adaptor<types::AmendOrder> for Request {
+    fn from_request(req: types::AmendOrder) -> Result<Self, ExchangeError> {
+        Ok(Self::with_rest_payload(AmendOrder { inner: req }))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<types::AmendOrder as exc_core::Request>::Response, ExchangeError> {
         Ok(async move {
             let order = resp.into_response::<Order>()?;
-            Ok(types::Canceled {
+            let id = types::OrderId::from(order.client_id().to_string());
+            Ok(types::Amended {
                 ts: order
                     .updated()
                     .map(super::from_timestamp)
                     .unwrap_or_else(|| Ok(OffsetDateTime::now_utc()))?,
-                order: Some(order.try_into()?),
+                id,
+                order: order
+                    .try_into()
+                    .map_err(|err| {
+                        tracing::warn!(%err, "failed to convert order");
+                    })
+                    .ok(),
             })
         }
         .boxed())
     }
 }
 
+impl Adaptor<types::CancelAllOrders> for Request {
+    fn from_request(req: types::CancelAllOrders) -> Result<Self, ExchangeError> {
+        let Some(inst) = req.instrument else {
+            return Err(ExchangeError::Unsupported(anyhow!(
+                "the instrument is required to cancel all orders"
+            )));
+        };
+        Ok(Self::with_rest_payload(CancelAllOrders {
+            symbol: inst.to_uppercase(),
+        }))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<types::CancelAllOrders as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            resp.into_response::<Ack>()?;
+            Ok(())
+        }
+        .boxed())
+    }
+}
+
+impl Adaptor<types::CancelAllAfter> for Request {
+    fn from_request(req: types::CancelAllAfter) -> Result<Self, ExchangeError> {
+        let Some(inst) = req.instrument else {
+            return Err(ExchangeError::Unsupported(anyhow!(
+                "the instrument is required to cancel all orders after the countdown"
+            )));
+        };
+        Ok(Self::with_rest_payload(CountdownCancelAll {
+            symbol: inst.to_uppercase(),
+            countdown_time: req.timeout.as_millis() as u64,
+        }))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<types::CancelAllAfter as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            resp.into_response::<Ack>()?;
+            Ok(())
+        }
+        .boxed())
+    }
+}
+
 impl Adaptor<types::GetOrder> for Request {
     fn from_request(req: types::GetOrder) -> Result<Self, ExchangeError> {
         Ok(Self::with_rest_payload(GetOrder {
@@ -310,16 +456,120 @@ impl Adaptor<types::GetOrder> for Request {
     fn into_response(
         resp: Self::Response,
     ) -> Result<<types::GetOrder as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move { updated(resp.into_response::<Order>()?) }.boxed())
+    }
+}
+
+impl Adaptor<types::FetchOpenOrders> for Request {
+    fn from_request(req: types::FetchOpenOrders) -> Result<Self, ExchangeError> {
+        Ok(Self::with_rest_payload(FetchOpenOrders {
+            symbol: req.instrument.map(|inst| inst.to_uppercase()),
+        }))
+    }
+
+    fn into_response(resp: Self::Response) -> Result<types::OrderStream, ExchangeError> {
+        let orders = resp
+            .into_response::<Vec<BatchOrder>>()?
+            .into_iter()
+            .map(|order| updated(order.into_order().map_err(crate::Error::from)?))
+            .collect::<Vec<_>>();
+        Ok(futures::stream::iter(orders).boxed())
+    }
+}
+
+impl Adaptor<types::FetchLastOrders> for Request {
+    fn from_request(req: types::FetchLastOrders) -> Result<Self, ExchangeError> {
+        let start_time = match req.query.start_bound() {
+            Bound::Included(ts) => Some(super::to_timestamp(ts)?),
+            Bound::Excluded(ts) => Some(super::to_timestamp(ts)? + 1),
+            Bound::Unbounded => None,
+        };
+        let end_time = match req.query.end_bound() {
+            Bound::Included(ts) => Some(super::to_timestamp(ts)?),
+            Bound::Excluded(ts) => Some(super::to_timestamp(ts)? - 1),
+            Bound::Unbounded => None,
+        };
+        Ok(Self::with_rest_payload(FetchAllOrders {
+            symbol: req.query.instrument.to_uppercase(),
+            start_time,
+            end_time,
+            limit: Some(req.last),
+        }))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<types::FetchLastOrders as exc_core::Request>::Response, ExchangeError> {
         Ok(async move {
-            let order = resp.into_response::<Order>()?;
-            Ok(types::OrderUpdate {
-                ts: order
-                    .updated()
-                    .map(super::from_timestamp)
-                    .unwrap_or_else(|| Ok(OffsetDateTime::now_utc()))?,
-                order: order.try_into()?,
+            let mut orders = resp
+                .into_response::<Vec<BatchOrder>>()?
+                .into_iter()
+                .map(|order| order.into_order().map_err(crate::Error::from))
+                .collect::<Result<Vec<_>, _>>()?;
+            // Binance returns the orders in ascending order of creation.
+            orders.sort_by_key(|order| std::cmp::Reverse(order.created()));
+            let oldest = orders
+                .last()
+                .and_then(|order| order.created())
+                .map(super::from_timestamp)
+                .transpose()?;
+            Ok(types::OrdersPage {
+                orders: orders.into_iter().map(updated).collect(),
+                oldest,
             })
         }
         .boxed())
     }
 }
+
+impl Adaptor<types::FetchLastFills> for Request {
+    fn from_request(req: types::FetchLastFills) -> Result<Self, ExchangeError> {
+        let start_time = match req.query.start_bound() {
+            Bound::Included(ts) => Some(super::to_timestamp(ts)?),
+            Bound::Excluded(ts) => Some(super::to_timestamp(ts)? + 1),
+            Bound::Unbounded => None,
+        };
+        let end_time = match req.query.end_bound() {
+            Bound::Included(ts) => Some(super::to_timestamp(ts)?),
+            Bound::Excluded(ts) => Some(super::to_timestamp(ts)? - 1),
+            Bound::Unbounded => None,
+        };
+        Ok(Self::with_rest_payload(UserTrades {
+            symbol: req.query.instrument.to_uppercase(),
+            start_time,
+            end_time,
+            limit: Some(req.last),
+        }))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<types::FetchLastFills as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            let mut trades = resp.into_response::<Vec<UserTrade>>()?;
+            // Binance returns the trades in ascending order.
+            trades.sort_by_key(|trade| std::cmp::Reverse(trade.time));
+            trades
+                .into_iter()
+                .map(|trade| {
+                    let size = trade.qty.abs().normalize();
+                    Ok(types::Fill {
+                        order_id: Str::new(trade.order_id.to_string()),
+                        trade_id: Str::new(trade.id.to_string()),
+                        price: trade.price.normalize(),
+                        size: if trade.buyer { size } else { -size },
+                        fee: -trade.commission.normalize(),
+                        fee_asset: trade.commission_asset,
+                        liquidity: if trade.maker {
+                            types::Liquidity::Maker
+                        } else {
+                            types::Liquidity::Taker
+                        },
+                        ts: super::from_timestamp(trade.time)?,
+                    })
+                })
+                .collect()
+        }
+        .boxed())
+    }
+}
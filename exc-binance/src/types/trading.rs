@@ -1,4 +1,5 @@
 use exc_core::{types, ExchangeError};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// Order side.
@@ -23,6 +24,26 @@ pub enum PositionSide {
     Both,
 }
 
+impl From<types::PositionSide> for PositionSide {
+    fn from(side: types::PositionSide) -> Self {
+        match side {
+            types::PositionSide::Long => Self::Long,
+            types::PositionSide::Short => Self::Short,
+            types::PositionSide::Net => Self::Both,
+        }
+    }
+}
+
+impl From<PositionSide> for types::PositionSide {
+    fn from(side: PositionSide) -> Self {
+        match side {
+            PositionSide::Long => Self::Long,
+            PositionSide::Short => Self::Short,
+            PositionSide::Both => Self::Net,
+        }
+    }
+}
+
 /// Time-in-force.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
@@ -93,3 +114,79 @@ pub enum OrderType {
     /// Limit Maker.
     LimitMaker,
 }
+
+impl OrderType {
+    /// Is conditional.
+    pub fn is_conditional(&self) -> bool {
+        matches!(
+            self,
+            Self::Stop
+                | Self::TakeProfit
+                | Self::StopMarket
+                | Self::TakeProfitMarket
+                | Self::TrailingStopMarket
+        )
+    }
+}
+
+/// Working type (the trigger source) of usd-margin futures.
+pub(crate) fn working_type(source: types::TriggerSource) -> Result<&'static str, ExchangeError> {
+    match source {
+        types::TriggerSource::Last => Ok("CONTRACT_PRICE"),
+        types::TriggerSource::Mark => Ok("MARK_PRICE"),
+        types::TriggerSource::Index => Err(ExchangeError::Unsupported(anyhow::anyhow!(
+            "triggering by index price is not supported"
+        ))),
+    }
+}
+
+/// Order kind of usd-margin futures.
+pub(crate) fn usd_margin_futures_kind(
+    order_type: OrderType,
+    tif: TimeInForce,
+    price: Decimal,
+    stop_price: Decimal,
+    working_type: &str,
+    activate_price: Option<Decimal>,
+    price_rate: Option<Decimal>,
+) -> Result<types::OrderKind, ExchangeError> {
+    let source = match working_type {
+        "MARK_PRICE" => types::TriggerSource::Mark,
+        _ => types::TriggerSource::Last,
+    };
+    let trigger = types::Trigger::new(stop_price.normalize()).with_source(source);
+    let kind = match order_type {
+        OrderType::Limit => match tif {
+            TimeInForce::Gtc => {
+                types::OrderKind::Limit(price.normalize(), types::TimeInForce::GoodTilCancelled)
+            }
+            TimeInForce::Fok => {
+                types::OrderKind::Limit(price.normalize(), types::TimeInForce::FillOrKill)
+            }
+            TimeInForce::Ioc => {
+                types::OrderKind::Limit(price.normalize(), types::TimeInForce::ImmediateOrCancel)
+            }
+            TimeInForce::Gtx => types::OrderKind::PostOnly(price.normalize()),
+        },
+        OrderType::Market => types::OrderKind::Market,
+        OrderType::StopMarket => types::OrderKind::StopMarket(trigger),
+        OrderType::Stop => types::OrderKind::StopLimit(trigger, price.normalize()),
+        OrderType::TakeProfitMarket => types::OrderKind::TakeProfit(trigger),
+        OrderType::TrailingStopMarket => {
+            // The callback rate is given in percentage.
+            let rate = price_rate.unwrap_or_default() / Decimal::ONE_HUNDRED;
+            types::OrderKind::TrailingStop(
+                activate_price
+                    .filter(|price| !price.is_zero())
+                    .map(|price| types::Trigger::new(price.normalize()).with_source(source)),
+                rate.normalize(),
+            )
+        }
+        other => {
+            return Err(ExchangeError::Other(anyhow::anyhow!(
+                "unsupported order type: {other:?}"
+            )));
+        }
+    };
+    Ok(kind)
+}
@@ -166,6 +166,7 @@ pub(crate) struct BinanceWsConnect {
     pub(crate) retry: Option<usize>,
     pub(crate) interval: Option<Duration>,
     pub(crate) stop_refresing_after: Option<Duration>,
+    pub(crate) rest: Option<Http>,
 }
 
 impl Service<BinanceWsTarget> for BinanceWsConnect {
@@ -191,16 +192,19 @@ impl Service<BinanceWsTarget> for BinanceWsConnect {
         let main_stream = self.main_stream.clone();
         let keep_alive_timeout = self.keep_alive_timeout;
         let default_stream_timeout = self.default_stream_timeout;
+        let rest = self.rest.clone();
         async move {
             let (ws, worker) = res.await?;
-            WsClient::with_websocket(
+            let mut client = WsClient::with_websocket(
                 endpoint,
                 ws,
                 main_stream,
                 keep_alive_timeout,
                 default_stream_timeout,
                 worker,
-            )
+            )?;
+            client.rest = rest;
+            Ok(client)
         }
         .boxed()
     }
@@ -23,6 +23,7 @@ pub struct WsEndpoint {
     listen_key_refresh_interval: Option<Duration>,
     listen_key_stop_refreshing_after: Option<Duration>,
     rate_limit: (u64, Duration),
+    rest: Option<Http>,
 }
 
 impl WsEndpoint {
@@ -41,6 +42,7 @@ impl WsEndpoint {
             listen_key_refresh_interval: None,
             listen_key_stop_refreshing_after: None,
             rate_limit: DEFAULT_RATE_LIMIT,
+            rest: None,
         }
     }
 
@@ -82,6 +84,12 @@ impl WsEndpoint {
         self
     }
 
+    /// Set the rest api used to fetch snapshots.
+    pub(crate) fn rest(&mut self, http: Http) -> &mut Self {
+        self.rest = Some(http);
+        self
+    }
+
     /// Add main stream.
     pub(crate) fn _add_main_stream(&mut self, name: Name) -> &mut Self {
         self.main_stream.insert(name);
@@ -110,6 +118,7 @@ impl WsEndpoint {
             retry: self.listen_key_retry,
             interval: self.listen_key_refresh_interval,
             stop_refresing_after: self.listen_key_stop_refreshing_after,
+            rest: self.rest.clone(),
         };
         let connection = Reconnect::new::<WsClient, WsRequest>(connect, self.target.clone())
             .map_err(|err| match err.downcast::<WsError>() {
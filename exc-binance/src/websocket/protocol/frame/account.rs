@@ -6,7 +6,7 @@ use rust_decimal::Decimal;
 use serde::Deserialize;
 
 use crate::{
-    types::trading::{OrderSide, PositionSide, Status, TimeInForce},
+    types::trading::{self, OrderSide, PositionSide, Status, TimeInForce},
     websocket::error::WsError,
 };
 
@@ -38,6 +38,130 @@ pub enum AccountEvent {
     /// Order update (for spot).
     #[serde(rename = "executionReport")]
     ExecutionReport(ExecutionReport),
+    /// Account update (for USD-M futures).
+    #[serde(rename = "ACCOUNT_UPDATE")]
+    AccountUpdate {
+        /// Event timestamp.
+        #[serde(rename = "E")]
+        event_ts: i64,
+        /// Trade timestamp.
+        #[serde(rename = "T")]
+        trade_ts: i64,
+        /// Account.
+        #[serde(rename = "a")]
+        account: AccountUpdate,
+    },
+    /// Account update (for spot).
+    #[serde(rename = "outboundAccountPosition")]
+    OutboundAccountPosition(OutboundAccountPosition),
+    /// Position updates broken down from an account update (for USD-M futures).
+    #[serde(skip)]
+    PositionsUpdate {
+        /// Trade timestamp.
+        trade_ts: i64,
+        /// Positions.
+        positions: Vec<PositionUpdate>,
+    },
+}
+
+/// Position update in an account update event (for USD-M futures).
+#[derive(Debug, Clone, Deserialize)]
+pub struct PositionUpdate {
+    /// Symbol.
+    #[serde(rename = "s")]
+    pub symbol: Str,
+    /// Position amount.
+    #[serde(rename = "pa")]
+    pub position_amount: Decimal,
+    /// Entry price.
+    #[serde(rename = "ep")]
+    pub entry_price: Decimal,
+    /// Unrealized PnL.
+    #[serde(rename = "up")]
+    pub unrealized_pnl: Decimal,
+    /// Margin type.
+    #[serde(rename = "mt")]
+    pub margin_type: Str,
+    /// Isolated wallet.
+    #[serde(rename = "iw")]
+    pub isolated_wallet: Decimal,
+    /// Position side.
+    #[serde(rename = "ps")]
+    pub position_side: PositionSide,
+}
+
+/// Balance update in an account update event (for USD-M futures).
+#[derive(Debug, Clone, Deserialize)]
+pub struct BalanceUpdate {
+    /// Asset.
+    #[serde(rename = "a")]
+    pub asset: Asset,
+    /// Wallet balance.
+    #[serde(rename = "wb")]
+    pub wallet_balance: Decimal,
+    /// Cross wallet balance.
+    #[serde(rename = "cw")]
+    pub cross_wallet_balance: Decimal,
+    /// Balance change except PnL and commission.
+    #[serde(rename = "bc")]
+    pub balance_change: Decimal,
+}
+
+/// Account update (for USD-M futures).
+#[derive(Debug, Clone, Deserialize)]
+pub struct AccountUpdate {
+    /// Event reason type.
+    #[serde(rename = "m")]
+    pub reason: Str,
+    /// Balances.
+    #[serde(rename = "B")]
+    pub balances: Vec<BalanceUpdate>,
+    /// Positions.
+    #[serde(rename = "P", default)]
+    pub positions: Vec<PositionUpdate>,
+}
+
+/// Balance in an account update event (for spot).
+#[derive(Debug, Clone, Deserialize)]
+pub struct SpotBalanceUpdate {
+    /// Asset.
+    #[serde(rename = "a")]
+    pub asset: Asset,
+    /// Free.
+    #[serde(rename = "f")]
+    pub free: Decimal,
+    /// Locked.
+    #[serde(rename = "l")]
+    pub locked: Decimal,
+}
+
+/// Account update (for spot).
+#[derive(Debug, Clone, Deserialize)]
+pub struct OutboundAccountPosition {
+    /// Event timestamp.
+    #[serde(rename = "E")]
+    pub event_ts: i64,
+    /// Last update timestamp.
+    #[serde(rename = "u")]
+    pub update_ts: i64,
+    /// Balances.
+    #[serde(rename = "B")]
+    pub balances: Vec<SpotBalanceUpdate>,
+}
+
+/// Balance Update Frame.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum BalanceUpdateFrame {
+    /// USD-M Futures.
+    UsdMarginFutures {
+        /// Trade timestamp.
+        trade_ts: i64,
+        /// Account update.
+        account: AccountUpdate,
+    },
+    /// Spot.
+    Spot(OutboundAccountPosition),
 }
 
 /// Order Update Frame.
@@ -64,12 +188,41 @@ pub enum OrderType {
     Stop,
     /// Take profit.
     TakeProfit,
+    /// Stop market.
+    StopMarket,
+    /// Take profit market.
+    TakeProfitMarket,
+    /// Trailing stop market.
+    TrailingStopMarket,
     /// Liquidation.
     Liquidation,
     /// Limit maker.
     LimitMaker,
 }
 
+impl TryFrom<OrderType> for trading::OrderType {
+    type Error = ExchangeError;
+
+    fn try_from(value: OrderType) -> Result<Self, Self::Error> {
+        let ty = match value {
+            OrderType::Market => Self::Market,
+            OrderType::Limit => Self::Limit,
+            OrderType::Stop => Self::Stop,
+            OrderType::TakeProfit => Self::TakeProfit,
+            OrderType::StopMarket => Self::StopMarket,
+            OrderType::TakeProfitMarket => Self::TakeProfitMarket,
+            OrderType::TrailingStopMarket => Self::TrailingStopMarket,
+            OrderType::LimitMaker => Self::LimitMaker,
+            OrderType::Liquidation => {
+                return Err(ExchangeError::Other(anyhow!(
+                    "unsupported order type: {value:?}"
+                )));
+            }
+        };
+        Ok(ty)
+    }
+}
+
 /// Update kind.
 #[derive(Debug, Clone, Copy, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
@@ -375,6 +528,8 @@ impl Nameable for AccountEvent {
                 Name::order_trade_update(&order.symbol.to_lowercase())
             }
             Self::ExecutionReport(r) => Name::order_trade_update(&r.symbol.to_lowercase()),
+            Self::AccountUpdate { .. } | Self::OutboundAccountPosition(_) => Name::balance_update(),
+            Self::PositionsUpdate { .. } => Name::position_update(),
         }
     }
 }
@@ -423,35 +578,125 @@ impl TryFrom<StreamFrame> for OrderUpdateFrame {
     }
 }
 
+impl TryFrom<StreamFrame> for BalanceUpdateFrame {
+    type Error = WsError;
+
+    fn try_from(frame: StreamFrame) -> Result<Self, Self::Error> {
+        match frame.data {
+            StreamFrameKind::AccountEvent(e) => match e {
+                AccountEvent::AccountUpdate {
+                    trade_ts, account, ..
+                } => Ok(Self::UsdMarginFutures { trade_ts, account }),
+                AccountEvent::OutboundAccountPosition(p) => Ok(Self::Spot(p)),
+                e => Err(WsError::UnexpectedFrame(anyhow::anyhow!("{e:?}"))),
+            },
+            e => Err(WsError::UnexpectedFrame(anyhow::anyhow!("{e:?}"))),
+        }
+    }
+}
+
+impl TryFrom<BalanceUpdateFrame> for Vec<types::Balance> {
+    type Error = ExchangeError;
+
+    fn try_from(value: BalanceUpdateFrame) -> Result<Self, Self::Error> {
+        match value {
+            BalanceUpdateFrame::UsdMarginFutures { trade_ts, account } => {
+                let ts = crate::types::adaptations::from_timestamp(trade_ts)?;
+                Ok(account
+                    .balances
+                    .into_iter()
+                    .map(|b| types::Balance {
+                        ts,
+                        asset: b.asset,
+                        total: b.wallet_balance.normalize(),
+                        available: b.cross_wallet_balance.normalize(),
+                        frozen: (b.wallet_balance - b.cross_wallet_balance).normalize(),
+                    })
+                    .collect())
+            }
+            BalanceUpdateFrame::Spot(p) => {
+                let ts = crate::types::adaptations::from_timestamp(p.update_ts)?;
+                Ok(p.balances
+                    .into_iter()
+                    .map(|b| types::Balance {
+                        ts,
+                        asset: b.asset,
+                        total: (b.free + b.locked).normalize(),
+                        available: b.free.normalize(),
+                        frozen: b.locked.normalize(),
+                    })
+                    .collect())
+            }
+        }
+    }
+}
+
+/// Positions Update Frame (for USD-M futures).
+#[derive(Debug, Clone)]
+pub struct PositionsUpdateFrame {
+    /// Trade timestamp.
+    pub trade_ts: i64,
+    /// Positions.
+    pub positions: Vec<PositionUpdate>,
+}
+
+impl TryFrom<StreamFrame> for PositionsUpdateFrame {
+    type Error = WsError;
+
+    fn try_from(frame: StreamFrame) -> Result<Self, Self::Error> {
+        match frame.data {
+            StreamFrameKind::AccountEvent(AccountEvent::PositionsUpdate {
+                trade_ts,
+                positions,
+            }) => Ok(Self {
+                trade_ts,
+                positions,
+            }),
+            e => Err(WsError::UnexpectedFrame(anyhow::anyhow!("{e:?}"))),
+        }
+    }
+}
+
+impl TryFrom<PositionsUpdateFrame> for Vec<types::Position> {
+    type Error = ExchangeError;
+
+    fn try_from(value: PositionsUpdateFrame) -> Result<Self, Self::Error> {
+        let ts = crate::types::adaptations::from_timestamp(value.trade_ts)?;
+        value
+            .positions
+            .into_iter()
+            .map(|p| {
+                Ok(types::Position {
+                    ts,
+                    instrument: Str::new(p.symbol.to_lowercase()),
+                    size: p.position_amount.normalize(),
+                    entry_price: p.entry_price.normalize(),
+                    mark_price: None,
+                    unrealized_pnl: p.unrealized_pnl.normalize(),
+                    liquidation_price: None,
+                    margin_mode: crate::types::adaptations::parse_margin_type(&p.margin_type)?,
+                    leverage: None,
+                })
+            })
+            .collect()
+    }
+}
+
 impl TryFrom<OrderUpdateFrame> for types::OrderUpdate {
     type Error = ExchangeError;
 
     fn try_from(value: OrderUpdateFrame) -> Result<Self, Self::Error> {
         match value {
             OrderUpdateFrame::UsdMarginFutures(update) => {
-                let kind = match update.order_type {
-                    OrderType::Limit => match update.time_in_force {
-                        TimeInForce::Gtc => types::OrderKind::Limit(
-                            update.price.normalize(),
-                            types::TimeInForce::GoodTilCancelled,
-                        ),
-                        TimeInForce::Fok => types::OrderKind::Limit(
-                            update.price.normalize(),
-                            types::TimeInForce::FillOrKill,
-                        ),
-                        TimeInForce::Ioc => types::OrderKind::Limit(
-                            update.price.normalize(),
-                            types::TimeInForce::ImmediateOrCancel,
-                        ),
-                        TimeInForce::Gtx => types::OrderKind::PostOnly(update.price.normalize()),
-                    },
-                    OrderType::Market => types::OrderKind::Market,
-                    other => {
-                        return Err(ExchangeError::Other(anyhow!(
-                            "unsupported order type: {other:?}"
-                        )));
-                    }
-                };
+                let kind = trading::usd_margin_futures_kind(
+                    update.original_order_type.try_into()?,
+                    update.time_in_force,
+                    update.price,
+                    update.trigger_price,
+                    &update.trigger_type,
+                    update.active_price,
+                    update.cr,
+                )?;
                 let mut filled = update.filled_size.abs().normalize();
                 let mut size = update.size.abs().normalize();
                 match update.side {
@@ -464,7 +709,16 @@ impl TryFrom<OrderUpdateFrame> for types::OrderUpdate {
                         size.set_sign_positive(false)
                     }
                 }
-                let status = update.status.try_into()?;
+                let status = match update.status {
+                    Status::New
+                        if trading::OrderType::try_from(update.order_type)
+                            .map(|ty| ty.is_conditional())
+                            .unwrap_or(false) =>
+                    {
+                        types::OrderStatus::Untriggered
+                    }
+                    status => status.try_into()?,
+                };
                 let trade_size = update.last_trade_size.abs().normalize();
                 let trade = if !trade_size.is_zero() {
                     let mut trade = types::OrderTrade {
@@ -501,6 +755,7 @@ impl TryFrom<OrderUpdateFrame> for types::OrderUpdate {
                             fees: HashMap::default(),
                         },
                         trade,
+                        position_side: update.position_side.into(),
                     },
                 })
             }
@@ -585,6 +840,7 @@ impl TryFrom<OrderUpdateFrame> for types::OrderUpdate {
                             fees: HashMap::default(),
                         },
                         trade,
+                        position_side: types::PositionSide::Net,
                     },
                 })
             }
@@ -650,6 +906,7 @@ impl TryFrom<OrderUpdateFrame> for types::OrderUpdate {
                     state,
                     // FIXME: we are not parsing the trades.
                     trade: None,
+                    position_side: types::PositionSide::Net,
                 };
                 Ok(types::OrderUpdate {
                     ts: crate::types::adaptations::from_timestamp(update.update_ts)?,
@@ -659,3 +916,58 @@ impl TryFrom<OrderUpdateFrame> for types::OrderUpdate {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use exc_core::types::{Balance, MarginMode, Position};
+
+    use super::{BalanceUpdateFrame, Name, PositionsUpdateFrame, StreamFrame};
+    use crate::websocket::protocol::frame::ServerFrame;
+
+    #[test]
+    fn test_usd_margin_futures_account_update() -> anyhow::Result<()> {
+        let frame: StreamFrame = serde_json::from_str(
+            r#"{"stream":"listenkey","data":{"e":"ACCOUNT_UPDATE","E":1564745798939,"T":1564745798938,"a":{"m":"ORDER","B":[{"a":"USDT","wb":"122624.12345678","cw":"100.12345678","bc":"50.12345678"}],"P":[]}}}"#,
+        )?;
+        assert_eq!(frame.to_name(), Some(Name::balance_update()));
+        let balances = Vec::<Balance>::try_from(BalanceUpdateFrame::try_from(frame)?)?;
+        assert_eq!(balances.len(), 1);
+        assert_eq!(balances[0].asset.as_ref(), "USDT");
+        assert_eq!(balances[0].total.to_string(), "122624.12345678");
+        assert_eq!(balances[0].frozen.to_string(), "122524");
+        Ok(())
+    }
+
+    #[test]
+    fn test_spot_outbound_account_position() -> anyhow::Result<()> {
+        let frame: StreamFrame = serde_json::from_str(
+            r#"{"stream":"listenkey","data":{"e":"outboundAccountPosition","E":1564034571105,"u":1564034571073,"B":[{"a":"ETH","f":"10000.000000","l":"1.000000"}]}}"#,
+        )?;
+        assert_eq!(frame.to_name(), Some(Name::balance_update()));
+        let balances = Vec::<Balance>::try_from(BalanceUpdateFrame::try_from(frame)?)?;
+        assert_eq!(balances.len(), 1);
+        assert_eq!(balances[0].total.to_string(), "10001");
+        assert_eq!(balances[0].available.to_string(), "10000");
+        assert_eq!(balances[0].frozen.to_string(), "1");
+        Ok(())
+    }
+
+    #[test]
+    fn test_usd_margin_futures_positions_update() -> anyhow::Result<()> {
+        let frame: StreamFrame = serde_json::from_str(
+            r#"{"stream":"listenkey","data":{"e":"ACCOUNT_UPDATE","E":1564745798939,"T":1564745798938,"a":{"m":"ORDER","B":[],"P":[{"s":"BTCUSDT","pa":"-0.5","ep":"9000","cr":"200","up":"-12.5","mt":"isolated","iw":"450.1","ps":"BOTH"}]}}}"#,
+        )?;
+        let mut frames = ServerFrame::Stream(frame).break_down();
+        assert_eq!(frames.len(), 2);
+        let Some(ServerFrame::Stream(frame)) = frames.pop() else {
+            anyhow::bail!("expecting a stream frame");
+        };
+        assert_eq!(frame.to_name(), Some(Name::position_update()));
+        let positions = Vec::<Position>::try_from(PositionsUpdateFrame::try_from(frame)?)?;
+        assert_eq!(positions.len(), 1);
+        assert_eq!(positions[0].instrument.as_str(), "btcusdt");
+        assert_eq!(positions[0].size.to_string(), "-0.5");
+        assert_eq!(positions[0].margin_mode, MarginMode::Isolated);
+        Ok(())
+    }
+}
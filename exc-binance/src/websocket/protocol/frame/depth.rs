@@ -1,7 +1,7 @@
 use rust_decimal::Decimal;
 use serde::Deserialize;
 
-use crate::websocket::error::WsError;
+use crate::{http::response::DepthSnapshot, websocket::error::WsError};
 
 use super::{StreamFrame, StreamFrameKind};
 
@@ -19,10 +19,16 @@ pub struct Depth {
     pub symbol: String,
     /// Trade time.
     #[serde(rename = "T")]
-    pub trade_timestamp: i64,
+    pub trade_timestamp: Option<i64>,
+    /// First update ID.
+    #[serde(rename = "U")]
+    pub first_id: Option<usize>,
     /// Update ID.
     #[serde(rename = "u")]
     pub id: usize,
+    /// Final update ID of the last event (for USD-M futures).
+    #[serde(rename = "pu")]
+    pub prev_id: Option<usize>,
     /// Bids.
     #[serde(rename = "b")]
     pub bids: Vec<(Decimal, Decimal)>,
@@ -42,3 +48,24 @@ impl TryFrom<StreamFrame> for Depth {
         }
     }
 }
+
+/// Order book frame.
+#[derive(Debug, Clone)]
+pub enum OrderBookFrame {
+    /// Snapshot fetched from the rest api.
+    Snapshot(DepthSnapshot),
+    /// Diff depth update.
+    Update(Depth),
+}
+
+impl TryFrom<StreamFrame> for OrderBookFrame {
+    type Error = WsError;
+
+    fn try_from(frame: StreamFrame) -> Result<Self, Self::Error> {
+        match frame.data {
+            StreamFrameKind::DepthSnapshot(s) => Ok(Self::Snapshot(s)),
+            StreamFrameKind::Depth(t) => Ok(Self::Update(t)),
+            _ => Err(WsError::UnexpectedFrame(anyhow::anyhow!("{frame:?}"))),
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use exc_core::{types, ExchangeError};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::{
+    types::{adaptations::from_timestamp, trading::OrderSide},
+    websocket::error::WsError,
+};
+
+use super::{Name, Nameable, StreamFrame, StreamFrameKind};
+
+/// # Example
+/// A [`ForceOrder`] in JSON format:
+/// ```json
+/// {
+///     "e": "forceOrder",              // Event Type
+///     "E": 1568014460893,             // Event Time
+///     "o": {
+///         "s": "BTCUSDT",             // Symbol
+///         "S": "SELL",                // Side
+///         "o": "LIMIT",               // Order Type
+///         "f": "IOC",                 // Time in Force
+///         "q": "0.014",               // Original Quantity
+///         "p": "9910",                // Price
+///         "ap": "9910",               // Average Price
+///         "X": "FILLED",              // Order Status
+///         "l": "0.014",               // Order Last Filled Quantity
+///         "z": "0.014",               // Order Filled Accumulated Quantity
+///         "T": 1568014460893          // Order Trade Time
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+pub struct ForceOrder {
+    /// Event type.
+    #[serde(rename = "e")]
+    pub event: String,
+    /// Event time.
+    #[serde(rename = "E")]
+    pub event_timestamp: i64,
+    /// Order.
+    #[serde(rename = "o")]
+    pub order: LiquidationOrder,
+}
+
+/// Liquidation order.
+#[derive(Debug, Clone, Deserialize)]
+pub struct LiquidationOrder {
+    /// Symbol.
+    #[serde(rename = "s")]
+    pub symbol: String,
+    /// Side.
+    #[serde(rename = "S")]
+    pub side: OrderSide,
+    /// Original quantity.
+    #[serde(rename = "q")]
+    pub size: Decimal,
+    /// Price.
+    #[serde(rename = "p")]
+    pub price: Decimal,
+    /// Average price.
+    #[serde(rename = "ap")]
+    pub avg_price: Decimal,
+    /// Filled quantity.
+    #[serde(rename = "z")]
+    pub filled_size: Decimal,
+    /// Trade time.
+    #[serde(rename = "T")]
+    pub trade_timestamp: i64,
+}
+
+impl Nameable for ForceOrder {
+    fn to_name(&self) -> Name {
+        Name::force_order(&self.order.symbol.to_lowercase())
+    }
+}
+
+impl TryFrom<StreamFrame> for ForceOrder {
+    type Error = WsError;
+
+    fn try_from(frame: StreamFrame) -> Result<Self, Self::Error> {
+        if let StreamFrameKind::ForceOrder(t) = frame.data {
+            Ok(t)
+        } else {
+            Err(WsError::UnexpectedFrame(anyhow::anyhow!("{frame:?}")))
+        }
+    }
+}
+
+impl TryFrom<ForceOrder> for types::Liquidation {
+    type Error = ExchangeError;
+
+    fn try_from(value: ForceOrder) -> Result<Self, Self::Error> {
+        let order = value.order;
+        let (price, size) = if order.filled_size.is_zero() {
+            (order.price, order.size)
+        } else {
+            (order.avg_price, order.filled_size)
+        };
+        let size = size.abs().normalize();
+        Ok(Self {
+            ts: from_timestamp(order.trade_timestamp)?,
+            price: price.normalize(),
+            size: match order.side {
+                OrderSide::Buy => size,
+                OrderSide::Sell => -size,
+            },
+        })
+    }
+}
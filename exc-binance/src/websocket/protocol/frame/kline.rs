@@ -0,0 +1,137 @@
+use exc_core::{types, ExchangeError};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::{types::adaptations::from_timestamp, websocket::error::WsError};
+
+use super::{Name, Nameable, StreamFrame, StreamFrameKind};
+
+/// # Example
+/// A [`Kline`] in JSON format:
+/// ```json
+/// {
+///     "e": "kline",                   // Event type
+///     "E": 1638747660000,             // Event time
+///     "s": "BTCUSDT",                 // Symbol
+///     "k": {
+///         "t": 1638747660000,         // Kline start time
+///         "T": 1638747719999,         // Kline close time
+///         "s": "BTCUSDT",             // Symbol
+///         "i": "1m",                  // Interval
+///         "f": 100,                   // First trade ID
+///         "L": 200,                   // Last trade ID
+///         "o": "0.0010",              // Open price
+///         "c": "0.0020",              // Close price
+///         "h": "0.0025",              // High price
+///         "l": "0.0015",              // Low price
+///         "v": "1000",                // Base asset volume
+///         "n": 100,                   // Number of trades
+///         "x": false,                 // Is this kline closed?
+///         "q": "1.0000",              // Quote asset volume
+///         "V": "500",                 // Taker buy base asset volume
+///         "Q": "0.500",               // Taker buy quote asset volume
+///         "B": "123456"               // Ignore
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+pub struct Kline {
+    /// Event type.
+    #[serde(rename = "e")]
+    pub event: String,
+    /// Event time.
+    #[serde(rename = "E")]
+    pub event_timestamp: i64,
+    /// Symbol.
+    #[serde(rename = "s")]
+    pub symbol: String,
+    /// Kline data.
+    #[serde(rename = "k")]
+    pub kline: KlineData,
+}
+
+/// Kline data.
+#[derive(Debug, Clone, Deserialize)]
+pub struct KlineData {
+    /// Start time.
+    #[serde(rename = "t")]
+    pub start_timestamp: i64,
+    /// Close time.
+    #[serde(rename = "T")]
+    pub close_timestamp: i64,
+    /// Interval.
+    #[serde(rename = "i")]
+    pub interval: String,
+    /// Open.
+    #[serde(rename = "o")]
+    pub open: Decimal,
+    /// High.
+    #[serde(rename = "h")]
+    pub high: Decimal,
+    /// Low.
+    #[serde(rename = "l")]
+    pub low: Decimal,
+    /// Close.
+    #[serde(rename = "c")]
+    pub close: Decimal,
+    /// Volume.
+    #[serde(rename = "v")]
+    pub volume: Decimal,
+    /// Is closed.
+    #[serde(rename = "x")]
+    pub is_closed: bool,
+}
+
+impl Nameable for Kline {
+    fn to_name(&self) -> Name {
+        Name::kline(&self.symbol.to_lowercase(), &self.kline.interval)
+    }
+}
+
+impl TryFrom<StreamFrame> for Kline {
+    type Error = WsError;
+
+    fn try_from(frame: StreamFrame) -> Result<Self, Self::Error> {
+        if let StreamFrameKind::Kline(t) = frame.data {
+            Ok(t)
+        } else {
+            Err(WsError::UnexpectedFrame(anyhow::anyhow!("{frame:?}")))
+        }
+    }
+}
+
+impl TryFrom<Kline> for types::CandleUpdate {
+    type Error = ExchangeError;
+
+    fn try_from(value: Kline) -> Result<Self, Self::Error> {
+        let k = value.kline;
+        Ok(Self {
+            candle: types::Candle {
+                ts: from_timestamp(k.start_timestamp)?,
+                open: k.open.normalize(),
+                high: k.high.normalize(),
+                low: k.low.normalize(),
+                close: k.close.normalize(),
+                volume: k.volume.normalize(),
+            },
+            is_closed: k.is_closed,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_kline_frame() -> anyhow::Result<()> {
+        let frame: StreamFrame = serde_json::from_str(
+            r#"{"stream":"btcusdt@kline_1m","data":{"e":"kline","E":1638747660000,"s":"BTCUSDT","k":{"t":1638747660000,"T":1638747719999,"s":"BTCUSDT","i":"1m","f":100,"L":200,"o":"0.0010","c":"0.0020","h":"0.0025","l":"0.0015","v":"1000","n":100,"x":true,"q":"1.0000","V":"500","Q":"0.500","B":"123456"}}}"#,
+        )?;
+        assert_eq!(frame.to_name(), Some(Name::kline("btcusdt", "1m")));
+        let update = types::CandleUpdate::try_from(Kline::try_from(frame)?)?;
+        assert!(update.is_closed);
+        assert_eq!(update.candle.close.to_string(), "0.002");
+        Ok(())
+    }
+}
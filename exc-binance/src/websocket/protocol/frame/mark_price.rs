@@ -0,0 +1,126 @@
+use exc_core::{types, ExchangeError};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use serde_with::{serde_as, NoneAsEmptyString};
+
+use crate::{types::adaptations::from_timestamp, websocket::error::WsError};
+
+use super::{Name, Nameable, StreamFrame, StreamFrameKind};
+
+/// # Example
+/// A [`MarkPrice`] in JSON format:
+/// ```json
+/// {
+///     "e": "markPriceUpdate",     // Event type
+///     "E": 1562305380000,         // Event time
+///     "s": "BTCUSDT",             // Symbol
+///     "p": "11794.15000000",      // Mark price
+///     "i": "11784.62659091",      // Index price
+///     "P": "11784.25641265",      // Estimated Settle Price
+///     "r": "0.00038167",          // Funding rate
+///     "T": 1562306400000          // Next funding time
+/// }
+/// ```
+#[serde_as]
+#[derive(Debug, Clone, Deserialize)]
+pub struct MarkPrice {
+    /// Event type.
+    #[serde(rename = "e")]
+    pub event: String,
+    /// Event time.
+    #[serde(rename = "E")]
+    pub event_timestamp: i64,
+    /// Symbol.
+    #[serde(rename = "s")]
+    pub symbol: String,
+    /// Mark price.
+    #[serde(rename = "p")]
+    pub mark_price: Decimal,
+    /// Index price.
+    #[serde(rename = "i")]
+    pub index_price: Decimal,
+    /// Funding rate (empty for delivery contracts).
+    #[serde_as(as = "NoneAsEmptyString")]
+    #[serde(rename = "r")]
+    pub funding_rate: Option<Decimal>,
+    /// Next funding time.
+    #[serde(rename = "T")]
+    pub next_funding_time: i64,
+}
+
+impl Nameable for MarkPrice {
+    fn to_name(&self) -> Name {
+        Name::mark_price(&self.symbol.to_lowercase())
+    }
+}
+
+impl TryFrom<StreamFrame> for MarkPrice {
+    type Error = WsError;
+
+    fn try_from(frame: StreamFrame) -> Result<Self, Self::Error> {
+        if let StreamFrameKind::MarkPrice(t) = frame.data {
+            Ok(t)
+        } else {
+            Err(WsError::UnexpectedFrame(anyhow::anyhow!("{frame:?}")))
+        }
+    }
+}
+
+impl TryFrom<MarkPrice> for types::MarkPrice {
+    type Error = ExchangeError;
+
+    fn try_from(value: MarkPrice) -> Result<Self, Self::Error> {
+        Ok(Self {
+            ts: from_timestamp(value.event_timestamp)?,
+            price: value.mark_price.normalize(),
+        })
+    }
+}
+
+impl TryFrom<MarkPrice> for types::IndexPrice {
+    type Error = ExchangeError;
+
+    fn try_from(value: MarkPrice) -> Result<Self, Self::Error> {
+        Ok(Self {
+            ts: from_timestamp(value.event_timestamp)?,
+            price: value.index_price.normalize(),
+        })
+    }
+}
+
+impl TryFrom<MarkPrice> for types::FundingRate {
+    type Error = ExchangeError;
+
+    fn try_from(value: MarkPrice) -> Result<Self, Self::Error> {
+        let Some(rate) = value.funding_rate else {
+            return Err(ExchangeError::Unsupported(anyhow::anyhow!(
+                "no funding rate for {}",
+                value.symbol
+            )));
+        };
+        Ok(Self {
+            ts: from_timestamp(value.event_timestamp)?,
+            rate: rate.normalize(),
+            funding_time: from_timestamp(value.next_funding_time)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_mark_price_frame() -> anyhow::Result<()> {
+        let frame: StreamFrame = serde_json::from_str(
+            r#"{"stream":"btcusdt@markPrice","data":{"e":"markPriceUpdate","E":1562305380000,"s":"BTCUSDT","p":"11794.15000000","i":"11784.62659091","P":"11784.25641265","r":"0.00038167","T":1562306400000}}"#,
+        )?;
+        assert_eq!(frame.to_name(), Some(Name::mark_price("btcusdt")));
+        let price = MarkPrice::try_from(frame)?;
+        let rate = types::FundingRate::try_from(price.clone())?;
+        assert_eq!(rate.rate.to_string(), "0.00038167");
+        let index = types::IndexPrice::try_from(price)?;
+        assert_eq!(index.price.to_string(), "11784.62659091");
+        Ok(())
+    }
+}
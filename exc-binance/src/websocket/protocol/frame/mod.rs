@@ -23,6 +23,18 @@ pub mod book_ticker;
 /// Depth.
 pub mod depth;
 
+/// Mark price.
+pub mod mark_price;
+
+/// Force order (liquidation).
+pub mod force_order;
+
+/// Open interest.
+pub mod open_interest;
+
+/// Kline.
+pub mod kline;
+
 /// Account.
 pub mod account;
 
@@ -82,6 +94,38 @@ impl Name {
         }
     }
 
+    /// Mark price
+    pub fn mark_price(inst: &str) -> Self {
+        Self {
+            inst: Some(inst.to_string()),
+            channel: "markPrice".to_string(),
+        }
+    }
+
+    /// Force order
+    pub fn force_order(inst: &str) -> Self {
+        Self {
+            inst: Some(inst.to_string()),
+            channel: "forceOrder".to_string(),
+        }
+    }
+
+    /// Open interest (polled from the rest api)
+    pub fn open_interest(inst: &str) -> Self {
+        Self {
+            inst: Some(inst.to_string()),
+            channel: "openInterest".to_string(),
+        }
+    }
+
+    /// Kline
+    pub fn kline(inst: &str, interval: &str) -> Self {
+        Self {
+            inst: Some(inst.to_string()),
+            channel: format!("kline_{interval}"),
+        }
+    }
+
     /// Depth
     pub fn depth(inst: &str, levels: &str, rate: &str) -> Self {
         Self {
@@ -99,6 +143,16 @@ impl Name {
     pub fn order_trade_update(inst: &str) -> Self {
         Self::new("orderTradeUpdate").with_inst(inst)
     }
+
+    /// Balance update.
+    pub fn balance_update() -> Self {
+        Self::new("balanceUpdate")
+    }
+
+    /// Position update.
+    pub fn position_update() -> Self {
+        Self::new("positionUpdate")
+    }
 }
 
 impl fmt::Display for Name {
@@ -209,6 +263,20 @@ impl ServerFrame {
                         })
                         .collect()
                 }
+                StreamFrameKind::AccountEvent(AccountEvent::AccountUpdate {
+                    trade_ts,
+                    account,
+                    ..
+                }) if !account.positions.is_empty() => {
+                    let frame = StreamFrame {
+                        stream: f.stream.clone(),
+                        data: StreamFrameKind::AccountEvent(AccountEvent::PositionsUpdate {
+                            trade_ts: *trade_ts,
+                            positions: account.positions.clone(),
+                        }),
+                    };
+                    vec![self, Self::Stream(frame)]
+                }
                 _ => vec![self],
             },
         }
@@ -234,6 +302,18 @@ pub enum StreamFrameKind {
     BookTicker(BookTicker),
     /// Depth.
     Depth(depth::Depth),
+    /// Mark price.
+    MarkPrice(mark_price::MarkPrice),
+    /// Force order.
+    ForceOrder(force_order::ForceOrder),
+    /// Kline.
+    Kline(kline::Kline),
+    /// Open interest (fetched from the rest api, never received from the stream).
+    #[serde(skip)]
+    OpenInterest(crate::http::response::OpenInterest),
+    /// Order book snapshot (fetched from the rest api, never received from the stream).
+    #[serde(skip)]
+    DepthSnapshot(crate::http::response::DepthSnapshot),
     /// Account event.
     AccountEvent(AccountEvent),
     /// Options Order Update.
@@ -260,6 +340,10 @@ impl StreamFrame {
             StreamFrameKind::AggTrade(f) => Some(f.to_name()),
             StreamFrameKind::Trade(f) => Some(f.to_name()),
             StreamFrameKind::BookTicker(f) => Some(f.to_name()),
+            StreamFrameKind::MarkPrice(f) => Some(f.to_name()),
+            StreamFrameKind::ForceOrder(f) => Some(f.to_name()),
+            StreamFrameKind::Kline(f) => Some(f.to_name()),
+            StreamFrameKind::OpenInterest(f) => Some(Name::open_interest(&f.symbol.to_lowercase())),
             StreamFrameKind::Depth(_) => {
                 let (inst, channel) = self.stream.split_once('@')?;
                 Some(Name {
@@ -269,7 +353,7 @@ impl StreamFrame {
             }
             StreamFrameKind::AccountEvent(e) => Some(e.to_name()),
             StreamFrameKind::OptionsOrder(e) => Some(e.to_name()),
-            StreamFrameKind::OptionsOrderUpdate(_) => None,
+            StreamFrameKind::OptionsOrderUpdate(_) | StreamFrameKind::DepthSnapshot(_) => None,
             StreamFrameKind::Unknwon(_) => {
                 let (inst, channel) = self.stream.split_once('@')?;
                 Some(Name {
@@ -372,7 +456,9 @@ impl TryFrom<DepthFrame> for exc_core::types::BidAsk {
                 ask: Some((t.ask.normalize(), t.ask_size.normalize())),
             }),
             DepthFrame::Depth(t) => Ok(exc_core::types::BidAsk {
-                ts: crate::types::adaptations::from_timestamp(t.trade_timestamp)?,
+                ts: crate::types::adaptations::from_timestamp(
+                    t.trade_timestamp.unwrap_or(t.event_timestamp),
+                )?,
                 bid: t.bids.first().map(|b| (b.0.normalize(), b.1.normalize())),
                 ask: t.asks.first().map(|a| (a.0.normalize(), a.1.normalize())),
             }),
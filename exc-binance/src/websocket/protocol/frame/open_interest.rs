@@ -0,0 +1,31 @@
+use exc_core::{types, ExchangeError};
+
+use crate::{
+    http::response::OpenInterest, types::adaptations::from_timestamp, websocket::error::WsError,
+};
+
+use super::{StreamFrame, StreamFrameKind};
+
+impl TryFrom<StreamFrame> for OpenInterest {
+    type Error = WsError;
+
+    fn try_from(frame: StreamFrame) -> Result<Self, Self::Error> {
+        if let StreamFrameKind::OpenInterest(t) = frame.data {
+            Ok(t)
+        } else {
+            Err(WsError::UnexpectedFrame(anyhow::anyhow!("{frame:?}")))
+        }
+    }
+}
+
+impl TryFrom<OpenInterest> for types::OpenInterest {
+    type Error = ExchangeError;
+
+    fn try_from(value: OpenInterest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            ts: from_timestamp(value.time)?,
+            size: value.open_interest.normalize(),
+            value: None,
+        })
+    }
+}
@@ -7,17 +7,29 @@ use std::{
 };
 
 use self::{
-    frame::Name,
+    frame::{Name, StreamFrame, StreamFrameKind},
     stream::{MultiplexRequest, MultiplexResponse},
 };
 
 use super::response::WsResponse;
-use super::{connect::BinanceWsHost, request::WsRequest};
+use super::{
+    connect::{BinanceWsHost, Http},
+    request::WsRequest,
+};
 use super::{error::WsError, request::RequestKind};
-use exc_core::transport::websocket::WsStream;
-use futures::{future::BoxFuture, FutureExt, Sink, SinkExt, Stream, TryFutureExt, TryStreamExt};
+use crate::http::{
+    request::{GetDepth, GetOpenInterest, RestRequest},
+    response::{DepthSnapshot, OpenInterest},
+};
+use exc_core::{
+    transport::websocket::WsStream,
+    types::{SubscribeOpenInterest, SubscribeOrderBook},
+};
+use futures::{
+    future::BoxFuture, FutureExt, Sink, SinkExt, Stream, StreamExt, TryFutureExt, TryStreamExt,
+};
 use tokio_tower::multiplex::{Client as Multiplex, TagStore};
-use tower::Service;
+use tower::{Service, ServiceExt};
 
 /// Multiplex protocol.
 pub mod stream;
@@ -31,6 +43,10 @@ pub mod keep_alive;
 type Req = MultiplexRequest;
 type Resp = MultiplexResponse;
 
+const SNAPSHOT_LIMIT: usize = 1000;
+
+const OPEN_INTEREST_INTERVAL: Duration = Duration::from_secs(5);
+
 trait Transport: Sink<Req, Error = WsError> + Stream<Item = Result<Resp, WsError>> {}
 
 impl<T> Transport for T
@@ -145,6 +161,7 @@ pub struct WsClient {
     state: Arc<stream::Shared>,
     svc: Multiplex<Protocol, WsError, Req>,
     reconnect: bool,
+    pub(crate) rest: Option<Http>,
 }
 
 impl WsClient {
@@ -175,9 +192,102 @@ impl WsClient {
             svc,
             state,
             reconnect: false,
+            rest: None,
         })
     }
 
+    fn order_book(
+        &mut self,
+        book: SubscribeOrderBook,
+    ) -> BoxFuture<'static, Result<WsResponse, WsError>> {
+        if matches!(self.endpoint, BinanceWsHost::EuropeanOptions) {
+            return futures::future::ready(Err(WsError::UnexpectedFrame(anyhow::anyhow!(
+                "order book is not supported by european options"
+            ))))
+            .boxed();
+        }
+        let Some(rest) = self.rest.clone() else {
+            return futures::future::ready(Err(WsError::UnexpectedFrame(anyhow::anyhow!(
+                "order book requires the rest api"
+            ))))
+            .boxed();
+        };
+        let name = Name::depth(&book.instrument, "", "100ms");
+        let RequestKind::Multiplex(req) = WsRequest::sub_stream(name.clone()).inner else {
+            unreachable!()
+        };
+        let diffs = self.svc.call(req);
+        async move {
+            let resp: WsResponse = diffs.await?.into();
+            let WsResponse::Stream(diffs) = resp.stream().await? else {
+                return Err(WsError::NoResponse);
+            };
+            // Fetch the snapshot after subscribing so that no update is missed.
+            let snapshot = rest
+                .oneshot(RestRequest::with_payload(GetDepth {
+                    symbol: book.instrument.to_uppercase(),
+                    limit: Some(SNAPSHOT_LIMIT),
+                }))
+                .await?
+                .into_response::<DepthSnapshot>()?;
+            let snapshot = StreamFrame {
+                stream: name.to_string(),
+                data: StreamFrameKind::DepthSnapshot(snapshot),
+            };
+            Ok(WsResponse::Stream(
+                futures::stream::once(futures::future::ready(Ok(snapshot)))
+                    .chain(diffs)
+                    .boxed(),
+            ))
+        }
+        .boxed()
+    }
+
+    fn open_interest(
+        &mut self,
+        oi: SubscribeOpenInterest,
+    ) -> BoxFuture<'static, Result<WsResponse, WsError>> {
+        if !matches!(self.endpoint, BinanceWsHost::UsdMarginFutures) {
+            return futures::future::ready(Err(WsError::UnexpectedFrame(anyhow::anyhow!(
+                "open interest is only supported by usd-margin futures"
+            ))))
+            .boxed();
+        }
+        let Some(rest) = self.rest.clone() else {
+            return futures::future::ready(Err(WsError::UnexpectedFrame(anyhow::anyhow!(
+                "open interest requires the rest api"
+            ))))
+            .boxed();
+        };
+        // There is no open interest stream, so we poll the rest api instead.
+        let name = Name::open_interest(&oi.instrument);
+        let symbol = oi.instrument.to_uppercase();
+        let stream = async_stream::try_stream! {
+            let mut interval = tokio::time::interval(OPEN_INTEREST_INTERVAL);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            let mut last = None;
+            loop {
+                interval.tick().await;
+                let oi = rest
+                    .clone()
+                    .oneshot(RestRequest::with_payload(GetOpenInterest {
+                        symbol: symbol.clone(),
+                    }))
+                    .await?
+                    .into_response::<OpenInterest>()?;
+                if last == Some(oi.time) {
+                    continue;
+                }
+                last = Some(oi.time);
+                yield StreamFrame {
+                    stream: name.to_string(),
+                    data: StreamFrameKind::OpenInterest(oi),
+                };
+            }
+        };
+        futures::future::ready(Ok(WsResponse::Stream(stream.boxed()))).boxed()
+    }
+
     fn dispatch(&self, req: WsRequest) -> WsRequest {
         tracing::trace!(
             "ws client; dispatching request with endpoint: {:?}",
@@ -244,6 +354,8 @@ impl Service<WsRequest> for WsClient {
                         })
                         .boxed()
                 }
+                RequestKind::DispatchOrderBook(book) => return self.order_book(book),
+                RequestKind::DispatchOpenInterest(oi) => return self.open_interest(oi),
                 RequestKind::Reconnect => {
                     self.reconnect = true;
                     return futures::future::ready(Ok(WsResponse::Reconnected)).boxed();
@@ -10,6 +10,8 @@ pub(crate) enum RequestKind {
     DispatchSubscribe(Name),
     DispatchTrades(exc_core::types::SubscribeTrades),
     DispatchBidAsk(exc_core::types::SubscribeBidAsk),
+    DispatchOrderBook(exc_core::types::SubscribeOrderBook),
+    DispatchOpenInterest(exc_core::types::SubscribeOpenInterest),
     Multiplex(MultiplexRequest),
     Reconnect,
 }
@@ -21,6 +23,8 @@ impl RequestKind {
             Self::Reconnect => Self::Reconnect,
             Self::DispatchTrades(req) => Self::DispatchTrades(req),
             Self::DispatchBidAsk(req) => Self::DispatchBidAsk(req),
+            Self::DispatchOrderBook(req) => Self::DispatchOrderBook(req),
+            Self::DispatchOpenInterest(req) => Self::DispatchOpenInterest(req),
             Self::DispatchSubscribe(req) => Self::DispatchSubscribe(req),
         }
     }
@@ -98,6 +102,22 @@ impl WsRequest {
             inner: RequestKind::DispatchBidAsk(bid_ask),
         }
     }
+
+    /// Dispatch order book.
+    pub fn dispatch_order_book(book: exc_core::types::SubscribeOrderBook) -> Self {
+        Self {
+            stream: true,
+            inner: RequestKind::DispatchOrderBook(book),
+        }
+    }
+
+    /// Dispatch open interest.
+    pub fn dispatch_open_interest(oi: exc_core::types::SubscribeOpenInterest) -> Self {
+        Self {
+            stream: true,
+            inner: RequestKind::DispatchOpenInterest(oi),
+        }
+    }
 }
 
 // impl From<WsRequest> for MultiplexRequest {
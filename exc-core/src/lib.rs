@@ -15,6 +15,10 @@ pub use exc_types as types;
 /// Retry utils.
 pub use exc_service::retry;
 
+#[cfg(feature = "limit")]
+/// Weight-based rate limit.
+pub use exc_service::weight;
+
 /// Utils for creating [`ExcService`](exc_service::ExcService).
 pub mod util;
 
@@ -0,0 +1,124 @@
+use std::{
+    sync::{Arc, Weak},
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use exc_service::ExcService;
+use exc_types::{CancelAllAfter, Str};
+use futures::TryFutureExt;
+use tokio::time::MissedTickBehavior;
+use tower::{Layer, Service, ServiceExt};
+
+/// Dead man's switch layer.
+///
+/// Keep a [`CancelAllAfter`] switch armed by re-sending the request
+/// periodically in a background task while the service is alive.
+/// The switch is left armed after the service (and all its clones)
+/// is dropped, so the orders will be cancelled once the timeout elapses.
+#[derive(Debug, Clone)]
+pub struct DeadManSwitchLayer {
+    instrument: Option<Str>,
+    timeout: Duration,
+    interval: Duration,
+}
+
+impl DeadManSwitchLayer {
+    /// Create a new dead man's switch layer with the given timeout.
+    /// The switch is renewed every half of the timeout by default.
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            instrument: None,
+            timeout,
+            interval: timeout / 2,
+        }
+    }
+
+    /// Only cancel the orders of the given instrument.
+    pub fn instrument(&mut self, inst: impl AsRef<str>) -> &mut Self {
+        self.instrument = Some(Str::new(inst));
+        self
+    }
+
+    /// Set the interval to renew the switch.
+    pub fn interval(&mut self, interval: Duration) -> &mut Self {
+        self.interval = interval;
+        self
+    }
+}
+
+impl<S> Layer<S> for DeadManSwitchLayer
+where
+    S: ExcService<CancelAllAfter> + Clone + Send + 'static,
+    S::Future: Send,
+{
+    type Service = DeadManSwitch<S>;
+
+    /// # Panic
+    /// Panic if it is not called within a tokio runtime.
+    fn layer(&self, inner: S) -> Self::Service {
+        let alive = Arc::new(());
+        tokio::spawn(keep_armed(
+            inner.clone(),
+            CancelAllAfter {
+                instrument: self.instrument.clone(),
+                timeout: self.timeout,
+            },
+            self.interval,
+            Arc::downgrade(&alive),
+        ));
+        DeadManSwitch {
+            inner,
+            _alive: alive,
+        }
+    }
+}
+
+async fn keep_armed<S>(mut svc: S, req: CancelAllAfter, interval: Duration, alive: Weak<()>)
+where
+    S: ExcService<CancelAllAfter> + Send,
+    S::Future: Send,
+{
+    let mut interval = tokio::time::interval(interval);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    loop {
+        interval.tick().await;
+        if alive.strong_count() == 0 {
+            break;
+        }
+        match svc.as_service().oneshot(req.clone()).try_flatten().await {
+            Ok(()) => {
+                tracing::trace!(timeout=?req.timeout, "dead man's switch renewed");
+            }
+            Err(err) => {
+                tracing::error!(%err, "failed to renew dead man's switch");
+            }
+        }
+    }
+    tracing::debug!("dead man's switch is no longer renewed");
+}
+
+/// Dead man's switch service.
+/// It is transparent to the requests.
+#[derive(Debug, Clone)]
+pub struct DeadManSwitch<S> {
+    inner: S,
+    _alive: Arc<()>,
+}
+
+impl<S, R> Service<R> for DeadManSwitch<S>
+where
+    S: Service<R>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: R) -> Self::Future {
+        self.inner.call(req)
+    }
+}
@@ -0,0 +1,125 @@
+use async_stream::stream;
+use futures::{future::BoxFuture, FutureExt, StreamExt, TryFutureExt};
+use std::{collections::HashSet, num::NonZeroUsize, ops::Bound};
+use time::OffsetDateTime;
+use tower::{buffer::Buffer, Layer, Service, ServiceExt};
+
+use crate::{
+    types::trading::{FetchFills, FetchLastFills, FillStream},
+    ExcService, ExcServiceExt, ExchangeError, IntoService,
+};
+
+const DEFAULT_BOUND: usize = 64;
+
+/// Fetch fills backward layer.
+pub struct FetchFillsBackwardLayer {
+    bound: usize,
+    limit: NonZeroUsize,
+}
+
+impl FetchFillsBackwardLayer {
+    /// Create a new fetch fills backward layer.
+    /// # Panic
+    /// Panic if `limit` is zero.
+    pub fn new(limit: usize, bound: usize) -> Self {
+        Self {
+            bound: bound + 1,
+            limit: NonZeroUsize::new(limit).unwrap(),
+        }
+    }
+
+    /// Create a new fetch fills backward layer, with default buffer bound.
+    /// # Panic
+    /// Panic if `limit` is zero.
+    pub fn with_default_bound(limit: usize) -> Self {
+        Self::new(limit, DEFAULT_BOUND)
+    }
+}
+
+impl<S> Layer<S> for FetchFillsBackwardLayer
+where
+    S: ExcService<FetchLastFills> + Send + 'static,
+    S::Future: Send,
+{
+    type Service = FetchFillsBackward<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        FetchFillsBackward {
+            svc: Buffer::new(inner.into_service(), self.bound),
+            limit: self.limit,
+        }
+    }
+}
+
+/// Fetch fills backward.
+pub struct FetchFillsBackward<S>
+where
+    S: ExcService<FetchLastFills> + 'static,
+{
+    svc: Buffer<IntoService<S, FetchLastFills>, FetchLastFills>,
+    limit: NonZeroUsize,
+}
+
+impl<S> Service<FetchFills> for FetchFillsBackward<S>
+where
+    S: ExcService<FetchLastFills> + 'static,
+    S::Future: Send,
+{
+    type Response = FillStream;
+    type Error = ExchangeError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        Service::poll_ready(&mut self.svc, cx).map_err(ExchangeError::from)
+    }
+
+    fn call(&mut self, query: FetchFills) -> Self::Future {
+        let mut query = FetchLastFills {
+            query,
+            last: self.limit.get(),
+        };
+        let mut svc = self.svc.clone();
+        async move {
+            let stream = stream! {
+                // Fills at the same time as the oldest one may be split across pages,
+                // so the next page includes it and we skip the seen ones.
+                let mut seen = HashSet::new();
+                loop {
+                    if query.query.is_empty() {
+                        break;
+                    }
+                    let fills = match (&mut svc)
+                        .oneshot(query.clone())
+                        .map_err(ExchangeError::Layer)
+                        .and_then(|fut| fut)
+                        .await
+                    {
+                        Ok(fills) => fills,
+                        Err(err) => {
+                            yield Err(err);
+                            break;
+                        }
+                    };
+                    let mut oldest = None;
+                    let mut fresh = false;
+                    for fill in fills {
+                        oldest = Some(oldest.map_or(fill.ts, |ts: OffsetDateTime| ts.min(fill.ts)));
+                        if seen.insert((fill.order_id.clone(), fill.trade_id.clone())) {
+                            fresh = true;
+                            yield Ok(fill);
+                        }
+                    }
+                    match oldest {
+                        Some(oldest) if fresh => query.query.end = Bound::Included(oldest),
+                        _ => break,
+                    }
+                }
+            };
+            Ok(stream.boxed())
+        }
+        .boxed()
+    }
+}
@@ -0,0 +1,127 @@
+use async_stream::stream;
+use futures::{future::BoxFuture, FutureExt, StreamExt, TryFutureExt};
+use std::{collections::HashSet, num::NonZeroUsize, ops::Bound};
+use tower::{buffer::Buffer, Layer, Service, ServiceExt};
+
+use crate::{
+    types::trading::{FetchLastOrders, FetchOrderHistory, OrderStream},
+    ExcService, ExcServiceExt, ExchangeError, IntoService,
+};
+
+const DEFAULT_BOUND: usize = 64;
+
+/// Fetch orders backward layer.
+pub struct FetchOrdersBackwardLayer {
+    bound: usize,
+    limit: NonZeroUsize,
+}
+
+impl FetchOrdersBackwardLayer {
+    /// Create a new fetch orders backward layer.
+    /// # Panic
+    /// Panic if `limit` is zero.
+    pub fn new(limit: usize, bound: usize) -> Self {
+        Self {
+            bound: bound + 1,
+            limit: NonZeroUsize::new(limit).unwrap(),
+        }
+    }
+
+    /// Create a new fetch orders backward layer, with default buffer bound.
+    /// # Panic
+    /// Panic if `limit` is zero.
+    pub fn with_default_bound(limit: usize) -> Self {
+        Self::new(limit, DEFAULT_BOUND)
+    }
+}
+
+impl<S> Layer<S> for FetchOrdersBackwardLayer
+where
+    S: ExcService<FetchLastOrders> + Send + 'static,
+    S::Future: Send,
+{
+    type Service = FetchOrdersBackward<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        FetchOrdersBackward {
+            svc: Buffer::new(inner.into_service(), self.bound),
+            limit: self.limit,
+        }
+    }
+}
+
+/// Fetch orders backward.
+pub struct FetchOrdersBackward<S>
+where
+    S: ExcService<FetchLastOrders> + 'static,
+{
+    svc: Buffer<IntoService<S, FetchLastOrders>, FetchLastOrders>,
+    limit: NonZeroUsize,
+}
+
+impl<S> Service<FetchOrderHistory> for FetchOrdersBackward<S>
+where
+    S: ExcService<FetchLastOrders> + 'static,
+    S::Future: Send,
+{
+    type Response = OrderStream;
+    type Error = ExchangeError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        Service::poll_ready(&mut self.svc, cx).map_err(ExchangeError::from)
+    }
+
+    fn call(&mut self, query: FetchOrderHistory) -> Self::Future {
+        let mut query = FetchLastOrders {
+            query,
+            last: self.limit.get(),
+        };
+        let mut svc = self.svc.clone();
+        async move {
+            let stream = stream! {
+                // Orders created at the same time as the oldest one may be split
+                // across pages, so the next page includes it and we skip the seen ones.
+                let mut seen = HashSet::new();
+                loop {
+                    if query.query.is_empty() {
+                        break;
+                    }
+                    let page = match (&mut svc)
+                        .oneshot(query.clone())
+                        .map_err(ExchangeError::Layer)
+                        .and_then(|fut| fut)
+                        .await
+                    {
+                        Ok(page) => page,
+                        Err(err) => {
+                            yield Err(err);
+                            break;
+                        }
+                    };
+                    let mut fresh = false;
+                    for order in page.orders {
+                        match order {
+                            Ok(update) => {
+                                if seen.insert(update.order.id.clone()) {
+                                    fresh = true;
+                                    yield Ok(update);
+                                }
+                            }
+                            Err(err) => yield Err(err),
+                        }
+                    }
+                    match page.oldest {
+                        Some(oldest) if fresh => query.query.end = Bound::Included(oldest),
+                        _ => break,
+                    }
+                }
+            };
+            Ok(stream.boxed())
+        }
+        .boxed()
+    }
+}
@@ -0,0 +1,303 @@
+use std::{
+    fmt,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use exc_service::{traits::IntoService, ExcService, ExcServiceExt, ExchangeError, Request};
+use exc_types::{
+    CandleStream, FetchInstruments, GetOrder, InstrumentStream, QueryCandles, QueryFirstCandles,
+    QueryLastCandles,
+};
+use futures::{
+    future::{select_ok, BoxFuture, MapErr},
+    stream::{self, BoxStream},
+    FutureExt, StreamExt, TryFutureExt,
+};
+use tower::{
+    balance::p2c::Balance,
+    discover::ServiceList,
+    load::{CompleteOnResponse, PeakEwma},
+    BoxError, Service, ServiceExt,
+};
+
+const DEFAULT_DECAY: Duration = Duration::from_secs(10);
+
+/// Read-only requests that are safe to be sent to multiple backends.
+pub trait Hedgeable: Request + Clone + Send + 'static {
+    /// Wait until the response is known to be successful,
+    /// so that a failure can be told apart before the response is chosen.
+    fn settle(resp: Self::Response) -> BoxFuture<'static, Result<Self::Response, ExchangeError>>;
+}
+
+/// Wait for the first item of the stream.
+async fn settle_stream<T: Send + 'static>(
+    mut stream: BoxStream<'static, Result<T, ExchangeError>>,
+) -> Result<BoxStream<'static, Result<T, ExchangeError>>, ExchangeError> {
+    match stream.next().await {
+        Some(Ok(first)) => Ok(stream::once(async move { Ok(first) }).chain(stream).boxed()),
+        Some(Err(err)) => Err(err),
+        None => Ok(stream::empty().boxed()),
+    }
+}
+
+async fn settle_candles(candles: CandleStream) -> Result<CandleStream, ExchangeError> {
+    let forward = candles.is_forward();
+    let candles = settle_stream(candles.boxed()).await?;
+    if forward {
+        Ok(CandleStream::new_forward(candles))
+    } else {
+        Ok(CandleStream::new_backward(candles))
+    }
+}
+
+impl Hedgeable for GetOrder {
+    fn settle(resp: Self::Response) -> BoxFuture<'static, Result<Self::Response, ExchangeError>> {
+        async move {
+            let update = resp.await?;
+            Ok(futures::future::ready(Ok(update)).boxed())
+        }
+        .boxed()
+    }
+}
+
+impl Hedgeable for QueryCandles {
+    fn settle(resp: Self::Response) -> BoxFuture<'static, Result<Self::Response, ExchangeError>> {
+        settle_candles(resp).boxed()
+    }
+}
+
+impl Hedgeable for QueryFirstCandles {
+    fn settle(resp: Self::Response) -> BoxFuture<'static, Result<Self::Response, ExchangeError>> {
+        settle_candles(resp).boxed()
+    }
+}
+
+impl Hedgeable for QueryLastCandles {
+    fn settle(resp: Self::Response) -> BoxFuture<'static, Result<Self::Response, ExchangeError>> {
+        settle_candles(resp).boxed()
+    }
+}
+
+impl Hedgeable for FetchInstruments {
+    fn settle(
+        resp: InstrumentStream,
+    ) -> BoxFuture<'static, Result<InstrumentStream, ExchangeError>> {
+        settle_stream(resp).boxed()
+    }
+}
+
+/// Hedge service.
+///
+/// Send each [`Hedgeable`] request to all the backends and return the first
+/// successful answer, dropping the others. The last error is returned if all fail.
+#[derive(Debug, Clone)]
+pub struct Hedge<S> {
+    backends: Vec<S>,
+    delay: Duration,
+}
+
+impl<S> Hedge<S> {
+    /// Create a hedge service over the given backends.
+    pub fn new(backends: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            backends: backends.into_iter().collect(),
+            delay: Duration::ZERO,
+        }
+    }
+
+    /// Send the request to the `i`-th backend only after `i` times the delay
+    /// has elapsed without an answer. Defaults to zero, i.e. all at once.
+    pub fn delay(&mut self, delay: Duration) -> &mut Self {
+        self.delay = delay;
+        self
+    }
+}
+
+impl<S, R> Service<R> for Hedge<S>
+where
+    R: Hedgeable,
+    R::Response: Send,
+    S: ExcService<R> + Clone + Send + 'static,
+    S::Future: Send,
+{
+    type Response = R::Response;
+    type Error = ExchangeError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // Each backend is driven to ready in its own attempt.
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: R) -> Self::Future {
+        if self.backends.is_empty() {
+            return futures::future::ready(Err(ExchangeError::Unavailable(anyhow::anyhow!(
+                "no backend to hedge"
+            ))))
+            .boxed();
+        }
+        let attempts = self
+            .backends
+            .iter()
+            .cloned()
+            .enumerate()
+            .map(|(idx, mut svc)| {
+                let req = req.clone();
+                let wait = self.delay * idx as u32;
+                async move {
+                    if !wait.is_zero() {
+                        tokio::time::sleep(wait).await;
+                    }
+                    let resp = ServiceExt::<R>::oneshot(svc.as_service(), req).await?;
+                    R::settle(resp).await
+                }
+                .boxed()
+            })
+            .collect::<Vec<_>>();
+        select_ok(attempts).map_ok(|(resp, _)| resp).boxed()
+    }
+}
+
+type Backend<S, R> = PeakEwma<IntoService<S, R>, CompleteOnResponse>;
+
+/// Latency-aware picker.
+///
+/// Route each request to the faster of two random ready backends, measured by
+/// the peak-EWMA of their response latencies. A backend failing to be ready is
+/// considered unhealthy and removed.
+pub struct Fastest<S, R>
+where
+    S: ExcService<R>,
+    R: Request,
+{
+    inner: Balance<ServiceList<Vec<Backend<S, R>>>, R>,
+}
+
+impl<S, R> fmt::Debug for Fastest<S, R>
+where
+    S: ExcService<R> + fmt::Debug,
+    R: Request,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Fastest").finish_non_exhaustive()
+    }
+}
+
+impl<S, R> Fastest<S, R>
+where
+    S: ExcService<R>,
+    R: Request,
+{
+    /// Create a picker over the given backends,
+    /// assuming `default_rtt` for the backends without samples.
+    pub fn new(backends: impl IntoIterator<Item = S>, default_rtt: Duration) -> Self {
+        let backends = backends
+            .into_iter()
+            .map(|svc| {
+                PeakEwma::new(
+                    ExcServiceExt::<R>::into_service(svc),
+                    default_rtt,
+                    DEFAULT_DECAY.as_nanos() as f64,
+                    CompleteOnResponse::default(),
+                )
+            })
+            .collect::<Vec<_>>();
+        Self {
+            inner: Balance::new(ServiceList::new::<R>(backends)),
+        }
+    }
+
+    /// The number of healthy backends.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Whether all the backends have been removed.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl<S, R> Service<R> for Fastest<S, R>
+where
+    S: ExcService<R>,
+    R: Request,
+{
+    type Response = R::Response;
+    type Error = ExchangeError;
+    type Future = MapErr<
+        <Balance<ServiceList<Vec<Backend<S, R>>>, R> as Service<R>>::Future,
+        fn(BoxError) -> ExchangeError,
+    >;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx).map_err(ExchangeError::layer)
+    }
+
+    fn call(&mut self, req: R) -> Self::Future {
+        self.inner
+            .call(req)
+            .map_err(ExchangeError::layer as fn(BoxError) -> ExchangeError)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use futures::executor::block_on;
+    use tower::service_fn;
+
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Ping;
+
+    impl Request for Ping {
+        type Response = BoxStream<'static, Result<usize, ExchangeError>>;
+    }
+
+    impl Hedgeable for Ping {
+        fn settle(
+            resp: Self::Response,
+        ) -> BoxFuture<'static, Result<Self::Response, ExchangeError>> {
+            settle_stream(resp).boxed()
+        }
+    }
+
+    #[test]
+    fn test_hedge() {
+        let backend = |idx: usize| {
+            service_fn(move |_: Ping| async move {
+                if idx == 0 {
+                    Ok::<_, ExchangeError>(
+                        stream::once(async {
+                            Err(ExchangeError::Unavailable(anyhow::anyhow!("down")))
+                        })
+                        .boxed(),
+                    )
+                } else {
+                    Ok(stream::iter([Ok(idx)]).boxed())
+                }
+            })
+        };
+        let mut svc = Hedge::new([backend(0), backend(1)]);
+        block_on(async {
+            let resp = ServiceExt::<Ping>::oneshot(&mut svc, Ping).await.unwrap();
+            let items = resp.collect::<Vec<_>>().await;
+            assert_eq!(items.len(), 1);
+            assert_eq!(*items[0].as_ref().unwrap(), 1);
+        });
+
+        let mut svc = Hedge::new([backend(0)]);
+        block_on(async {
+            assert!(ServiceExt::<Ping>::oneshot(&mut svc, Ping).await.is_err());
+        });
+
+        let mut svc = Fastest::new([backend(1), backend(2)], Duration::from_millis(100));
+        block_on(async {
+            let resp = ServiceExt::<Ping>::oneshot(&mut svc, Ping).await.unwrap();
+            assert_eq!(resp.collect::<Vec<_>>().await.len(), 1);
+        });
+        assert_eq!(svc.len(), 2);
+    }
+}
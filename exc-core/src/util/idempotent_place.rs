@@ -0,0 +1,211 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    sync::Arc,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use exc_service::{ExcService, ExchangeError};
+use exc_types::{GetOrder, OrderId, PlaceOrder, Placed, Str};
+use futures::{future::BoxFuture, FutureExt, TryFutureExt};
+use time::OffsetDateTime;
+use tower::{Layer, Service, ServiceExt};
+
+const DEFAULT_PREFIX: &str = "exc";
+const DEFAULT_RECOVER_ATTEMPTS: usize = 3;
+const DEFAULT_RECOVER_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Whether the order may have been placed despite the error.
+fn is_ambiguous(err: &ExchangeError) -> bool {
+    !err.is_pre_submission() && !matches!(err, ExchangeError::Api(_) | ExchangeError::OrderNotFound)
+}
+
+/// Idempotent place layer.
+///
+/// Assign a client id to every [`PlaceOrder`] lacking one, and when the placement
+/// fails ambiguously (e.g. timeouts and disconnections), query the order by the
+/// client id with [`GetOrder`] before reporting the failure.
+/// Retrying with the same request will then never create a duplicate order.
+#[derive(Debug, Clone)]
+pub struct IdempotentPlaceLayer {
+    prefix: Str,
+    attempts: usize,
+    interval: Duration,
+}
+
+impl Default for IdempotentPlaceLayer {
+    fn default() -> Self {
+        Self {
+            prefix: Str::new_inline(DEFAULT_PREFIX),
+            attempts: DEFAULT_RECOVER_ATTEMPTS,
+            interval: DEFAULT_RECOVER_INTERVAL,
+        }
+    }
+}
+
+impl IdempotentPlaceLayer {
+    /// Set the prefix of the generated client ids.
+    ///
+    /// The generated ids are the prefix followed by 19 alphanumeric characters,
+    /// so the prefix should start with a letter and be short enough for the exchange.
+    pub fn prefix(&mut self, prefix: impl AsRef<str>) -> &mut Self {
+        self.prefix = Str::new(prefix);
+        self
+    }
+
+    /// Set the number of queries to recover an ambiguous placement.
+    pub fn recover_attempts(&mut self, attempts: usize) -> &mut Self {
+        self.attempts = attempts;
+        self
+    }
+
+    /// Set the interval to wait before each query.
+    pub fn recover_interval(&mut self, interval: Duration) -> &mut Self {
+        self.interval = interval;
+        self
+    }
+}
+
+impl<S> Layer<S> for IdempotentPlaceLayer {
+    type Service = IdempotentPlace<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        IdempotentPlace {
+            svc: inner,
+            prefix: self.prefix.clone(),
+            attempts: self.attempts,
+            interval: self.interval,
+        }
+    }
+}
+
+/// Idempotent place service.
+#[derive(Debug, Clone)]
+pub struct IdempotentPlace<S> {
+    svc: S,
+    prefix: Str,
+    attempts: usize,
+    interval: Duration,
+}
+
+impl<S> IdempotentPlace<S> {
+    fn generate(&self) -> String {
+        let mut hasher = RandomState::new().build_hasher();
+        let now = OffsetDateTime::now_utc().unix_timestamp_nanos();
+        hasher.write_i128(now);
+        format!(
+            "{}{:011x}{:08x}",
+            self.prefix,
+            now / 1_000_000,
+            hasher.finish() as u32
+        )
+    }
+}
+
+async fn recover<S>(
+    mut svc: S,
+    req: GetOrder,
+    attempts: usize,
+    interval: Duration,
+    err: ExchangeError,
+) -> Result<Placed, ExchangeError>
+where
+    S: ExcService<GetOrder>,
+{
+    tracing::warn!(id=%req.id.as_str(), %err, "ambiguous placement; recovering");
+    for _ in 0..attempts {
+        tokio::time::sleep(interval).await;
+        match ServiceExt::<GetOrder>::oneshot(svc.as_service(), req.clone())
+            .try_flatten()
+            .await
+        {
+            Ok(update) => {
+                return Ok(Placed {
+                    id: update.order.id.clone(),
+                    ts: update.ts,
+                    order: Some(update.order),
+                });
+            }
+            Err(ExchangeError::OrderNotFound) => {}
+            Err(err) => {
+                tracing::warn!(id=%req.id.as_str(), %err, "failed to query the order");
+            }
+        }
+    }
+    Err(err)
+}
+
+impl<S> Service<PlaceOrder> for IdempotentPlace<S>
+where
+    S: ExcService<PlaceOrder> + ExcService<GetOrder> + Clone + Send + 'static,
+    <S as ExcService<PlaceOrder>>::Future: Send,
+    <S as ExcService<GetOrder>>::Future: Send,
+{
+    type Response = BoxFuture<'static, Result<Placed, ExchangeError>>;
+    type Error = ExchangeError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Service::<PlaceOrder>::poll_ready(&mut self.svc.as_service(), cx)
+    }
+
+    fn call(&mut self, mut req: PlaceOrder) -> Self::Future {
+        if req.opts.client_id().is_none() {
+            let id = self.generate();
+            Arc::make_mut(&mut req.opts).with_client_id(Some(id));
+        }
+        let get = GetOrder {
+            instrument: Str::new(req.opts.instrument()),
+            id: OrderId::from(req.opts.client_id().unwrap_or_default().to_string()),
+        };
+        let placing = Service::<PlaceOrder>::call(&mut self.svc.as_service(), req);
+        let svc = self.svc.clone();
+        let attempts = self.attempts;
+        let interval = self.interval;
+        async move {
+            match placing.await {
+                Ok(placed) => Ok(async move {
+                    match placed.await {
+                        Err(err) if is_ambiguous(&err) => {
+                            recover(svc, get, attempts, interval, err).await
+                        }
+                        res => res,
+                    }
+                }
+                .boxed()),
+                Err(err) if is_ambiguous(&err) => {
+                    Ok(recover(svc, get, attempts, interval, err).boxed())
+                }
+                Err(err) => Err(err),
+            }
+        }
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_client_id() {
+        let svc = IdempotentPlaceLayer::default().layer(());
+        let a = svc.generate();
+        let b = svc.generate();
+        assert_ne!(a, b);
+        assert!(a.starts_with(DEFAULT_PREFIX));
+        assert!(a.len() <= 32);
+        assert!(a.bytes().all(|b| b.is_ascii_alphanumeric()));
+
+        assert!(is_ambiguous(&ExchangeError::Unavailable(anyhow::anyhow!(
+            "timeout"
+        ))));
+        assert!(!is_ambiguous(&ExchangeError::RateLimited(anyhow::anyhow!(
+            "429"
+        ))));
+        assert!(!is_ambiguous(&ExchangeError::Api(anyhow::anyhow!(
+            "insufficient balance"
+        ))));
+    }
+}
@@ -4,6 +4,15 @@ pub mod period;
 /// Create a service to subscribe tickers from subscribe trades and bid/ask.
 pub mod trade_bid_ask;
 
+/// Create a service to subscribe candles of any period from subscribe trades.
+pub mod trades_to_candles;
+
+/// Create a service to query candles of any multiple of a base period by resampling.
+pub mod resample_candles;
+
+/// Create a service to resubscribe the order book when it is out of sync.
+pub mod resync_order_book;
+
 /// Create a service to subscribe instruments by first fetching.
 pub mod fetch_instruments_first;
 
@@ -15,4 +24,24 @@ pub mod poll_instruments;
 #[cfg(feature = "fetch-candles")]
 pub mod fetch_candles;
 
-pub use period::{trunc, PeriodExt};
+/// Fetch orders.
+#[cfg(feature = "fetch-orders")]
+pub mod fetch_orders;
+
+/// Fetch fills.
+#[cfg(feature = "fetch-fills")]
+pub mod fetch_fills;
+
+/// Keep a dead man's switch armed.
+#[cfg(feature = "dead-man-switch")]
+pub mod dead_man_switch;
+
+/// Place orders idempotently by client ids.
+#[cfg(feature = "idempotent-place")]
+pub mod idempotent_place;
+
+/// Hedge read-only requests and pick the fastest backend.
+#[cfg(feature = "hedge")]
+pub mod hedge;
+
+pub use period::{period_bounds, trunc, PeriodExt};
@@ -4,6 +4,7 @@ use std::{
     time::Duration,
 };
 
+use exc_service::ExchangeError;
 use indicator::{window::mode::tumbling::period::PeriodKind, Period};
 use time::{macros::time, Date, Month, OffsetDateTime, PrimitiveDateTime};
 
@@ -41,6 +42,17 @@ pub fn trunc(ts: OffsetDateTime, duration: Duration) -> Option<OffsetDateTime> {
     }
 }
 
+/// Get the start and the end of the period containing the ts.
+pub fn period_bounds(
+    period: &Period,
+    ts: OffsetDateTime,
+) -> Result<(OffsetDateTime, OffsetDateTime), ExchangeError> {
+    let mut iter = period.iterate(ts..);
+    iter.next()
+        .zip(iter.next())
+        .ok_or_else(|| ExchangeError::Other(anyhow::anyhow!("cannot truncate {ts} by {period}")))
+}
+
 /// A range iterator of [`OffsetDateTime`].
 pub struct RangeIter {
     period: PeriodKind,
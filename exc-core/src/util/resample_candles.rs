@@ -0,0 +1,250 @@
+use std::{
+    ops::{Bound, RangeBounds},
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use async_stream::try_stream;
+use exc_service::{ExcService, ExchangeError};
+use exc_types::{Candle, CandleStream, Period, PeriodKind, QueryCandles};
+use futures::{future::BoxFuture, FutureExt};
+use time::OffsetDateTime;
+use tower::{Layer, Service};
+
+use super::period::period_bounds;
+
+const DAY: Duration = Duration::from_secs(24 * 3600);
+
+/// Resample candles layer.
+#[derive(Debug, Clone, Copy)]
+pub struct ResampleCandlesLayer {
+    base: Period,
+}
+
+impl ResampleCandlesLayer {
+    /// Create a new resample candles layer,
+    /// which fetches the candles of the `base` period from the inner service.
+    pub fn new(base: Period) -> Self {
+        Self { base }
+    }
+}
+
+impl<S> Layer<S> for ResampleCandlesLayer {
+    type Service = ResampleCandles<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ResampleCandles {
+            svc: inner,
+            base: self.base,
+        }
+    }
+}
+
+/// Resample candles service.
+///
+/// Answer [`QueryCandles`] of any period that is a multiple of the base period
+/// (or a calendar period made of base candles) by aggregating the base candles.
+/// Only the candles whose periods are fully covered by the range are produced.
+#[derive(Debug, Clone, Copy)]
+pub struct ResampleCandles<S> {
+    svc: S,
+    base: Period,
+}
+
+fn offset_secs(period: &Period) -> i64 {
+    period.utc_offset().whole_seconds() as i64
+}
+
+fn is_divisible(dur: Duration, base: Duration) -> bool {
+    !base.is_zero() && dur.as_nanos() % base.as_nanos() == 0
+}
+
+/// Check that every candle of the `base` period is contained in exactly one candle of `target`.
+fn check_resample(base: &Period, target: &Period) -> Result<(), ExchangeError> {
+    let offset_diff = (offset_secs(target) - offset_secs(base)).unsigned_abs();
+    let aligned = match (base.kind(), target.kind()) {
+        (PeriodKind::Duration(base_dur), PeriodKind::Duration(dur)) => {
+            is_divisible(dur, base_dur) && is_divisible(Duration::from_secs(offset_diff), base_dur)
+        }
+        (PeriodKind::Duration(base_dur), PeriodKind::Month | PeriodKind::Year) => {
+            is_divisible(DAY, base_dur) && is_divisible(Duration::from_secs(offset_diff), base_dur)
+        }
+        (PeriodKind::Month, PeriodKind::Month | PeriodKind::Year)
+        | (PeriodKind::Year, PeriodKind::Year) => offset_diff == 0,
+        _ => false,
+    };
+    if aligned {
+        Ok(())
+    } else {
+        Err(ExchangeError::Unsupported(anyhow::anyhow!(
+            "cannot resample candles of {base} into {target}"
+        )))
+    }
+}
+
+/// Convert the range of the target candles to the range of the base candles.
+fn base_range(
+    target: &Period,
+    query: &QueryCandles,
+) -> Result<(Bound<OffsetDateTime>, Bound<OffsetDateTime>), ExchangeError> {
+    let start = match query.start_bound() {
+        Bound::Unbounded => Bound::Unbounded,
+        Bound::Included(start) => {
+            let (first, next) = period_bounds(target, *start)?;
+            Bound::Included(if first == *start { first } else { next })
+        }
+        Bound::Excluded(start) => Bound::Included(period_bounds(target, *start)?.1),
+    };
+    let end = match query.end_bound() {
+        Bound::Unbounded => Bound::Unbounded,
+        Bound::Included(end) => Bound::Excluded(period_bounds(target, *end)?.1),
+        Bound::Excluded(end) => {
+            let last = *end - time::Duration::NANOSECOND;
+            Bound::Excluded(period_bounds(target, last)?.1)
+        }
+    };
+    Ok((start, end))
+}
+
+fn merge(candle: &mut Candle, other: &Candle, forward: bool) {
+    candle.high = candle.high.max(other.high);
+    candle.low = candle.low.min(other.low);
+    candle.volume += other.volume;
+    if forward {
+        candle.close = other.close;
+    } else {
+        candle.open = other.open;
+    }
+}
+
+fn resample(stream: CandleStream, target: Period) -> CandleStream {
+    let forward = stream.is_forward();
+    let resampled = try_stream! {
+        let mut current: Option<Candle> = None;
+        for await candle in stream {
+            let candle = candle?;
+            let (ts, _) = period_bounds(&target, candle.ts)?;
+            match current.as_mut() {
+                Some(agg) if agg.ts == ts => merge(agg, &candle, forward),
+                _ => {
+                    if let Some(agg) = current.take() {
+                        yield agg;
+                    }
+                    current = Some(Candle { ts, ..candle });
+                }
+            }
+        }
+        if let Some(agg) = current {
+            yield agg;
+        }
+    };
+    if forward {
+        CandleStream::new_forward(resampled)
+    } else {
+        CandleStream::new_backward(resampled)
+    }
+}
+
+impl<S> Service<QueryCandles> for ResampleCandles<S>
+where
+    S: ExcService<QueryCandles>,
+    S::Future: Send + 'static,
+{
+    type Response = CandleStream;
+    type Error = ExchangeError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.svc.poll_ready(cx)
+    }
+
+    fn call(&mut self, query: QueryCandles) -> Self::Future {
+        let target = query.period();
+        if target == self.base {
+            return self.svc.call(query).boxed();
+        }
+        let base_query = check_resample(&self.base, &target)
+            .and_then(|_| base_range(&target, &query))
+            .map(|range| {
+                QueryCandles::new(query.inst(), self.base, range).with_source(query.source())
+            });
+        let base_query = match base_query {
+            Ok(query) => query,
+            Err(err) => return futures::future::ready(Err(err)).boxed(),
+        };
+        tracing::trace!("resample candles: {query} from {base_query}");
+        let stream = self.svc.call(base_query);
+        async move { Ok(resample(stream.await?, target)) }.boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use futures::{executor::block_on, stream, TryStreamExt};
+    use rust_decimal::Decimal;
+    use time::macros::{datetime, offset};
+
+    fn candles(start: OffsetDateTime, n: i64) -> Vec<Candle> {
+        (0..n)
+            .map(|idx| {
+                let price = Decimal::from(idx);
+                Candle {
+                    ts: start + time::Duration::hours(idx),
+                    open: price,
+                    high: price + Decimal::ONE,
+                    low: price - Decimal::ONE,
+                    close: price,
+                    volume: Decimal::ONE,
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_resample_candles() -> anyhow::Result<()> {
+        let base = Period::hours(offset!(+0), 1);
+        let target = Period::day(offset!(+8));
+        check_resample(&base, &target)?;
+        assert!(check_resample(&Period::hours(offset!(+0), 3), &target).is_err());
+
+        let query = QueryCandles::new(
+            "BTC-USDT",
+            target,
+            datetime!(2023-01-01 00:00:00 +08:00)..datetime!(2023-01-03 00:00:00 +08:00),
+        );
+        let (start, end) = base_range(&target, &query)?;
+        assert_eq!(
+            start,
+            Bound::Included(datetime!(2023-01-01 00:00:00 +08:00))
+        );
+        assert_eq!(end, Bound::Excluded(datetime!(2023-01-03 00:00:00 +08:00)));
+
+        let data = candles(datetime!(2022-12-31 16:00:00 +00:00), 48);
+        let forward = CandleStream::new_forward(stream::iter(data.clone().into_iter().map(Ok)));
+        let days: Vec<_> = block_on(resample(forward, target).try_collect())?;
+        assert_eq!(days.len(), 2);
+        assert_eq!(days[0].ts, datetime!(2023-01-01 00:00:00 +08:00));
+        assert_eq!(
+            (days[0].open, days[0].high, days[0].low, days[0].close),
+            (
+                Decimal::from(0),
+                Decimal::from(24),
+                Decimal::from(-1),
+                Decimal::from(23)
+            )
+        );
+        assert_eq!(days[1].volume, Decimal::from(24));
+
+        let backward = CandleStream::new_backward(stream::iter(data.into_iter().rev().map(Ok)));
+        let resampled = resample(backward, target);
+        assert!(!resampled.is_forward());
+        let rev_days: Vec<_> = block_on(resampled.try_collect())?;
+        assert_eq!(rev_days[1].ts, days[0].ts);
+        assert_eq!(
+            (rev_days[1].open, rev_days[1].close),
+            (days[0].open, days[0].close)
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+use std::task::{Context, Poll};
+
+use async_stream::try_stream;
+use exc_service::{ExcService, ExchangeError};
+use exc_types::{OrderBookStream, SubscribeOrderBook};
+use futures::{future::BoxFuture, FutureExt, StreamExt};
+use tower::{Layer, Service, ServiceExt};
+
+/// Resync order book layer.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ResyncOrderBookLayer {
+    max_resyncs: Option<usize>,
+}
+
+impl ResyncOrderBookLayer {
+    /// Set the maximum number of resubscriptions (unlimited by default).
+    pub fn max_resyncs(&mut self, max: usize) -> &mut Self {
+        self.max_resyncs = Some(max);
+        self
+    }
+}
+
+impl<S> Layer<S> for ResyncOrderBookLayer {
+    type Service = ResyncOrderBook<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ResyncOrderBook {
+            svc: inner,
+            max_resyncs: self.max_resyncs,
+        }
+    }
+}
+
+/// Resync order book service.
+/// Resubscribe to the order book when the inner stream is out of sync,
+/// and truncate the books to the requested depth.
+#[derive(Debug, Clone, Copy)]
+pub struct ResyncOrderBook<S> {
+    svc: S,
+    max_resyncs: Option<usize>,
+}
+
+impl<S> Service<SubscribeOrderBook> for ResyncOrderBook<S>
+where
+    S: Clone + Send + 'static,
+    S: ExcService<SubscribeOrderBook>,
+    S::Future: Send,
+{
+    type Response = OrderBookStream;
+    type Error = ExchangeError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Service::<SubscribeOrderBook>::poll_ready(&mut self.svc.as_service(), cx)
+    }
+
+    fn call(&mut self, req: SubscribeOrderBook) -> Self::Future {
+        let first = Service::call(&mut self.svc.as_service(), req.clone());
+        let mut svc = self.svc.clone();
+        let max_resyncs = self.max_resyncs;
+        async move {
+            let mut books = first.await?;
+            let stream = try_stream! {
+                let mut resyncs = 0;
+                while let Some(book) = books.next().await {
+                    match book {
+                        Ok(mut book) => {
+                            if let Some(depth) = req.depth {
+                                book.truncate(depth);
+                            }
+                            yield book;
+                        }
+                        Err(ExchangeError::OutOfSync(err)) => {
+                            if max_resyncs.is_some_and(|max| resyncs >= max) {
+                                Err(ExchangeError::OutOfSync(err))?;
+                                break;
+                            }
+                            resyncs += 1;
+                            tracing::warn!(%err, "order book out of sync; resubscribing ({resyncs})");
+                            books = ServiceExt::<SubscribeOrderBook>::oneshot(
+                                svc.as_service(),
+                                req.clone(),
+                            )
+                            .await?;
+                        }
+                        Err(err) => {
+                            Err(err)?;
+                        }
+                    }
+                }
+            };
+            Ok(stream.boxed())
+        }
+        .boxed()
+    }
+}
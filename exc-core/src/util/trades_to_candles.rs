@@ -0,0 +1,242 @@
+use std::task::{Context, Poll};
+
+use async_stream::try_stream;
+use exc_service::{ExcService, ExchangeError};
+use exc_types::{
+    Candle, CandleUpdate, CandleUpdateStream, Period, SubscribeCandles, SubscribeTrades,
+    TradeStream,
+};
+use futures::{future::BoxFuture, FutureExt, StreamExt};
+use time::OffsetDateTime;
+use tower::{Layer, Service};
+
+use super::{period::period_bounds, PeriodExt};
+
+/// How to handle the periods without any trade.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EmptyPeriods {
+    /// Skip the empty periods.
+    #[default]
+    Skip,
+    /// Produce closed candles with the last close price and zero volume.
+    CarryForward,
+}
+
+/// How to handle the trades of the periods that have been closed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LateTrades {
+    /// Drop the late trades.
+    #[default]
+    Drop,
+    /// Merge the late trades into the current candle
+    /// (only its high, low and volume are updated).
+    Merge,
+}
+
+/// Trades-to-candles layer.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TradesToCandlesLayer {
+    empty_periods: EmptyPeriods,
+    late_trades: LateTrades,
+}
+
+impl TradesToCandlesLayer {
+    /// Set how to handle the empty periods. Default to [`EmptyPeriods::Skip`].
+    pub fn empty_periods(&mut self, mode: EmptyPeriods) -> &mut Self {
+        self.empty_periods = mode;
+        self
+    }
+
+    /// Set how to handle the late trades. Default to [`LateTrades::Drop`].
+    pub fn late_trades(&mut self, mode: LateTrades) -> &mut Self {
+        self.late_trades = mode;
+        self
+    }
+}
+
+impl<S> Layer<S> for TradesToCandlesLayer {
+    type Service = TradesToCandles<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        TradesToCandles {
+            svc: inner,
+            empty_periods: self.empty_periods,
+            late_trades: self.late_trades,
+        }
+    }
+}
+
+/// Trades-to-candles service.
+///
+/// A candle is closed when the first trade of a later period arrives.
+#[derive(Debug, Clone, Copy)]
+pub struct TradesToCandles<S> {
+    empty_periods: EmptyPeriods,
+    late_trades: LateTrades,
+    svc: S,
+}
+
+impl<S> Service<SubscribeCandles> for TradesToCandles<S>
+where
+    S: ExcService<SubscribeTrades>,
+    S::Future: Send + 'static,
+{
+    type Response = CandleUpdateStream;
+    type Error = ExchangeError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.svc.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: SubscribeCandles) -> Self::Future {
+        let trades = self.svc.call(SubscribeTrades {
+            instrument: req.inst.clone(),
+        });
+        let empty_periods = self.empty_periods;
+        let late_trades = self.late_trades;
+        async move {
+            let trades = trades.await?;
+            Ok(aggregate(trades, req.period, empty_periods, late_trades))
+        }
+        .boxed()
+    }
+}
+
+fn aggregate(
+    trades: TradeStream,
+    period: Period,
+    empty_periods: EmptyPeriods,
+    late_trades: LateTrades,
+) -> CandleUpdateStream {
+    let stream = try_stream! {
+        // The current candle and the end of its period.
+        let mut current: Option<(Candle, OffsetDateTime)> = None;
+        for await trade in trades {
+            let trade = trade?;
+            let size = trade.size.abs();
+            match current.as_mut() {
+                Some((candle, _)) if trade.ts < candle.ts => {
+                    tracing::trace!("late trade: {trade}");
+                    if matches!(late_trades, LateTrades::Drop) {
+                        continue;
+                    }
+                    candle.high = candle.high.max(trade.price);
+                    candle.low = candle.low.min(trade.price);
+                    candle.volume += size;
+                }
+                Some((candle, end)) if trade.ts < *end => {
+                    candle.high = candle.high.max(trade.price);
+                    candle.low = candle.low.min(trade.price);
+                    candle.close = trade.price;
+                    candle.volume += size;
+                }
+                _ => {
+                    let (start, end) = period_bounds(&period, trade.ts)?;
+                    if let Some((candle, last_end)) = current.take() {
+                        let close = candle.close;
+                        yield CandleUpdate { candle, is_closed: true };
+                        if matches!(empty_periods, EmptyPeriods::CarryForward) {
+                            for ts in period.iterate(last_end..start) {
+                                let candle = Candle {
+                                    ts,
+                                    open: close,
+                                    high: close,
+                                    low: close,
+                                    close,
+                                    volume: rust_decimal::Decimal::ZERO,
+                                };
+                                yield CandleUpdate { candle, is_closed: true };
+                            }
+                        }
+                    }
+                    let candle = Candle {
+                        ts: start,
+                        open: trade.price,
+                        high: trade.price,
+                        low: trade.price,
+                        close: trade.price,
+                        volume: size,
+                    };
+                    current = Some((candle, end));
+                }
+            }
+            if let Some((candle, _)) = current.as_ref() {
+                yield CandleUpdate { candle: candle.clone(), is_closed: false };
+            }
+        }
+    };
+    stream.boxed()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use exc_types::Trade;
+    use futures::{executor::block_on, stream, TryStreamExt};
+    use rust_decimal_macros::dec;
+    use time::macros::{datetime, offset};
+
+    fn trades(trades: &[(OffsetDateTime, rust_decimal::Decimal)]) -> TradeStream {
+        let trades = trades
+            .iter()
+            .map(|(ts, price)| {
+                Ok(Trade {
+                    ts: *ts,
+                    price: *price,
+                    size: dec!(1),
+                    buy: true,
+                })
+            })
+            .collect::<Vec<_>>();
+        stream::iter(trades).boxed()
+    }
+
+    #[test]
+    fn test_trades_to_candles() -> anyhow::Result<()> {
+        // 7-minute bars are aligned to `1970-01-05 00:00:00 +08:00`.
+        let period = Period::minutes(offset!(+8), 7);
+        let trades = trades(&[
+            (datetime!(2023-01-01 00:00:00 +08:00), dec!(1)),
+            (datetime!(2023-01-01 00:04:59 +08:00), dec!(3)),
+            (datetime!(2023-01-01 00:05:30 +08:00), dec!(4)),
+            (datetime!(2023-01-01 00:04:00 +08:00), dec!(0)),
+            (datetime!(2023-01-01 00:20:00 +08:00), dec!(2)),
+        ]);
+        let updates: Vec<_> = block_on(
+            aggregate(trades, period, EmptyPeriods::CarryForward, LateTrades::Drop).try_collect(),
+        )?;
+        let closed = updates
+            .iter()
+            .filter(|u| u.is_closed)
+            .map(|u| (u.candle.ts, u.candle.low, u.candle.close, u.candle.volume))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            closed,
+            [
+                (
+                    datetime!(2022-12-31 23:58:00 +08:00),
+                    dec!(1),
+                    dec!(3),
+                    dec!(2)
+                ),
+                (
+                    datetime!(2023-01-01 00:05:00 +08:00),
+                    dec!(4),
+                    dec!(4),
+                    dec!(1)
+                ),
+                (
+                    datetime!(2023-01-01 00:12:00 +08:00),
+                    dec!(4),
+                    dec!(4),
+                    dec!(0)
+                ),
+            ]
+        );
+        let last = updates.last().unwrap();
+        assert!(!last.is_closed);
+        assert_eq!(last.candle.ts, datetime!(2023-01-01 00:19:00 +08:00));
+        Ok(())
+    }
+}
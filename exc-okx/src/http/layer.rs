@@ -4,7 +4,11 @@ use super::types::{
     request::HttpRequest,
     response::{FullHttpResponse, HttpResponse},
 };
-use exc_core::{retry::RetryPolicy, ExchangeError};
+use exc_core::{
+    retry::RetryPolicy,
+    weight::{WeightLimit, WeightLimitLayer, WeightLimits},
+    ExchangeError,
+};
 use futures::{
     future::{ready, BoxFuture},
     FutureExt, TryFutureExt,
@@ -18,6 +22,7 @@ pub struct OkxHttpApiLayer<F> {
     testing: bool,
     aws: bool,
     key: Option<Key>,
+    limit: WeightLimitLayer,
     retry_policy: RetryPolicy<HttpRequest, HttpResponse, F>,
 }
 
@@ -40,6 +45,14 @@ impl<F> OkxHttpApiLayer<F> {
         self
     }
 
+    /// Set the per-endpoint weight limits.
+    ///
+    /// The services created by this layer share the same counters.
+    pub fn weight_limits(&mut self, limits: WeightLimits) -> &mut Self {
+        self.limit = WeightLimitLayer::new(limits);
+        self
+    }
+
     /// Set retry policy.
     pub fn retry<F2>(
         self,
@@ -52,6 +65,7 @@ impl<F> OkxHttpApiLayer<F> {
             aws: self.aws,
             retry_policy: policy,
             key: self.key,
+            limit: self.limit,
             testing: self.testing,
         }
     }
@@ -93,6 +107,7 @@ impl OkxHttpApiLayer<fn(&ExchangeError) -> bool> {
             aws: false,
             retry_policy: RetryPolicy::never(),
             key: None,
+            limit: WeightLimitLayer::default(),
             testing: false,
         }
     }
@@ -100,14 +115,14 @@ impl OkxHttpApiLayer<fn(&ExchangeError) -> bool> {
 
 impl<S, F> Layer<S> for OkxHttpApiLayer<F>
 where
-    S: Service<Request<Body>, Response = Response<Body>> + Clone,
+    S: Service<Request<Body>, Response = Response<Body>> + Clone + Send + 'static,
     S::Future: Send + 'static,
     S::Error: 'static,
     ExchangeError: From<S::Error>,
     F: Fn(&ExchangeError) -> bool,
     F: Send + 'static + Clone,
 {
-    type Service = Retry<RetryPolicy<HttpRequest, HttpResponse, F>, OkxHttpApi<S>>;
+    type Service = Retry<RetryPolicy<HttpRequest, HttpResponse, F>, WeightLimit<OkxHttpApi<S>>>;
 
     fn layer(&self, inner: S) -> Self::Service {
         let svc = OkxHttpApi {
@@ -118,6 +133,7 @@ where
         };
         ServiceBuilder::default()
             .retry(self.retry_policy.clone())
+            .layer(&self.limit)
             .service(svc)
     }
 }
@@ -170,6 +186,15 @@ where
                     )))
                 }
             }
+            HttpRequest::PrivatePost(post) => {
+                if let Some(key) = self.key.as_ref() {
+                    post.to_request(&self.host, key)
+                } else {
+                    Err(ExchangeError::KeyError(anyhow::anyhow!(
+                        "key has not been set"
+                    )))
+                }
+            }
         };
         match req {
             Ok(mut req) => {
@@ -0,0 +1,68 @@
+use std::time::Duration;
+
+use exc_core::weight::{Cost, ReportUsage, WeightLimits, Weighted};
+
+use super::types::{
+    request::{Get, HttpRequest, PrivateGet, PrivatePost},
+    response::HttpResponse,
+};
+
+const TWO_SECS: Duration = Duration::from_secs(2);
+
+/// The default per-endpoint limits, each of which is named by the path of the endpoint.
+///
+/// The limits of the trading endpoints are counted per instrument.
+pub fn weight_limits() -> WeightLimits {
+    let mut limits = WeightLimits::default();
+    limits
+        .limit("/api/v5/market/history-candles", 20, TWO_SECS)
+        .limit("/api/v5/market/history-mark-price-candles", 10, TWO_SECS)
+        .limit("/api/v5/market/history-index-candles", 10, TWO_SECS)
+        .limit("/api/v5/public/instruments", 20, TWO_SECS)
+        .limit("/api/v5/public/funding-rate-history", 10, TWO_SECS)
+        .limit(
+            "/api/v5/rubik/stat/contracts/open-interest-history",
+            5,
+            TWO_SECS,
+        )
+        .limit("/api/v5/trade/order", 60, TWO_SECS)
+        .limit("/api/v5/trade/orders-pending", 60, TWO_SECS)
+        .limit("/api/v5/trade/orders-history", 40, TWO_SECS)
+        .limit("/api/v5/trade/fills-history", 10, TWO_SECS)
+        .limit("/api/v5/account/balance", 10, TWO_SECS)
+        .limit("/api/v5/account/positions", 10, TWO_SECS)
+        .limit("/api/v5/trade/cancel-all-after", 1, Duration::from_secs(1))
+        .limit("/api/v5/trade/order-algo", 20, TWO_SECS);
+    limits
+}
+
+impl Weighted for HttpRequest {
+    fn costs(&self) -> Vec<Cost> {
+        let cost = match self {
+            Self::Get(get) => {
+                let cost = Cost::new(get.uri(), 1);
+                match get {
+                    Get::FundingRateHistory(req) => cost.scoped(&req.inst_id),
+                    _ => cost,
+                }
+            }
+            Self::PrivateGet(get) => {
+                let cost = Cost::new(get.uri(), 1);
+                match get {
+                    PrivateGet::Order(req) => cost.scoped(&req.inst_id),
+                    _ => cost,
+                }
+            }
+            Self::PrivatePost(post) => {
+                let cost = Cost::new(post.uri(), 1);
+                match post {
+                    PrivatePost::OrderAlgo(req) => cost.scoped(&req.inst_id),
+                    PrivatePost::CancelAllAfter(_) => cost,
+                }
+            }
+        };
+        vec![cost]
+    }
+}
+
+impl ReportUsage for HttpResponse {}
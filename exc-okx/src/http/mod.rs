@@ -1,5 +1,8 @@
 /// Types of okx http api.
 pub mod types;
 
+/// Per-endpoint limits.
+pub mod limit;
+
 /// Layer.
 pub mod layer;
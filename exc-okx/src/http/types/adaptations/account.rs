@@ -0,0 +1,72 @@
+use exc_core::{
+    types::{Balance, FetchBalances, FetchPositions, Position},
+    Adaptor, ExchangeError,
+};
+use futures::FutureExt;
+
+use crate::http::types::{
+    request::{account, HttpRequest, PrivateGet},
+    response::ResponseData,
+};
+
+impl Adaptor<FetchBalances> for HttpRequest {
+    fn from_request(_req: FetchBalances) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        Ok(HttpRequest::PrivateGet(PrivateGet::Balance(
+            account::Balance::default(),
+        )))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<FetchBalances as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            let mut balances = Vec::new();
+            for data in resp.data {
+                if let ResponseData::Account(account) = data {
+                    balances.extend(account.details.into_iter().map(Balance::from));
+                } else {
+                    return Err(ExchangeError::Api(anyhow::anyhow!(
+                        "unexpected response type"
+                    )));
+                }
+            }
+            Ok(balances)
+        }
+        .boxed())
+    }
+}
+
+impl Adaptor<FetchPositions> for HttpRequest {
+    fn from_request(req: FetchPositions) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        Ok(HttpRequest::PrivateGet(PrivateGet::Positions(
+            account::Positions {
+                inst_id: req.instrument,
+            },
+        )))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<FetchPositions as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            let mut positions = Vec::new();
+            for data in resp.data {
+                if let ResponseData::Position(position) = data {
+                    positions.push(Position::from(*position));
+                } else {
+                    return Err(ExchangeError::Api(anyhow::anyhow!(
+                        "unexpected response type"
+                    )));
+                }
+            }
+            Ok(positions)
+        }
+        .boxed())
+    }
+}
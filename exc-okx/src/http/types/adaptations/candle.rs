@@ -1,12 +1,14 @@
-use exc_core::types::candle::{Candle, QueryLastCandles};
+use exc_core::types::candle::{Candle, CandleSource, QueryLastCandles};
 use exc_core::types::CandleStream;
 use exc_core::Adaptor;
 use exc_core::ExchangeError;
+use rust_decimal::Decimal;
 use std::ops::RangeBounds;
 
 use crate::http::types::request::history_candles::HistoryCandles;
 use crate::http::types::request::Get;
 use crate::http::types::response::ResponseData;
+use crate::utils::inst_tag::index_of;
 use crate::utils::timestamp::millis_to_ts;
 use crate::utils::{
     period::period_to_bar,
@@ -26,14 +28,27 @@ impl Adaptor<QueryLastCandles> for HttpRequest {
         // from before to after.
         let start = start_bound_to_millis(query.start_bound());
         let end = end_bound_to_millis(query.end_bound());
-        let req = Self::Get(Get::HistoryCandles(HistoryCandles {
+        let mut candles = HistoryCandles {
             inst_id: query.inst().to_string(),
             after: end,
             before: start,
             bar: period_to_bar(&query.period()),
             limit: Some(limit),
-        }));
-        Ok(req)
+        };
+        let req = match query.source() {
+            CandleSource::Trade => Get::HistoryCandles(candles),
+            CandleSource::Mark => Get::HistoryMarkPriceCandles(candles),
+            CandleSource::Index => {
+                candles.inst_id = index_of(query.inst());
+                Get::HistoryIndexCandles(candles)
+            }
+            CandleSource::Premium => {
+                return Err(ExchangeError::Unsupported(anyhow::anyhow!(
+                    "premium candles are not supported by okx"
+                )))
+            }
+        };
+        Ok(Self::Get(req))
     }
 
     fn into_response(
@@ -42,19 +57,22 @@ impl Adaptor<QueryLastCandles> for HttpRequest {
         let stream = stream! {
                 for data in resp.data {
         trace!("received a data: {data:?}");
-            if let ResponseData::Candle(c) = data {
-                if let Some(ts) = millis_to_ts(c.0) {
+            let (ts, open, high, low, close, volume) = match data {
+                ResponseData::Candle(c) => (c.0, c.1, c.2, c.3, c.4, c.5),
+                ResponseData::PriceCandle(c) => (c.0, c.1, c.2, c.3, c.4, Decimal::ZERO),
+                _ => continue,
+            };
+            if let Some(ts) = millis_to_ts(ts) {
                 yield Ok(Candle {
                     ts,
-                    open: c.1,
-                    high: c.2,
-                    low: c.3,
-                    close: c.4,
-                    volume: c.5,
+                    open,
+                    high,
+                    low,
+                    close,
+                    volume,
                 });
-                } else {
+            } else {
                 yield Err(ExchangeError::Other(anyhow::anyhow!("cannot parse ts")));
-                }
             }
             }
                 };
@@ -0,0 +1,116 @@
+use exc_core::{
+    types::{FetchFundingRateHistory, FetchOpenInterestHistory, FundingRate, OpenInterest},
+    Adaptor, ExchangeError, Str,
+};
+use futures::FutureExt;
+use std::ops::RangeBounds;
+
+use crate::{
+    http::types::{
+        request::{
+            derivative::{FundingRateHistory, OpenInterestHistory},
+            Get, HttpRequest,
+        },
+        response::ResponseData,
+    },
+    utils::{
+        period::period_to_bar,
+        timestamp::{end_bound_to_millis, millis_to_ts, start_bound_to_millis},
+    },
+};
+
+const MAX_FUNDING_RATES_LIMIT: usize = 100;
+const MAX_OPEN_INTERESTS_LIMIT: usize = 100;
+
+impl Adaptor<FetchFundingRateHistory> for HttpRequest {
+    fn from_request(req: FetchFundingRateHistory) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        // from before to after.
+        Ok(Self::Get(Get::FundingRateHistory(FundingRateHistory {
+            after: end_bound_to_millis(req.end_bound()),
+            before: start_bound_to_millis(req.start_bound()),
+            inst_id: req.instrument,
+            limit: req.last.map(|last| last.min(MAX_FUNDING_RATES_LIMIT)),
+        })))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<FetchFundingRateHistory as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            resp.data
+                .into_iter()
+                .map(|data| {
+                    let ResponseData::FundingRate(rate) = data else {
+                        return Err(ExchangeError::Api(anyhow::anyhow!(
+                            "unexpected response type"
+                        )));
+                    };
+                    let ts = rate
+                        .funding_time
+                        .parse::<u64>()
+                        .ok()
+                        .and_then(millis_to_ts)
+                        .ok_or_else(|| {
+                            ExchangeError::Other(anyhow::anyhow!(
+                                "parse ts error, ts={}",
+                                rate.funding_time
+                            ))
+                        })?;
+                    Ok(FundingRate {
+                        ts,
+                        rate: rate.realized_rate.normalize(),
+                        funding_time: ts,
+                    })
+                })
+                .collect()
+        }
+        .boxed())
+    }
+}
+
+impl Adaptor<FetchOpenInterestHistory> for HttpRequest {
+    fn from_request(req: FetchOpenInterestHistory) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        let period = period_to_bar(&req.period).ok_or_else(|| {
+            ExchangeError::Other(anyhow::anyhow!("unsupported period: {}", req.period))
+        })?;
+        Ok(Self::Get(Get::OpenInterestHistory(OpenInterestHistory {
+            begin: start_bound_to_millis(req.start_bound()),
+            end: end_bound_to_millis(req.end_bound()),
+            inst_id: req.instrument,
+            period: Str::new_inline(period),
+            limit: req.last.map(|last| last.min(MAX_OPEN_INTERESTS_LIMIT)),
+        })))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<FetchOpenInterestHistory as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            resp.data
+                .into_iter()
+                .map(|data| {
+                    let ResponseData::OpenInterest(record) = data else {
+                        return Err(ExchangeError::Api(anyhow::anyhow!(
+                            "unexpected response type"
+                        )));
+                    };
+                    let ts = millis_to_ts(record.0).ok_or_else(|| {
+                        ExchangeError::Other(anyhow::anyhow!("parse ts error, ts={}", record.0))
+                    })?;
+                    Ok(OpenInterest {
+                        ts,
+                        size: record.1.normalize(),
+                        value: Some(record.3.normalize()),
+                    })
+                })
+                .collect()
+        }
+        .boxed())
+    }
+}
@@ -7,6 +7,10 @@ impl Request for HttpRequest {
     type Response = HttpResponse;
 }
 
+mod account;
 mod candle;
+mod derivative;
 mod instruments;
 mod trading;
+
+pub(crate) use trading::inst_type;
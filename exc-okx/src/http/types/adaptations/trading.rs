@@ -1,26 +1,138 @@
 use exc_core::{
     types::{
-        trading::{GetOrder, Order as ExcOrder, OrderId, OrderState, OrderStatus, Place},
-        OrderUpdate, TimeInForce,
+        trading::{
+            GetOrder, Order as ExcOrder, OrderId, OrderKind, OrderState, OrderStatus, Place,
+            PlaceOrder, Placed, Trigger, TriggerSource,
+        },
+        CancelAllAfter, FetchLastFills, FetchLastOrders, FetchOpenOrders, Fill, Liquidity,
+        OrderStream, OrderUpdate, OrdersPage, TimeInForce,
     },
-    Adaptor, ExchangeError,
+    Adaptor, ExchangeError, Str,
 };
-use futures::FutureExt;
+use futures::{FutureExt, StreamExt};
 use rust_decimal::{prelude::ToPrimitive, Decimal};
+use std::ops::RangeBounds;
 use time::OffsetDateTime;
 
 use crate::{
     http::types::{
-        request::{trading::Order, HttpRequest, PrivateGet},
-        response::ResponseData,
+        request::{
+            trading,
+            trading::{FillsHistory, Order, OrderAlgo, OrdersHistory, OrdersPending},
+            HttpRequest, PrivateGet, PrivatePost,
+        },
+        response::{OrderDetail, ResponseData},
+    },
+    utils::{
+        pos_side::{format_pos_side, parse_pos_side},
+        timestamp::{end_bound_to_millis, millis_to_ts, start_bound_to_millis},
     },
-    utils::timestamp::millis_to_ts,
 };
 
 fn decimal_to_ts(ts: Decimal) -> Option<OffsetDateTime> {
     millis_to_ts(ts.to_u64()?)
 }
 
+fn order_update(order: OrderDetail) -> Result<OrderUpdate, ExchangeError> {
+    let (target, buy) = match order.side.as_str() {
+        "buy" => (Place::with_size(order.size), true),
+        "sell" => (Place::with_size(-order.size), false),
+        side => {
+            return Err(ExchangeError::Other(anyhow::anyhow!(
+                "unexpected order side: {side}"
+            )));
+        }
+    };
+    let target = match order.order_type.as_str() {
+        "market" => target,
+        "limit" => {
+            if let Some(price) = order.price {
+                target.limit(price)
+            } else {
+                return Err(ExchangeError::Other(anyhow::anyhow!("limit without price")));
+            }
+        }
+        "fok" => {
+            if let Some(price) = order.price {
+                target.limit_with_tif(price, TimeInForce::FillOrKill)
+            } else {
+                return Err(ExchangeError::Other(anyhow::anyhow!("fok without price")));
+            }
+        }
+        "ioc" => {
+            if let Some(price) = order.price {
+                target.limit_with_tif(price, TimeInForce::ImmediateOrCancel)
+            } else {
+                return Err(ExchangeError::Other(anyhow::anyhow!("ioc without price")));
+            }
+        }
+        "post_only" => {
+            if let Some(price) = order.price {
+                target.post_only(price)
+            } else {
+                return Err(ExchangeError::Other(anyhow::anyhow!(
+                    "post_only without price"
+                )));
+            }
+        }
+        t => {
+            return Err(ExchangeError::Other(anyhow::anyhow!(
+                "unsupported order type: {t}"
+            )));
+        }
+    };
+    let mut state = OrderState::default();
+    let status = match order.state.as_str() {
+        "live" | "partially_filled" => OrderStatus::Pending,
+        "canceled" | "filled" => OrderStatus::Finished,
+        s => {
+            return Err(ExchangeError::Other(anyhow::anyhow!(
+                "unknown order status: {s}"
+            )))
+        }
+    };
+    let mut filled = order.filled_size;
+    filled.set_sign_positive(buy);
+    let cost = order.avg_price.unwrap_or(Decimal::ONE);
+    if let Some((ccy, fee)) = order
+        .fee
+        .and_then(|fee| order.fee_currency.map(|ccy| (ccy, fee)))
+    {
+        let f = state.fees.entry(ccy).or_default();
+        *f += fee;
+    }
+    if let Some((ccy, fee)) = order
+        .rebate
+        .and_then(|fee| order.rebate_currency.map(|ccy| (ccy, fee)))
+    {
+        let f = state.fees.entry(ccy).or_default();
+        *f += fee;
+    }
+    state.status = status;
+    state.filled = filled;
+    state.cost = cost;
+    #[cfg(not(feature = "prefer-client-id"))]
+    let id = OrderId::from(order.order_id);
+    #[cfg(feature = "prefer-client-id")]
+    let id = if let Some(id) = order.client_id {
+        OrderId::from(id)
+    } else {
+        return Err(crate::error::OkxError::MissingClientId.into());
+    };
+    Ok(OrderUpdate {
+        ts: decimal_to_ts(order.updated_at).ok_or_else(|| {
+            ExchangeError::Other(anyhow::anyhow!("parse ts error, ts={}", order.updated_at))
+        })?,
+        order: ExcOrder {
+            id,
+            target,
+            state,
+            trade: None,
+            position_side: parse_pos_side(&order.position_side),
+        },
+    })
+}
+
 impl Adaptor<GetOrder> for HttpRequest {
     fn from_request(req: GetOrder) -> Result<Self, exc_core::ExchangeError>
     where
@@ -28,10 +140,17 @@ impl Adaptor<GetOrder> for HttpRequest {
     {
         #[cfg(not(feature = "prefer-client-id"))]
         {
+            // The order ids are numeric, so the others must be client ids.
+            let id = req.id.as_smol_str().clone();
+            let (ord_id, cl_ord_id) = if id.bytes().all(|b| b.is_ascii_digit()) {
+                (Some(id), None)
+            } else {
+                (None, Some(id))
+            };
             Ok(HttpRequest::PrivateGet(PrivateGet::Order(Order {
                 inst_id: req.instrument,
-                ord_id: Some(req.id.as_smol_str().clone()),
-                cl_ord_id: None,
+                ord_id,
+                cl_ord_id,
             })))
         }
         #[cfg(feature = "prefer-client-id")]
@@ -50,119 +169,321 @@ impl Adaptor<GetOrder> for HttpRequest {
         Ok(async move {
             if let Some(data) = resp.data.pop() {
                 if let ResponseData::Order(order) = data {
-                    let order = *order;
-                    let (target, buy) = match order.side.as_str() {
-                        "buy" => (Place::with_size(order.size), true),
-                        "sell" => (Place::with_size(-order.size), false),
+                    order_update(*order)
+                } else {
+                    Err(ExchangeError::Api(anyhow::anyhow!(
+                        "unexpected response type"
+                    )))
+                }
+            } else {
+                Err(ExchangeError::Api(anyhow::anyhow!("empty response")))
+            }
+        }
+        .boxed())
+    }
+}
+
+/// Maximum number of orders in a page.
+const MAX_ORDERS_LIMIT: usize = 100;
+
+/// Guess the instrument type from the instrument id.
+pub(crate) fn inst_type(inst: &str) -> Str {
+    let parts = inst.split('-').count();
+    if inst.ends_with("-SWAP") {
+        Str::new_inline("SWAP")
+    } else if parts == 5 {
+        Str::new_inline("OPTION")
+    } else if parts == 3 {
+        Str::new_inline("FUTURES")
+    } else {
+        // Orders are placed in `cross` mode by default.
+        Str::new_inline("MARGIN")
+    }
+}
+
+impl Adaptor<FetchOpenOrders> for HttpRequest {
+    fn from_request(req: FetchOpenOrders) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        Ok(HttpRequest::PrivateGet(PrivateGet::OrdersPending(
+            OrdersPending {
+                inst_id: req.instrument,
+                limit: Some(MAX_ORDERS_LIMIT),
+            },
+        )))
+    }
+
+    fn into_response(resp: Self::Response) -> Result<OrderStream, ExchangeError> {
+        let orders = resp
+            .data
+            .into_iter()
+            .map(|data| match data {
+                ResponseData::Order(order) => order_update(*order),
+                _ => Err(ExchangeError::Api(anyhow::anyhow!(
+                    "unexpected response type"
+                ))),
+            })
+            .collect::<Vec<_>>();
+        Ok(futures::stream::iter(orders).boxed())
+    }
+}
+
+impl Adaptor<FetchLastOrders> for HttpRequest {
+    fn from_request(req: FetchLastOrders) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        let query = req.query;
+        Ok(HttpRequest::PrivateGet(PrivateGet::OrdersHistory(
+            OrdersHistory {
+                inst_type: inst_type(&query.instrument),
+                begin: start_bound_to_millis(query.start_bound()),
+                end: end_bound_to_millis(query.end_bound()),
+                inst_id: query.instrument,
+                limit: Some(req.last.min(MAX_ORDERS_LIMIT)),
+            },
+        )))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<FetchLastOrders as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            let mut page = OrdersPage::default();
+            for data in resp.data {
+                if let ResponseData::Order(order) = data {
+                    let created = decimal_to_ts(order.created_at);
+                    page.oldest = match (page.oldest, created) {
+                        (Some(oldest), Some(created)) => Some(oldest.min(created)),
+                        (oldest, created) => oldest.or(created),
+                    };
+                    page.orders.push(order_update(*order));
+                } else {
+                    return Err(ExchangeError::Api(anyhow::anyhow!(
+                        "unexpected response type"
+                    )));
+                }
+            }
+            Ok(page)
+        }
+        .boxed())
+    }
+}
+
+impl Adaptor<FetchLastFills> for HttpRequest {
+    fn from_request(req: FetchLastFills) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        let query = req.query;
+        Ok(HttpRequest::PrivateGet(PrivateGet::FillsHistory(
+            FillsHistory {
+                inst_type: inst_type(&query.instrument),
+                begin: start_bound_to_millis(query.start_bound()),
+                end: end_bound_to_millis(query.end_bound()),
+                inst_id: query.instrument,
+                limit: Some(req.last.min(MAX_ORDERS_LIMIT)),
+            },
+        )))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<FetchLastFills as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            resp.data
+                .into_iter()
+                .map(|data| {
+                    let ResponseData::Fill(fill) = data else {
+                        return Err(ExchangeError::Api(anyhow::anyhow!(
+                            "unexpected response type"
+                        )));
+                    };
+                    let size = match fill.side.as_str() {
+                        "buy" => fill.fill_sz,
+                        "sell" => -fill.fill_sz,
                         side => {
                             return Err(ExchangeError::Other(anyhow::anyhow!(
                                 "unexpected order side: {side}"
                             )));
                         }
                     };
-                    let target = match order.order_type.as_str() {
-                        "market" => target,
-                        "limit" => {
-                            if let Some(price) = order.price {
-                                target.limit(price)
-                            } else {
-                                return Err(ExchangeError::Other(anyhow::anyhow!(
-                                    "limit without price"
-                                )));
-                            }
-                        }
-                        "fok" => {
-                            if let Some(price) = order.price {
-                                target.limit_with_tif(price, TimeInForce::FillOrKill)
-                            } else {
-                                return Err(ExchangeError::Other(anyhow::anyhow!(
-                                    "fok without price"
-                                )));
-                            }
-                        }
-                        "ioc" => {
-                            if let Some(price) = order.price {
-                                target.limit_with_tif(price, TimeInForce::ImmediateOrCancel)
-                            } else {
-                                return Err(ExchangeError::Other(anyhow::anyhow!(
-                                    "ioc without price"
-                                )));
-                            }
-                        }
-                        "post_only" => {
-                            if let Some(price) = order.price {
-                                target.post_only(price)
-                            } else {
-                                return Err(ExchangeError::Other(anyhow::anyhow!(
-                                    "post_only without price"
-                                )));
-                            }
-                        }
-                        t => {
-                            return Err(ExchangeError::Other(anyhow::anyhow!(
-                                "unsupported order type: {t}"
-                            )));
-                        }
+                    let liquidity = match fill.exec_type.as_str() {
+                        "M" => Liquidity::Maker,
+                        _ => Liquidity::Taker,
                     };
-                    let mut state = OrderState::default();
-                    let status = match order.state.as_str() {
-                        "live" | "partially_filled" => OrderStatus::Pending,
-                        "canceled" | "filled" => OrderStatus::Finished,
-                        s => {
-                            return Err(ExchangeError::Other(anyhow::anyhow!(
-                                "unknown order status: {s}"
-                            )))
-                        }
-                    };
-                    let mut filled = order.filled_size;
-                    filled.set_sign_positive(buy);
-                    let cost = order.avg_price.unwrap_or(Decimal::ONE);
-                    if let Some((ccy, fee)) = order
-                        .fee
-                        .and_then(|fee| order.fee_currency.map(|ccy| (ccy, fee)))
-                    {
-                        let f = state.fees.entry(ccy).or_default();
-                        *f += fee;
-                    }
-                    if let Some((ccy, fee)) = order
-                        .rebate
-                        .and_then(|fee| order.rebate_currency.map(|ccy| (ccy, fee)))
-                    {
-                        let f = state.fees.entry(ccy).or_default();
-                        *f += fee;
-                    }
-                    state.status = status;
-                    state.filled = filled;
-                    state.cost = cost;
-                    #[cfg(not(feature = "prefer-client-id"))]
-                    let id = OrderId::from(order.order_id);
-                    #[cfg(feature = "prefer-client-id")]
-                    let id = if let Some(id) = order.client_id {
-                        OrderId::from(id)
-                    } else {
-                        return Err(crate::error::OkxError::MissingClientId.into());
-                    };
-                    Ok(OrderUpdate {
-                        ts: decimal_to_ts(order.updated_at).ok_or_else(|| {
-                            ExchangeError::Other(anyhow::anyhow!(
-                                "parse ts error, ts={}",
-                                order.updated_at
-                            ))
-                        })?,
-                        order: ExcOrder {
-                            id,
-                            target,
-                            state,
-                            trade: None,
-                        },
+                    let ts = fill
+                        .ts
+                        .parse::<u64>()
+                        .ok()
+                        .and_then(millis_to_ts)
+                        .ok_or_else(|| {
+                            ExchangeError::Other(anyhow::anyhow!("parse ts error, ts={}", fill.ts))
+                        })?;
+                    Ok(Fill {
+                        order_id: fill.ord_id,
+                        trade_id: fill.trade_id,
+                        price: fill.fill_px,
+                        size,
+                        fee: fill.fee,
+                        fee_asset: fill.fee_ccy,
+                        liquidity,
+                        ts,
                     })
-                } else {
-                    Err(ExchangeError::Api(anyhow::anyhow!(
-                        "unexpected response type"
-                    )))
-                }
+                })
+                .collect()
+        }
+        .boxed())
+    }
+}
+
+fn trigger_px_type(trigger: &Trigger) -> Str {
+    match trigger.source {
+        TriggerSource::Last => Str::new_inline("last"),
+        TriggerSource::Mark => Str::new_inline("mark"),
+        TriggerSource::Index => Str::new_inline("index"),
+    }
+}
+
+impl Adaptor<PlaceOrder> for HttpRequest {
+    fn from_request(req: PlaceOrder) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        let place = req.place;
+        let opts = req.opts;
+        let side = if place.size.is_sign_negative() {
+            "sell"
+        } else {
+            "buy"
+        };
+        #[cfg(not(feature = "prefer-client-id"))]
+        let algo_cl_ord_id = opts.client_id().map(Str::new);
+        #[cfg(feature = "prefer-client-id")]
+        let algo_cl_ord_id = Some(
+            opts.client_id()
+                .map(Str::new)
+                .unwrap_or_else(|| Str::new(uuid::Uuid::new_v4().simple().to_string())),
+        );
+        let mut algo = OrderAlgo {
+            inst_id: Str::new(opts.instrument()),
+            td_mode: opts
+                .custom()
+                .get("tdMode")
+                .cloned()
+                .unwrap_or_else(|| Str::new_inline("cross")),
+            ccy: opts.margin().map(Str::new),
+            side: Str::new_inline(side),
+            pos_side: Str::new_inline(format_pos_side(opts.position_side())),
+            ord_type: Str::new_inline("conditional"),
+            sz: Str::new(place.size.abs().to_string()),
+            reduce_only: opts.reduce_only().then_some(true),
+            algo_cl_ord_id,
+            tp_trigger_px: None,
+            tp_trigger_px_type: None,
+            tp_ord_px: None,
+            sl_trigger_px: None,
+            sl_trigger_px_type: None,
+            sl_ord_px: None,
+            callback_ratio: None,
+            active_px: None,
+        };
+        match place.kind {
+            OrderKind::StopMarket(trigger) => {
+                algo.sl_trigger_px = Some(Str::new(trigger.price.to_string()));
+                algo.sl_trigger_px_type = Some(trigger_px_type(&trigger));
+                algo.sl_ord_px = Some(Str::new_inline("-1"));
+            }
+            OrderKind::StopLimit(trigger, price) => {
+                algo.sl_trigger_px = Some(Str::new(trigger.price.to_string()));
+                algo.sl_trigger_px_type = Some(trigger_px_type(&trigger));
+                algo.sl_ord_px = Some(Str::new(price.to_string()));
+            }
+            OrderKind::TakeProfit(trigger) => {
+                algo.tp_trigger_px = Some(Str::new(trigger.price.to_string()));
+                algo.tp_trigger_px_type = Some(trigger_px_type(&trigger));
+                algo.tp_ord_px = Some(Str::new_inline("-1"));
+            }
+            OrderKind::TrailingStop(activation, rate) => {
+                algo.ord_type = Str::new_inline("move_order_stop");
+                algo.callback_ratio = Some(Str::new(rate.to_string()));
+                algo.active_px = activation.map(|trigger| Str::new(trigger.price.to_string()));
+            }
+            OrderKind::Market | OrderKind::Limit(_, _) | OrderKind::PostOnly(_) => {
+                return Err(ExchangeError::Unsupported(anyhow::anyhow!(
+                    "only conditional orders can be placed by the algo order API"
+                )));
+            }
+        }
+        Ok(HttpRequest::PrivatePost(PrivatePost::OrderAlgo(Box::new(
+            algo,
+        ))))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<PlaceOrder as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            let Some(ResponseData::AlgoOrder(ack)) = resp.data.into_iter().next() else {
+                return Err(ExchangeError::Api(anyhow::anyhow!(
+                    "unexpected response type"
+                )));
+            };
+            if ack.s_code != "0" {
+                return Err(ExchangeError::Api(anyhow::anyhow!(
+                    "code={} msg={}",
+                    ack.s_code,
+                    ack.s_msg
+                )));
+            }
+            #[cfg(not(feature = "prefer-client-id"))]
+            let id = OrderId::from(ack.algo_id);
+            #[cfg(feature = "prefer-client-id")]
+            let id = if let Some(id) = ack.algo_cl_ord_id {
+                OrderId::from(id)
             } else {
-                Err(ExchangeError::Api(anyhow::anyhow!("empty response")))
+                return Err(crate::error::OkxError::MissingClientId.into());
+            };
+            Ok(Placed {
+                ts: OffsetDateTime::now_utc(),
+                id,
+                order: None,
+            })
+        }
+        .boxed())
+    }
+}
+
+impl Adaptor<CancelAllAfter> for HttpRequest {
+    fn from_request(req: CancelAllAfter) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        let secs = req.timeout.as_secs();
+        if secs != 0 && !(10..=120).contains(&secs) {
+            return Err(ExchangeError::Other(anyhow::anyhow!(
+                "the timeout must be zero or between 10s and 120s"
+            )));
+        }
+        Ok(HttpRequest::PrivatePost(PrivatePost::CancelAllAfter(
+            trading::CancelAllAfter {
+                time_out: Str::new(secs.to_string()),
+            },
+        )))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<CancelAllAfter as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            if let Some(ResponseData::CancelAllAfter(detail)) = resp.data.into_iter().next() {
+                tracing::trace!(?detail, "cancel all after");
+                Ok(())
+            } else {
+                Err(ExchangeError::Api(anyhow::anyhow!(
+                    "unexpected response type"
+                )))
             }
         }
         .boxed())
@@ -0,0 +1,20 @@
+use exc_core::Str;
+use serde::Serialize;
+
+/// Balance.
+#[derive(Debug, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Balance {
+    /// Currencies (separated by comma).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ccy: Option<Str>,
+}
+
+/// Positions.
+#[derive(Debug, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Positions {
+    /// Instrument id.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inst_id: Option<Str>,
+}
@@ -0,0 +1,47 @@
+use exc_core::Str;
+use serde::Serialize;
+use serde_with::{serde_as, DisplayFromStr};
+
+/// Funding rate history.
+#[serde_as]
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRateHistory {
+    /// Instrument Id.
+    pub inst_id: Str,
+    /// After (older) (millis).
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<u64>,
+    /// Before (newer) (millis).
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<u64>,
+    /// Limit (last).
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+/// Open interest history.
+#[serde_as]
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterestHistory {
+    /// Instrument Id.
+    pub inst_id: Str,
+    /// Period.
+    pub period: Str,
+    /// Begin (newer than) (millis).
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub begin: Option<u64>,
+    /// End (older than) (millis).
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end: Option<u64>,
+    /// Limit (last).
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
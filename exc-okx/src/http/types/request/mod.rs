@@ -5,9 +5,17 @@ use serde::Serialize;
 
 use crate::key::OkxKey as Key;
 
+use self::account::{Balance, Positions};
+use self::derivative::{FundingRateHistory, OpenInterestHistory};
 use self::history_candles::HistoryCandles;
 use self::instruments::Instruments;
-use self::trading::Order;
+use self::trading::{CancelAllAfter, FillsHistory, Order, OrderAlgo, OrdersHistory, OrdersPending};
+
+/// Account.
+pub mod account;
+
+/// Derivative.
+pub mod derivative;
 
 /// History candles.
 pub mod history_candles;
@@ -25,6 +33,8 @@ pub enum HttpRequest {
     Get(Get),
     /// Private Get.
     PrivateGet(PrivateGet),
+    /// Private Post.
+    PrivatePost(PrivatePost),
 }
 
 /// Okx HTTP API get request types.
@@ -33,15 +43,27 @@ pub enum HttpRequest {
 pub enum Get {
     /// History candles.
     HistoryCandles(HistoryCandles),
+    /// Get history mark price candles.
+    HistoryMarkPriceCandles(HistoryCandles),
+    /// Get history index candles.
+    HistoryIndexCandles(HistoryCandles),
     /// Get instruments.
     Instruments(Instruments),
+    /// Funding rate history.
+    FundingRateHistory(FundingRateHistory),
+    /// Open interest history.
+    OpenInterestHistory(OpenInterestHistory),
 }
 
 impl Get {
     pub(crate) fn uri(&self) -> &'static str {
         match self {
             Self::HistoryCandles(_) => "/api/v5/market/history-candles",
+            Self::HistoryMarkPriceCandles(_) => "/api/v5/market/history-mark-price-candles",
+            Self::HistoryIndexCandles(_) => "/api/v5/market/history-index-candles",
             Self::Instruments(_) => "/api/v5/public/instruments",
+            Self::FundingRateHistory(_) => "/api/v5/public/funding-rate-history",
+            Self::OpenInterestHistory(_) => "/api/v5/rubik/stat/contracts/open-interest-history",
         }
     }
 }
@@ -52,12 +74,27 @@ impl Get {
 pub enum PrivateGet {
     /// Order.
     Order(Order),
+    /// Orders pending.
+    OrdersPending(OrdersPending),
+    /// Orders history.
+    OrdersHistory(OrdersHistory),
+    /// Fills history.
+    FillsHistory(FillsHistory),
+    /// Balance.
+    Balance(Balance),
+    /// Positions.
+    Positions(Positions),
 }
 
 impl PrivateGet {
     pub(crate) fn uri(&self) -> &'static str {
         match self {
             Self::Order(_) => "/api/v5/trade/order",
+            Self::OrdersPending(_) => "/api/v5/trade/orders-pending",
+            Self::OrdersHistory(_) => "/api/v5/trade/orders-history",
+            Self::FillsHistory(_) => "/api/v5/trade/fills-history",
+            Self::Balance(_) => "/api/v5/account/balance",
+            Self::Positions(_) => "/api/v5/account/positions",
         }
     }
 
@@ -65,7 +102,11 @@ impl PrivateGet {
         serde_qs::to_string(self)
             .map_err(|err| ExchangeError::Other(err.into()))
             .and_then(|q| {
-                let uri = format!("{}?{q}", self.uri());
+                let uri = if q.is_empty() {
+                    self.uri().to_string()
+                } else {
+                    format!("{}?{q}", self.uri())
+                };
                 let sign = key
                     .sign_now("GET", &uri, false)
                     .map_err(|e| ExchangeError::KeyError(anyhow::anyhow!("{e}")))?;
@@ -79,3 +120,41 @@ impl PrivateGet {
             })
     }
 }
+
+/// Okx HTTP API post request types.
+#[derive(Debug, Serialize, Clone)]
+#[serde(untagged)]
+pub enum PrivatePost {
+    /// Cancel all after.
+    CancelAllAfter(CancelAllAfter),
+    /// Place algo order.
+    OrderAlgo(Box<OrderAlgo>),
+}
+
+impl PrivatePost {
+    pub(crate) fn uri(&self) -> &'static str {
+        match self {
+            Self::CancelAllAfter(_) => "/api/v5/trade/cancel-all-after",
+            Self::OrderAlgo(_) => "/api/v5/trade/order-algo",
+        }
+    }
+
+    pub(crate) fn to_request(&self, host: &str, key: &Key) -> Result<Request<Body>, ExchangeError> {
+        serde_json::to_string(self)
+            .map_err(|err| ExchangeError::Other(err.into()))
+            .and_then(|body| {
+                let uri = self.uri();
+                let sign = key
+                    .sign_now("POST", &format!("{uri}{body}"), false)
+                    .map_err(|e| ExchangeError::KeyError(anyhow::anyhow!("{e}")))?;
+                Request::post(format!("{host}{uri}"))
+                    .header("OK-ACCESS-KEY", key.apikey.as_str())
+                    .header("OK-ACCESS-SIGN", sign.signature.as_str())
+                    .header("OK-ACCESS-TIMESTAMP", sign.timestamp.as_str())
+                    .header("OK-ACCESS-PASSPHRASE", key.passphrase.as_str())
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(body))
+                    .map_err(|err| ExchangeError::Other(err.into()))
+            })
+    }
+}
@@ -1,5 +1,6 @@
 use exc_core::Str;
 use serde::Serialize;
+use serde_with::{serde_as, DisplayFromStr};
 
 /// Order
 #[derive(Debug, Serialize, Clone)]
@@ -14,3 +15,122 @@ pub struct Order {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cl_ord_id: Option<Str>,
 }
+
+/// Cancel all orders after the countdown.
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelAllAfter {
+    /// Countdown in seconds (`0` to disable, otherwise between `10` and `120`).
+    pub time_out: Str,
+}
+
+/// Place an algo order.
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderAlgo {
+    /// Instrument Id.
+    pub inst_id: Str,
+    /// Trade mode.
+    pub td_mode: Str,
+    /// Margin currency.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ccy: Option<Str>,
+    /// Side.
+    pub side: Str,
+    /// Position side.
+    pub pos_side: Str,
+    /// Algo order type.
+    pub ord_type: Str,
+    /// Size.
+    pub sz: Str,
+    /// Reduce only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reduce_only: Option<bool>,
+    /// Client-supplied algo id.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub algo_cl_ord_id: Option<Str>,
+    /// Take-profit trigger price.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tp_trigger_px: Option<Str>,
+    /// Take-profit trigger price type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tp_trigger_px_type: Option<Str>,
+    /// Take-profit order price (`-1` for market).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tp_ord_px: Option<Str>,
+    /// Stop-loss trigger price.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sl_trigger_px: Option<Str>,
+    /// Stop-loss trigger price type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sl_trigger_px_type: Option<Str>,
+    /// Stop-loss order price (`-1` for market).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sl_ord_px: Option<Str>,
+    /// Callback ratio of trailing stop.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_ratio: Option<Str>,
+    /// Activation price of trailing stop.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active_px: Option<Str>,
+}
+
+/// Orders pending.
+#[serde_as]
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrdersPending {
+    /// Instrument Id.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inst_id: Option<Str>,
+    /// Limit.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+/// Orders history (newest first).
+#[serde_as]
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrdersHistory {
+    /// Instrument type.
+    pub inst_type: Str,
+    /// Instrument Id.
+    pub inst_id: Str,
+    /// Begin (millis of creation time).
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub begin: Option<u64>,
+    /// End (millis of creation time).
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end: Option<u64>,
+    /// Limit.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+/// Fills history (newest first).
+#[serde_as]
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FillsHistory {
+    /// Instrument type.
+    pub inst_type: Str,
+    /// Instrument Id.
+    pub inst_id: Str,
+    /// Begin (millis).
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub begin: Option<u64>,
+    /// End (millis).
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end: Option<u64>,
+    /// Limit.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
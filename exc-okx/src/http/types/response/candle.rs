@@ -16,3 +16,15 @@ pub struct Candle(
     #[serde_as(as = "DisplayFromStr")] pub Decimal,
     #[serde_as(as = "DisplayFromStr")] pub Decimal,
 );
+
+/// Price candle (OHLC) of mark price or index.
+#[serde_as]
+#[derive(Debug, Deserialize)]
+pub struct PriceCandle(
+    #[serde_as(as = "DisplayFromStr")] pub u64,
+    #[serde_as(as = "DisplayFromStr")] pub Decimal,
+    #[serde_as(as = "DisplayFromStr")] pub Decimal,
+    #[serde_as(as = "DisplayFromStr")] pub Decimal,
+    #[serde_as(as = "DisplayFromStr")] pub Decimal,
+    #[serde_as(as = "DisplayFromStr")] pub Decimal,
+);
@@ -0,0 +1,27 @@
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use serde_with::{serde_as, DisplayFromStr};
+
+/// Funding rate detail.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRateDetail {
+    /// Inst.
+    pub inst_id: String,
+    /// Funding rate.
+    pub funding_rate: Decimal,
+    /// Realized funding rate.
+    pub realized_rate: Decimal,
+    /// Funding time (millis).
+    pub funding_time: String,
+}
+
+/// Open interest record (ts, oi, oiCcy, oiUsd).
+#[serde_as]
+#[derive(Debug, Clone, Deserialize)]
+pub struct OpenInterestRecord(
+    #[serde_as(as = "DisplayFromStr")] pub u64,
+    #[serde_as(as = "DisplayFromStr")] pub Decimal,
+    #[serde_as(as = "DisplayFromStr")] pub Decimal,
+    #[serde_as(as = "DisplayFromStr")] pub Decimal,
+);
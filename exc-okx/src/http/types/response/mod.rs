@@ -8,10 +8,14 @@ pub mod candle;
 /// Trading.
 pub mod trading;
 
-pub use candle::Candle;
-pub use trading::OrderDetail;
+/// Derivative.
+pub mod derivative;
 
-pub use crate::websocket::types::messages::event::OkxInstrumentMeta;
+pub use candle::{Candle, PriceCandle};
+pub use derivative::{FundingRateDetail, OpenInterestRecord};
+pub use trading::{AlgoOrderAck, CancelAllAfterDetail, FillDetail, OrderDetail};
+
+pub use crate::websocket::types::messages::event::{OkxAccount, OkxInstrumentMeta, OkxPosition};
 
 /// Okx HTTP API Response (with `code` and `msg`).
 #[derive(Debug, Deserialize)]
@@ -55,8 +59,89 @@ impl TryFrom<FullHttpResponse> for HttpResponse {
 pub enum ResponseData {
     /// Candle.
     Candle(Candle),
+    /// Open interest.
+    // Placed before the struct variants, which can also be deserialized from arrays.
+    OpenInterest(OpenInterestRecord),
+    /// Price candle.
+    PriceCandle(PriceCandle),
     /// Instruments.
     Instruments(OkxInstrumentMeta),
     /// Order.
     Order(Box<OrderDetail>),
+    /// Account.
+    Account(OkxAccount),
+    /// Position.
+    Position(Box<OkxPosition>),
+    /// Cancel all after.
+    CancelAllAfter(CancelAllAfterDetail),
+    /// Fill.
+    Fill(FillDetail),
+    /// Algo order.
+    AlgoOrder(AlgoOrderAck),
+    /// Funding rate.
+    FundingRate(FundingRateDetail),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_fills_history() -> anyhow::Result<()> {
+        let json = r#"{
+            "code": "0",
+            "msg": "",
+            "data": [{
+                "instType": "SPOT",
+                "instId": "BTC-USDT",
+                "tradeId": "123",
+                "ordId": "312269865356374016",
+                "clOrdId": "b16",
+                "billId": "1111",
+                "tag": "",
+                "fillPx": "999",
+                "fillSz": "3",
+                "fillIdxPx": "",
+                "fillPnl": "0",
+                "fillPxVol": "",
+                "fillPxUsd": "",
+                "fillMarkVol": "",
+                "fillFwdPx": "",
+                "fillMarkPx": "",
+                "side": "buy",
+                "posSide": "long",
+                "execType": "M",
+                "feeCcy": "USDT",
+                "fee": "-0.1",
+                "ts": "1597026383085"
+            }]
+        }"#;
+        let resp = serde_json::from_str::<FullHttpResponse>(json)?;
+        assert!(matches!(resp.data[0], ResponseData::Fill(_)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_interest_history() -> anyhow::Result<()> {
+        let json = r#"{
+            "code": "0",
+            "msg": "",
+            "data": [["1701417600000", "731377.57500501", "111", "3236.57"]]
+        }"#;
+        let resp = serde_json::from_str::<FullHttpResponse>(json)?;
+        assert!(matches!(resp.data[0], ResponseData::OpenInterest(_)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_price_candles() -> anyhow::Result<()> {
+        let json = r#"{
+            "code": "0",
+            "msg": "",
+            "data": [["1597026383085", "3.721", "3.743", "3.677", "3.708", "1"]]
+        }"#;
+        let resp = serde_json::from_str::<FullHttpResponse>(json)?;
+        assert!(matches!(resp.data[0], ResponseData::PriceCandle(_)));
+        Ok(())
+    }
 }
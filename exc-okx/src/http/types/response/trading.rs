@@ -113,3 +113,58 @@ pub struct OrderDetail {
     #[serde(rename = "cTime")]
     pub created_at: Decimal,
 }
+
+/// Cancel all after detail.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelAllAfterDetail {
+    /// Trigger time (`0` if disabled).
+    pub trigger_time: String,
+    /// Request time.
+    pub ts: String,
+}
+
+/// Fill detail.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FillDetail {
+    /// Inst.
+    pub inst_id: String,
+    /// Trade id.
+    pub trade_id: Str,
+    /// Order id.
+    pub ord_id: Str,
+    /// Bill id.
+    pub bill_id: Str,
+    /// Filled price.
+    pub fill_px: Decimal,
+    /// Filled size.
+    pub fill_sz: Decimal,
+    /// Order side.
+    pub side: String,
+    /// Liquidity (`T` for taker and `M` for maker).
+    pub exec_type: String,
+    /// Fee currency.
+    pub fee_ccy: Asset,
+    /// Fee (negative for paid).
+    pub fee: Decimal,
+    /// Timestamp.
+    pub ts: String,
+}
+
+/// Algo order acknowledgement.
+#[serde_as]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlgoOrderAck {
+    /// Algo id.
+    pub algo_id: Str,
+    /// Client-supplied algo id.
+    #[serde_as(as = "NoneAsEmptyString")]
+    #[serde(default)]
+    pub algo_cl_ord_id: Option<Str>,
+    /// Code.
+    pub s_code: String,
+    /// Message.
+    pub s_msg: String,
+}
@@ -2,8 +2,12 @@ use exc_core::{
     types::{
         instrument::{FetchInstruments, SubscribeInstruments},
         utils::Reconnect,
-        CancelOrder, GetOrder, PlaceOrder, QueryLastCandles, SubscribeBidAsk, SubscribeOrders,
-        SubscribeTickers, SubscribeTrades,
+        AmendOrder, CancelAllAfter, CancelAllOrders, CancelOrder, CancelOrders, FetchBalances,
+        FetchFundingRateHistory, FetchLastFills, FetchLastOrders, FetchOpenInterestHistory,
+        FetchOpenOrders, FetchPositions, GetOrder, PlaceOrder, PlaceOrders, QueryLastCandles,
+        SubscribeBalances, SubscribeBidAsk, SubscribeCandles, SubscribeFundingRate,
+        SubscribeIndexPrice, SubscribeLiquidations, SubscribeMarkPrice, SubscribeOpenInterest,
+        SubscribeOrderBook, SubscribeOrders, SubscribePositions, SubscribeTickers, SubscribeTrades,
     },
     Adaptor, ExchangeError, Request,
 };
@@ -11,7 +15,7 @@ use exc_core::{
 use crate::http::types::request::HttpRequest;
 use crate::websocket::Request as WsRequest;
 
-use super::OkxRequest;
+use super::{OkxRequest, OkxResponse};
 
 impl Adaptor<SubscribeInstruments> for OkxRequest {
     fn from_request(req: SubscribeInstruments) -> Result<Self, ExchangeError> {
@@ -111,17 +115,66 @@ impl Adaptor<GetOrder> for OkxRequest {
     }
 }
 
+impl Adaptor<FetchOpenOrders> for OkxRequest {
+    fn from_request(req: FetchOpenOrders) -> Result<Self, ExchangeError> {
+        let req = HttpRequest::from_request(req)?;
+        Ok(Self::Http(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<FetchOpenOrders as Request>::Response, ExchangeError> {
+        let res = resp.http()?;
+        <HttpRequest as Adaptor<FetchOpenOrders>>::into_response(res)
+    }
+}
+
+impl Adaptor<FetchLastOrders> for OkxRequest {
+    fn from_request(req: FetchLastOrders) -> Result<Self, ExchangeError> {
+        let req = HttpRequest::from_request(req)?;
+        Ok(Self::Http(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<FetchLastOrders as Request>::Response, ExchangeError> {
+        let res = resp.http()?;
+        <HttpRequest as Adaptor<FetchLastOrders>>::into_response(res)
+    }
+}
+
+impl Adaptor<FetchLastFills> for OkxRequest {
+    fn from_request(req: FetchLastFills) -> Result<Self, ExchangeError> {
+        let req = HttpRequest::from_request(req)?;
+        Ok(Self::Http(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<FetchLastFills as Request>::Response, ExchangeError> {
+        let res = resp.http()?;
+        <HttpRequest as Adaptor<FetchLastFills>>::into_response(res)
+    }
+}
+
 impl Adaptor<PlaceOrder> for OkxRequest {
     fn from_request(req: PlaceOrder) -> Result<Self, ExchangeError> {
-        let req = WsRequest::from_request(req)?;
-        Ok(Self::Ws(req))
+        if req.place.kind.is_conditional() {
+            let req = HttpRequest::from_request(req)?;
+            Ok(Self::Http(req))
+        } else {
+            let req = WsRequest::from_request(req)?;
+            Ok(Self::Ws(req))
+        }
     }
 
     fn into_response(
         resp: Self::Response,
     ) -> Result<<PlaceOrder as Request>::Response, ExchangeError> {
-        let res = resp.ws()?;
-        <WsRequest as Adaptor<PlaceOrder>>::into_response(res)
+        match resp {
+            OkxResponse::Http(res) => <HttpRequest as Adaptor<PlaceOrder>>::into_response(res),
+            OkxResponse::Ws(res) => <WsRequest as Adaptor<PlaceOrder>>::into_response(res),
+        }
     }
 }
 
@@ -139,6 +192,76 @@ impl Adaptor<CancelOrder> for OkxRequest {
     }
 }
 
+impl Adaptor<PlaceOrders> for OkxRequest {
+    fn from_request(req: PlaceOrders) -> Result<Self, ExchangeError> {
+        let req = WsRequest::from_request(req)?;
+        Ok(Self::Ws(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<PlaceOrders as Request>::Response, ExchangeError> {
+        let res = resp.ws()?;
+        <WsRequest as Adaptor<PlaceOrders>>::into_response(res)
+    }
+}
+
+impl Adaptor<CancelOrders> for OkxRequest {
+    fn from_request(req: CancelOrders) -> Result<Self, ExchangeError> {
+        let req = WsRequest::from_request(req)?;
+        Ok(Self::Ws(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<CancelOrders as Request>::Response, ExchangeError> {
+        let res = resp.ws()?;
+        <WsRequest as Adaptor<CancelOrders>>::into_response(res)
+    }
+}
+
+impl Adaptor<CancelAllOrders> for OkxRequest {
+    fn from_request(req: CancelAllOrders) -> Result<Self, ExchangeError> {
+        let req = WsRequest::from_request(req)?;
+        Ok(Self::Ws(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<CancelAllOrders as Request>::Response, ExchangeError> {
+        let res = resp.ws()?;
+        <WsRequest as Adaptor<CancelAllOrders>>::into_response(res)
+    }
+}
+
+impl Adaptor<CancelAllAfter> for OkxRequest {
+    fn from_request(req: CancelAllAfter) -> Result<Self, ExchangeError> {
+        let req = HttpRequest::from_request(req)?;
+        Ok(Self::Http(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<CancelAllAfter as Request>::Response, ExchangeError> {
+        let res = resp.http()?;
+        <HttpRequest as Adaptor<CancelAllAfter>>::into_response(res)
+    }
+}
+
+impl Adaptor<AmendOrder> for OkxRequest {
+    fn from_request(req: AmendOrder) -> Result<Self, ExchangeError> {
+        let req = WsRequest::from_request(req)?;
+        Ok(Self::Ws(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<AmendOrder as Request>::Response, ExchangeError> {
+        let res = resp.ws()?;
+        <WsRequest as Adaptor<AmendOrder>>::into_response(res)
+    }
+}
+
 impl Adaptor<Reconnect> for OkxRequest {
     fn from_request(_req: Reconnect) -> Result<Self, ExchangeError> {
         Ok(Self::Ws(WsRequest::reconnect()))
@@ -167,3 +290,185 @@ impl Adaptor<SubscribeOrders> for OkxRequest {
         <WsRequest as Adaptor<SubscribeOrders>>::into_response(res)
     }
 }
+
+impl Adaptor<FetchBalances> for OkxRequest {
+    fn from_request(req: FetchBalances) -> Result<Self, ExchangeError> {
+        let req = HttpRequest::from_request(req)?;
+        Ok(Self::Http(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<FetchBalances as Request>::Response, ExchangeError> {
+        let res = resp.http()?;
+        <HttpRequest as Adaptor<FetchBalances>>::into_response(res)
+    }
+}
+
+impl Adaptor<SubscribeBalances> for OkxRequest {
+    fn from_request(req: SubscribeBalances) -> Result<Self, ExchangeError> {
+        let req = WsRequest::from_request(req)?;
+        Ok(Self::Ws(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SubscribeBalances as Request>::Response, ExchangeError> {
+        let res = resp.ws()?;
+        <WsRequest as Adaptor<SubscribeBalances>>::into_response(res)
+    }
+}
+
+impl Adaptor<FetchPositions> for OkxRequest {
+    fn from_request(req: FetchPositions) -> Result<Self, ExchangeError> {
+        let req = HttpRequest::from_request(req)?;
+        Ok(Self::Http(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<FetchPositions as Request>::Response, ExchangeError> {
+        let res = resp.http()?;
+        <HttpRequest as Adaptor<FetchPositions>>::into_response(res)
+    }
+}
+
+impl Adaptor<SubscribePositions> for OkxRequest {
+    fn from_request(req: SubscribePositions) -> Result<Self, ExchangeError> {
+        let req = WsRequest::from_request(req)?;
+        Ok(Self::Ws(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SubscribePositions as Request>::Response, ExchangeError> {
+        let res = resp.ws()?;
+        <WsRequest as Adaptor<SubscribePositions>>::into_response(res)
+    }
+}
+
+impl Adaptor<SubscribeOrderBook> for OkxRequest {
+    fn from_request(req: SubscribeOrderBook) -> Result<Self, ExchangeError> {
+        let req = WsRequest::from_request(req)?;
+        Ok(Self::Ws(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SubscribeOrderBook as Request>::Response, ExchangeError> {
+        let res = resp.ws()?;
+        <WsRequest as Adaptor<SubscribeOrderBook>>::into_response(res)
+    }
+}
+
+impl Adaptor<SubscribeMarkPrice> for OkxRequest {
+    fn from_request(req: SubscribeMarkPrice) -> Result<Self, ExchangeError> {
+        let req = WsRequest::from_request(req)?;
+        Ok(Self::Ws(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SubscribeMarkPrice as Request>::Response, ExchangeError> {
+        let res = resp.ws()?;
+        <WsRequest as Adaptor<SubscribeMarkPrice>>::into_response(res)
+    }
+}
+
+impl Adaptor<SubscribeIndexPrice> for OkxRequest {
+    fn from_request(req: SubscribeIndexPrice) -> Result<Self, ExchangeError> {
+        let req = WsRequest::from_request(req)?;
+        Ok(Self::Ws(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SubscribeIndexPrice as Request>::Response, ExchangeError> {
+        let res = resp.ws()?;
+        <WsRequest as Adaptor<SubscribeIndexPrice>>::into_response(res)
+    }
+}
+
+impl Adaptor<SubscribeFundingRate> for OkxRequest {
+    fn from_request(req: SubscribeFundingRate) -> Result<Self, ExchangeError> {
+        let req = WsRequest::from_request(req)?;
+        Ok(Self::Ws(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SubscribeFundingRate as Request>::Response, ExchangeError> {
+        let res = resp.ws()?;
+        <WsRequest as Adaptor<SubscribeFundingRate>>::into_response(res)
+    }
+}
+
+impl Adaptor<FetchFundingRateHistory> for OkxRequest {
+    fn from_request(req: FetchFundingRateHistory) -> Result<Self, ExchangeError> {
+        let req = HttpRequest::from_request(req)?;
+        Ok(Self::Http(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<FetchFundingRateHistory as Request>::Response, ExchangeError> {
+        let res = resp.http()?;
+        <HttpRequest as Adaptor<FetchFundingRateHistory>>::into_response(res)
+    }
+}
+
+impl Adaptor<SubscribeOpenInterest> for OkxRequest {
+    fn from_request(req: SubscribeOpenInterest) -> Result<Self, ExchangeError> {
+        let req = WsRequest::from_request(req)?;
+        Ok(Self::Ws(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SubscribeOpenInterest as Request>::Response, ExchangeError> {
+        let res = resp.ws()?;
+        <WsRequest as Adaptor<SubscribeOpenInterest>>::into_response(res)
+    }
+}
+
+impl Adaptor<FetchOpenInterestHistory> for OkxRequest {
+    fn from_request(req: FetchOpenInterestHistory) -> Result<Self, ExchangeError> {
+        let req = HttpRequest::from_request(req)?;
+        Ok(Self::Http(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<FetchOpenInterestHistory as Request>::Response, ExchangeError> {
+        let res = resp.http()?;
+        <HttpRequest as Adaptor<FetchOpenInterestHistory>>::into_response(res)
+    }
+}
+
+impl Adaptor<SubscribeLiquidations> for OkxRequest {
+    fn from_request(req: SubscribeLiquidations) -> Result<Self, ExchangeError> {
+        let req = WsRequest::from_request(req)?;
+        Ok(Self::Ws(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SubscribeLiquidations as Request>::Response, ExchangeError> {
+        let res = resp.ws()?;
+        <WsRequest as Adaptor<SubscribeLiquidations>>::into_response(res)
+    }
+}
+
+impl Adaptor<SubscribeCandles> for OkxRequest {
+    fn from_request(req: SubscribeCandles) -> Result<Self, ExchangeError> {
+        let req = WsRequest::from_request(req)?;
+        Ok(Self::Ws(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SubscribeCandles as Request>::Response, ExchangeError> {
+        let res = resp.ws()?;
+        <WsRequest as Adaptor<SubscribeCandles>>::into_response(res)
+    }
+}
@@ -80,6 +80,19 @@ impl Endpoint {
         self
     }
 
+    /// Switch to the business websocket endpoint,
+    /// which serves the candle channels instead of the public one.
+    pub fn business(&mut self, enable: bool) -> &mut Self {
+        self.ws.business(enable);
+        self
+    }
+
+    /// Limit http requests with the default per-endpoint limits.
+    pub fn http_weight_limited(&mut self) -> &mut Self {
+        self.http.weight_limits(crate::http::limit::weight_limits());
+        self
+    }
+
     /// Set whether to use the testing endpoint.
     pub fn testing(&mut self, enable: bool) -> &mut Self {
         self.ws.testing(enable);
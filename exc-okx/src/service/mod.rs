@@ -1,5 +1,6 @@
 use exc_core::retry::RetryPolicy;
 use exc_core::transport::http::channel::HttpsChannel;
+use exc_core::weight::WeightLimit;
 use exc_core::{ExchangeError, Request};
 use futures::future::{ready, BoxFuture};
 use futures::{FutureExt, TryFutureExt};
@@ -78,7 +79,7 @@ impl Request for OkxRequest {
     type Response = OkxResponse;
 }
 
-type HttpInner = OkxHttpApi<HttpsChannel>;
+type HttpInner = WeightLimit<OkxHttpApi<HttpsChannel>>;
 type Http = Retry<RetryPolicy<HttpRequest, HttpResponse, fn(&ExchangeError) -> bool>, HttpInner>;
 type Ws = WsChannel;
 
@@ -24,3 +24,9 @@ pub fn parse_inst_tag(tag: &str) -> Result<(Str, Params), OkxError> {
         .unwrap_or_else(|| (tag, Params::default()));
     Ok((Str::new(tag), params))
 }
+
+/// Get the index name of an instrument.
+/// The index of a derivative (e.g. `BTC-USDT-SWAP`) is named by its first two parts.
+pub fn index_of(inst: &str) -> String {
+    inst.splitn(3, '-').take(2).collect::<Vec<_>>().join("-")
+}
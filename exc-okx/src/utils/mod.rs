@@ -12,3 +12,6 @@ pub mod period;
 
 /// Parse Instrument Tag.
 pub mod inst_tag;
+
+/// Position side.
+pub mod pos_side;
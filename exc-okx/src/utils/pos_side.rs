@@ -0,0 +1,19 @@
+use exc_core::types::PositionSide;
+
+/// Format the position side as the `posSide` parameter.
+pub fn format_pos_side(side: PositionSide) -> &'static str {
+    match side {
+        PositionSide::Long => "long",
+        PositionSide::Short => "short",
+        PositionSide::Net => "net",
+    }
+}
+
+/// Parse the `posSide` field (empty or unknown as net).
+pub fn parse_pos_side(side: &str) -> PositionSide {
+    match side {
+        "long" => PositionSide::Long,
+        "short" => PositionSide::Short,
+        _ => PositionSide::Net,
+    }
+}
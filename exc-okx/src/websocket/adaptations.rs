@@ -1,21 +1,41 @@
 use exc_core::{
     types::{
         instrument::{InstrumentMeta, SubscribeInstruments},
-        trading::{CancelOrder, OrderId, PlaceOrder},
-        BidAsk, Canceled, OrderUpdate, Placed, SubscribeBidAsk, SubscribeOrders, SubscribeTrades,
-        Trade,
+        trading::{
+            AmendOrder, CancelAllOrders, CancelOrder, CancelOrders, OrderId, PlaceOrder,
+            PlaceOrders,
+        },
+        Amended, Balance, BidAsk, Canceled, CandleUpdate, FundingRate, IndexPrice, Liquidation,
+        MarkPrice, OpenInterest, OrderUpdate, Placed, Position, SubscribeBalances, SubscribeBidAsk,
+        SubscribeCandles, SubscribeFundingRate, SubscribeIndexPrice, SubscribeLiquidations,
+        SubscribeMarkPrice, SubscribeOpenInterest, SubscribeOrderBook, SubscribeOrders,
+        SubscribePositions, SubscribeTrades, Trade,
     },
     Adaptor, ExchangeError,
 };
-use futures::{future::ready, stream::iter, FutureExt, StreamExt, TryStreamExt};
+use futures::{
+    future::ready,
+    stream::{iter, BoxStream},
+    FutureExt, StreamExt, TryStreamExt,
+};
 use time::OffsetDateTime;
 
-use crate::{error::OkxError, utils::inst_tag::parse_inst_tag};
+use crate::{
+    error::OkxError,
+    http::types::adaptations::inst_type,
+    utils::{
+        inst_tag::{index_of, parse_inst_tag},
+        period::period_to_bar,
+    },
+};
 
 use super::{
     types::{
         messages::{
-            event::{order::OkxOrder, Event, OkxInstrumentMeta, TradeResponse},
+            event::{
+                order::OkxOrder, Event, OkxAccount, OkxInstrumentMeta, OkxOrderBook, OkxPosition,
+                OrderData, TradeResponse,
+            },
             Args,
         },
         response::StatusKind,
@@ -126,11 +146,114 @@ impl Adaptor<SubscribeOrders> for Request {
     }
 }
 
+impl Adaptor<SubscribeBalances> for Request {
+    fn from_request(_req: SubscribeBalances) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        Ok(Self::subscribe(Args::subscribe_account()))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SubscribeBalances as exc_core::Request>::Response, ExchangeError> {
+        match resp {
+            Response::Error(err) => Err(ExchangeError::Other(anyhow::anyhow!("status: {err}"))),
+            Response::Reconnected => Err(ExchangeError::Other(anyhow::anyhow!(
+                "invalid response kind"
+            ))),
+            Response::Streaming(stream) => {
+                let stream = stream
+                    .skip(1)
+                    .filter_map(|frame| {
+                        ready(match frame {
+                            Ok(frame) => frame.into_change().map(Ok),
+                            Err(err) => Some(Err(err)),
+                        })
+                    })
+                    .flat_map(|change| match change {
+                        Ok(change) => iter(change.deserialize_data::<OkxAccount>())
+                            .filter_map(|m| match m {
+                                Ok(m) => ready(Some(iter(m.details).map(|d| Ok(Balance::from(d))))),
+                                Err(err) => {
+                                    error!(%err, "deserialize account error, skipped.");
+                                    ready(None)
+                                }
+                            })
+                            .flatten()
+                            .left_stream(),
+                        Err(err) => {
+                            futures::stream::once(
+                                async move { Err(ExchangeError::Other(err.into())) },
+                            )
+                            .right_stream()
+                        }
+                    })
+                    .boxed();
+                Ok(stream)
+            }
+        }
+    }
+}
+
+impl Adaptor<SubscribePositions> for Request {
+    fn from_request(_req: SubscribePositions) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        Ok(Self::subscribe(Args::subscribe_positions()))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SubscribePositions as exc_core::Request>::Response, ExchangeError> {
+        match resp {
+            Response::Error(err) => Err(ExchangeError::Other(anyhow::anyhow!("status: {err}"))),
+            Response::Reconnected => Err(ExchangeError::Other(anyhow::anyhow!(
+                "invalid response kind"
+            ))),
+            Response::Streaming(stream) => {
+                let stream =
+                    stream
+                        .skip(1)
+                        .filter_map(|frame| {
+                            ready(match frame {
+                                Ok(frame) => frame.into_change().map(Ok),
+                                Err(err) => Some(Err(err)),
+                            })
+                        })
+                        .flat_map(|change| match change {
+                            Ok(change) => iter(change.deserialize_data::<OkxPosition>())
+                                .filter_map(|m| match m {
+                                    Ok(m) => ready(Some(Ok(Position::from(m)))),
+                                    Err(err) => {
+                                        error!(%err, "deserialize position error, skipped.");
+                                        ready(None)
+                                    }
+                                })
+                                .left_stream(),
+                            Err(err) => futures::stream::once(async move {
+                                Err(ExchangeError::Other(err.into()))
+                            })
+                            .right_stream(),
+                        })
+                        .boxed();
+                Ok(stream)
+            }
+        }
+    }
+}
+
 impl Adaptor<PlaceOrder> for Request {
     fn from_request(req: PlaceOrder) -> Result<Self, ExchangeError>
     where
         Self: Sized,
     {
+        if req.place.kind.is_conditional() {
+            return Err(ExchangeError::Unsupported(anyhow::anyhow!(
+                "conditional orders must be placed by the algo order API"
+            )));
+        }
         Ok(Self::order(&req))
     }
 
@@ -246,6 +369,245 @@ impl Adaptor<CancelOrder> for Request {
     }
 }
 
+/// The maximum number of orders in a batch request.
+const MAX_BATCH_ORDERS: usize = 20;
+
+fn batch_data(
+    code: String,
+    msg: String,
+    data: Vec<OrderData>,
+) -> Result<impl Iterator<Item = Result<OrderData, ExchangeError>>, OkxError> {
+    if code != "0" && data.is_empty() {
+        return Err(OkxError::Api(StatusKind::Other(anyhow::anyhow!(
+            "code={code} msg={msg}"
+        ))));
+    }
+    Ok(data.into_iter().map(|data| {
+        if data.s_code == "0" {
+            Ok(data)
+        } else {
+            Err(OkxError::Api(StatusKind::Other(anyhow::anyhow!(
+                "code={} msg={}",
+                data.s_code,
+                data.s_msg
+            )))
+            .into())
+        }
+    }))
+}
+
+impl Adaptor<PlaceOrders> for Request {
+    fn from_request(req: PlaceOrders) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        if req.0.len() > MAX_BATCH_ORDERS {
+            return Err(ExchangeError::Unsupported(anyhow::anyhow!(
+                "at most {MAX_BATCH_ORDERS} orders can be placed in a batch"
+            )));
+        }
+        if req.0.iter().any(|req| req.place.kind.is_conditional()) {
+            return Err(ExchangeError::Unsupported(anyhow::anyhow!(
+                "conditional orders must be placed by the algo order API"
+            )));
+        }
+        Ok(Self::batch_orders(&req.0))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<PlaceOrders as exc_core::Request>::Response, ExchangeError> {
+        let resp = resp.into_unary().map_err(OkxError::Api)?;
+
+        Ok(async move {
+            let event = resp.await?.inner;
+            if let Event::TradeResponse(TradeResponse::BatchOrders {
+                code, msg, data, ..
+            }) = event
+            {
+                let placed = batch_data(code, msg, data)?
+                    .map(|data| {
+                        let data = data?;
+                        #[cfg(not(feature = "prefer-client-id"))]
+                        let id = OrderId::from(data.ord_id);
+                        #[cfg(feature = "prefer-client-id")]
+                        let id = if data.cl_ord_id.is_empty() {
+                            return Err(OkxError::MissingClientId.into());
+                        } else {
+                            OrderId::from(data.cl_ord_id)
+                        };
+                        Ok(Placed {
+                            id,
+                            order: None,
+                            ts: OffsetDateTime::now_utc(),
+                        })
+                    })
+                    .collect();
+                Ok(placed)
+            } else {
+                Err(OkxError::UnexpectedDataType(anyhow::anyhow!("{event:?}")).into())
+            }
+        }
+        .boxed())
+    }
+}
+
+impl Adaptor<CancelOrders> for Request {
+    fn from_request(req: CancelOrders) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        if req.0.len() > MAX_BATCH_ORDERS {
+            return Err(ExchangeError::Unsupported(anyhow::anyhow!(
+                "at most {MAX_BATCH_ORDERS} orders can be cancelled in a batch"
+            )));
+        }
+        Ok(Self::batch_cancel_orders(&req.0))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<CancelOrders as exc_core::Request>::Response, ExchangeError> {
+        let resp = resp.into_unary().map_err(OkxError::Api)?;
+
+        Ok(async move {
+            let event = resp.await?.inner;
+            if let Event::TradeResponse(TradeResponse::BatchCancelOrders {
+                code, msg, data, ..
+            }) = event
+            {
+                let canceled = batch_data(code, msg, data)?
+                    .map(|data| {
+                        data?;
+                        Ok(Canceled {
+                            ts: OffsetDateTime::now_utc(),
+                            order: None,
+                        })
+                    })
+                    .collect();
+                Ok(canceled)
+            } else {
+                Err(OkxError::UnexpectedDataType(anyhow::anyhow!("{event:?}")).into())
+            }
+        }
+        .boxed())
+    }
+}
+
+impl Adaptor<CancelAllOrders> for Request {
+    fn from_request(req: CancelAllOrders) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        // Only option orders can be mass-cancelled, by instrument family (e.g. `BTC-USD`).
+        let family = req.instrument.as_ref().and_then(|inst| {
+            let parts = inst.split('-').collect::<Vec<_>>();
+            (parts.len() == 5).then(|| format!("{}-{}", parts[0], parts[1]))
+        });
+        let Some(family) = family else {
+            return Err(ExchangeError::Unsupported(anyhow::anyhow!(
+                "mass cancel is only supported for option instruments"
+            )));
+        };
+        Ok(Self::mass_cancel(&family))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<CancelAllOrders as exc_core::Request>::Response, ExchangeError> {
+        let resp = resp.into_unary().map_err(OkxError::Api)?;
+
+        Ok(async move {
+            let event = resp.await?.inner;
+            if let Event::TradeResponse(TradeResponse::MassCancel {
+                code, msg, data, ..
+            }) = event
+            {
+                if code == "0" && data.iter().all(|data| data.result) {
+                    Ok(())
+                } else {
+                    Err(
+                        OkxError::Api(StatusKind::Other(anyhow::anyhow!("code={code} msg={msg}")))
+                            .into(),
+                    )
+                }
+            } else {
+                Err(OkxError::UnexpectedDataType(anyhow::anyhow!("{event:?}")).into())
+            }
+        }
+        .boxed())
+    }
+}
+
+impl Adaptor<AmendOrder> for Request {
+    fn from_request(req: AmendOrder) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        if req.new_size.is_none() && req.new_price.is_none() {
+            return Err(ExchangeError::Other(anyhow::anyhow!(
+                "nothing to amend: both new size and new price are missing"
+            )));
+        }
+        Ok(Self::amend_order(
+            &req.instrument,
+            req.id.as_str(),
+            req.new_size,
+            req.new_price,
+        ))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<AmendOrder as exc_core::Request>::Response, ExchangeError> {
+        let resp = resp.into_unary().map_err(OkxError::Api)?;
+
+        Ok(async move {
+            let event = resp.await?.inner;
+            let id = if let Event::TradeResponse(TradeResponse::AmendOrder {
+                code,
+                msg,
+                mut data,
+                ..
+            }) = event
+            {
+                if code == "0" {
+                    if let Some(data) = data.pop() {
+                        #[cfg(not(feature = "prefer-client-id"))]
+                        {
+                            Ok(OrderId::from(data.ord_id))
+                        }
+                        #[cfg(feature = "prefer-client-id")]
+                        {
+                            Ok(OrderId::from(data.cl_ord_id))
+                        }
+                    } else {
+                        Err(OkxError::Api(StatusKind::EmptyResponse))
+                    }
+                } else if let Some(data) = data.pop() {
+                    Err(OkxError::Api(StatusKind::Other(anyhow::anyhow!(
+                        "code={} msg={}",
+                        data.s_code,
+                        data.s_msg
+                    ))))
+                } else {
+                    Err(OkxError::Api(StatusKind::Other(anyhow::anyhow!(
+                        "code={code} msg={msg}"
+                    ))))
+                }
+            } else {
+                Err(OkxError::UnexpectedDataType(anyhow::anyhow!("{event:?}")))
+            }?;
+            Ok(Amended {
+                id,
+                order: None,
+                ts: OffsetDateTime::now_utc(),
+            })
+        }
+        .boxed())
+    }
+}
+
 impl Adaptor<SubscribeTrades> for Request {
     fn from_request(req: SubscribeTrades) -> Result<Self, ExchangeError> {
         Ok(Self::subscribe_trades(&req.instrument))
@@ -313,3 +675,152 @@ impl Adaptor<SubscribeBidAsk> for Request {
         }
     }
 }
+
+impl Adaptor<SubscribeOrderBook> for Request {
+    fn from_request(req: SubscribeOrderBook) -> Result<Self, ExchangeError> {
+        Ok(Self::subscribe(Args::subscribe_order_book(
+            &req.instrument,
+            req.depth,
+        )))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SubscribeOrderBook as exc_core::Request>::Response, ExchangeError> {
+        match resp {
+            Response::Streaming(stream) => {
+                let mut book = OkxOrderBook::default();
+                let stream = stream
+                    .skip(1)
+                    .filter_map(|frame| {
+                        ready(match frame {
+                            Ok(frame) => frame.into_change().map(Ok),
+                            Err(err) => Some(Err(ExchangeError::from(err))),
+                        })
+                    })
+                    .map(move |change| change.and_then(|change| book.apply_change(change)))
+                    .map_ok(|books| iter(books.into_iter().map(Ok)))
+                    .try_flatten()
+                    .boxed();
+                Ok(stream)
+            }
+            Response::Error(status) => Err(OkxError::Api(status).into()),
+            Response::Reconnected => Err(ExchangeError::Other(anyhow::anyhow!(
+                "invalid response kind"
+            ))),
+        }
+    }
+}
+
+fn into_change_stream<T>(
+    resp: Response,
+) -> Result<BoxStream<'static, Result<T, ExchangeError>>, ExchangeError>
+where
+    T: Send + 'static,
+    Vec<Result<T, OkxError>>: TryFrom<Event, Error = OkxError>,
+{
+    match resp {
+        Response::Streaming(stream) => {
+            let stream = stream
+                .skip(1)
+                .flat_map(|frame| {
+                    let res: Result<Vec<Result<T, OkxError>>, OkxError> =
+                        frame.and_then(|f| f.inner.try_into());
+                    match res {
+                        Ok(items) => futures::stream::iter(items).left_stream(),
+                        Err(err) => futures::stream::once(async move { Err(err) }).right_stream(),
+                    }
+                })
+                .map_err(ExchangeError::from)
+                .boxed();
+            Ok(stream)
+        }
+        Response::Error(status) => Err(OkxError::Api(status).into()),
+        Response::Reconnected => Err(ExchangeError::Other(anyhow::anyhow!(
+            "invalid response kind"
+        ))),
+    }
+}
+
+impl Adaptor<SubscribeMarkPrice> for Request {
+    fn from_request(req: SubscribeMarkPrice) -> Result<Self, ExchangeError> {
+        Ok(Self::subscribe(Args::subscribe_mark_price(&req.instrument)))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SubscribeMarkPrice as exc_core::Request>::Response, ExchangeError> {
+        into_change_stream::<MarkPrice>(resp)
+    }
+}
+
+impl Adaptor<SubscribeIndexPrice> for Request {
+    fn from_request(req: SubscribeIndexPrice) -> Result<Self, ExchangeError> {
+        let index = index_of(&req.instrument);
+        Ok(Self::subscribe(Args::subscribe_index_tickers(&index)))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SubscribeIndexPrice as exc_core::Request>::Response, ExchangeError> {
+        into_change_stream::<IndexPrice>(resp)
+    }
+}
+
+impl Adaptor<SubscribeFundingRate> for Request {
+    fn from_request(req: SubscribeFundingRate) -> Result<Self, ExchangeError> {
+        Ok(Self::subscribe(Args::subscribe_funding_rate(
+            &req.instrument,
+        )))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SubscribeFundingRate as exc_core::Request>::Response, ExchangeError> {
+        into_change_stream::<FundingRate>(resp)
+    }
+}
+
+impl Adaptor<SubscribeOpenInterest> for Request {
+    fn from_request(req: SubscribeOpenInterest) -> Result<Self, ExchangeError> {
+        Ok(Self::subscribe(Args::subscribe_open_interest(
+            &req.instrument,
+        )))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SubscribeOpenInterest as exc_core::Request>::Response, ExchangeError> {
+        into_change_stream::<OpenInterest>(resp)
+    }
+}
+
+impl Adaptor<SubscribeLiquidations> for Request {
+    fn from_request(req: SubscribeLiquidations) -> Result<Self, ExchangeError> {
+        // The `liquidation-orders` channel is subscribed by instrument type.
+        let args = Args::subscribe_liquidation_orders(&inst_type(&req.instrument));
+        Ok(Self::subscribe(args).with_inst_filter(&req.instrument))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SubscribeLiquidations as exc_core::Request>::Response, ExchangeError> {
+        into_change_stream::<Liquidation>(resp)
+    }
+}
+
+impl Adaptor<SubscribeCandles> for Request {
+    fn from_request(req: SubscribeCandles) -> Result<Self, ExchangeError> {
+        // Note that the candle channels are served by the business endpoint.
+        let bar = period_to_bar(&req.period()).ok_or_else(|| {
+            ExchangeError::Other(anyhow::anyhow!("unsupported period: {}", req.period()))
+        })?;
+        Ok(Self::subscribe(Args::subscribe_candles(req.inst(), bar)))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SubscribeCandles as exc_core::Request>::Response, ExchangeError> {
+        into_change_stream::<CandleUpdate>(resp)
+    }
+}
@@ -12,6 +12,7 @@ const DEFAULT_PING_TIMEOUT: Duration = Duration::from_secs(15);
 pub struct Endpoint {
     pub(crate) testing: bool,
     pub(crate) aws: bool,
+    pub(crate) business: bool,
     pub(crate) request_timeout: Option<Duration>,
     pub(crate) connection_timeout: Option<Duration>,
     pub(crate) ping_timeout: Duration,
@@ -56,6 +57,12 @@ impl Endpoint {
         self
     }
 
+    /// Switch to business endpoint (required by channels like `candle1m`).
+    pub fn business(&mut self, enable: bool) -> &mut Self {
+        self.business = enable;
+        self
+    }
+
     /// Switch to private channel.
     pub fn private(&mut self, key: Key) -> &mut Self {
         self.login = Some(key);
@@ -87,6 +94,15 @@ impl Endpoint {
 
     /// Get current uri.
     pub fn uri(&self) -> Uri {
+        if self.business {
+            return match (self.testing, self.aws) {
+                (true, _) => {
+                    Uri::from_static("wss://wspap.okx.com:8443/ws/v5/business?brokerId=9999")
+                }
+                (false, true) => Uri::from_static("wss://wsaws.okx.com:8443/ws/v5/business"),
+                (false, false) => Uri::from_static("wss://ws.okx.com:8443/ws/v5/business"),
+            };
+        }
         match (self.login.is_some(), self.testing, self.aws) {
             (true, true, _) => {
                 Uri::from_static("wss://wspap.okx.com:8443/ws/v5/private?brokerId=9999")
@@ -106,6 +122,7 @@ impl Default for Endpoint {
     fn default() -> Self {
         Self {
             aws: false,
+            business: false,
             testing: false,
             request_timeout: None,
             connection_timeout: None,
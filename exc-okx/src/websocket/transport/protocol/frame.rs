@@ -26,7 +26,12 @@ fn client_message_to_tag(msg: &WsRequest) -> String {
     match msg {
         WsRequest::Subscribe(args) | WsRequest::Unsubscribe(args) => args.to_tag(),
         WsRequest::Login(_) => LOGIN_TAG.to_string(),
-        WsRequest::Order(id, _) | WsRequest::CancelOrder(id, _) => id.clone(),
+        WsRequest::Order(id, _)
+        | WsRequest::CancelOrder(id, _)
+        | WsRequest::AmendOrder(id, _)
+        | WsRequest::BatchOrders(id, _)
+        | WsRequest::BatchCancelOrders(id, _)
+        | WsRequest::MassCancel(id, _) => id.clone(),
     }
 }
 
@@ -48,9 +53,12 @@ fn server_message_to_tag(msg: &Event) -> Option<String> {
             },
         },
         Event::TradeResponse(resp) => match resp {
-            TradeResponse::Order { id, .. } | TradeResponse::CancelOrder { id, .. } => {
-                Some(id.clone())
-            }
+            TradeResponse::Order { id, .. }
+            | TradeResponse::CancelOrder { id, .. }
+            | TradeResponse::AmendOrder { id, .. }
+            | TradeResponse::BatchOrders { id, .. }
+            | TradeResponse::BatchCancelOrders { id, .. }
+            | TradeResponse::MassCancel { id, .. } => Some(id.clone()),
         },
     }
 }
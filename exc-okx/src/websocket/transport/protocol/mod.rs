@@ -213,6 +213,7 @@ impl Service<Request> for Protocol {
             self.reconnect = true;
             ready(Ok(Response::Reconnected)).boxed()
         } else {
+            let inst_filter = req.inst_filter.clone();
             let resp = self.inner.call(req.into_client_stream());
             async move {
                 let resp = resp.await?;
@@ -221,7 +222,17 @@ impl Service<Request> for Protocol {
                         let mut stream = Box::pin(stream.peekable());
                         if let Some(frame) = stream.as_mut().peek().await {
                             trace!("wait header; peeked {frame:?}");
-                            Response::Streaming(stream)
+                            match inst_filter {
+                                Some(inst) => Response::Streaming(
+                                    stream
+                                        .map_ok(move |mut frame| {
+                                            frame.retain_inst(&inst);
+                                            frame
+                                        })
+                                        .boxed(),
+                                ),
+                                None => Response::Streaming(stream),
+                            }
                         } else {
                             trace!("wait header; no header");
                             Response::Error(StatusKind::EmptyResponse)
@@ -24,6 +24,15 @@ impl ServerFrame {
         )
     }
 
+    /// Only keep the change data of the given instrument.
+    pub(crate) fn retain_inst(&mut self, inst: &str) {
+        if let Event::Change(change) = &mut self.inner {
+            change
+                .data
+                .retain(|data| data.get("instId").and_then(|v| v.as_str()) == Some(inst));
+        }
+    }
+
     /// Get change if it is.
     pub fn change(&self) -> Option<&Change> {
         match &self.inner {
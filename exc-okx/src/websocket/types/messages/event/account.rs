@@ -0,0 +1,168 @@
+use exc_core::{
+    types::{Balance, MarginMode, Position},
+    Asset, Str,
+};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, NoneAsEmptyString};
+use time::OffsetDateTime;
+
+/// Okx balance detail of a currency.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxBalanceDetail {
+    /// Currency.
+    pub ccy: Asset,
+    /// Equity.
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub eq: Option<Decimal>,
+    /// Cash balance.
+    pub cash_bal: Decimal,
+    /// Available balance.
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub avail_bal: Option<Decimal>,
+    /// Available equity.
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub avail_eq: Option<Decimal>,
+    /// Frozen balance.
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub frozen_bal: Option<Decimal>,
+    /// Margin frozen for open orders.
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub ord_frozen: Option<Decimal>,
+    /// Unrealized profit and loss.
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub upl: Option<Decimal>,
+    /// Update time.
+    #[serde(with = "crate::utils::timestamp_serde")]
+    #[serde(rename = "uTime")]
+    pub update_ts: OffsetDateTime,
+}
+
+/// Okx account balances.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxAccount {
+    /// Total equity in USD.
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub total_eq: Option<Decimal>,
+    /// Details.
+    pub details: Vec<OkxBalanceDetail>,
+    /// Update time.
+    #[serde(with = "crate::utils::timestamp_serde")]
+    #[serde(rename = "uTime")]
+    pub update_ts: OffsetDateTime,
+}
+
+impl From<OkxBalanceDetail> for Balance {
+    fn from(detail: OkxBalanceDetail) -> Self {
+        let frozen = detail.frozen_bal.unwrap_or_default();
+        let available = detail
+            .avail_bal
+            .or(detail.avail_eq)
+            .unwrap_or(detail.cash_bal - frozen);
+        Self {
+            ts: detail.update_ts,
+            asset: detail.ccy,
+            total: detail.cash_bal.normalize(),
+            available: available.normalize(),
+            frozen: frozen.normalize(),
+        }
+    }
+}
+
+/// Okx position.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxPosition {
+    /// Instrument id.
+    pub inst_id: Str,
+    /// Position side (`long`, `short` or `net`).
+    pub pos_side: Str,
+    /// Position size.
+    pub pos: Decimal,
+    /// Average open price.
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub avg_px: Option<Decimal>,
+    /// Mark price.
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub mark_px: Option<Decimal>,
+    /// Unrealized profit and loss.
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub upl: Option<Decimal>,
+    /// Estimated liquidation price.
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub liq_px: Option<Decimal>,
+    /// Margin mode.
+    pub mgn_mode: MarginMode,
+    /// Leverage.
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub lever: Option<Decimal>,
+    /// Update time.
+    #[serde(with = "crate::utils::timestamp_serde")]
+    #[serde(rename = "uTime")]
+    pub update_ts: OffsetDateTime,
+}
+
+impl From<OkxPosition> for Position {
+    fn from(position: OkxPosition) -> Self {
+        let size = if position.pos_side.as_str() == "short" {
+            -position.pos.abs()
+        } else {
+            position.pos
+        };
+        Self {
+            ts: position.update_ts,
+            instrument: position.inst_id,
+            size: size.normalize(),
+            entry_price: position.avg_px.unwrap_or_default().normalize(),
+            mark_price: position.mark_px.map(|p| p.normalize()),
+            unrealized_pnl: position.upl.unwrap_or_default().normalize(),
+            liquidation_price: position.liq_px.map(|p| p.normalize()),
+            margin_mode: position.mgn_mode,
+            leverage: position.lever.map(|l| l.normalize()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use exc_core::types::{Balance, MarginMode, Position};
+
+    use crate::http::types::response::{FullHttpResponse, ResponseData};
+
+    #[test]
+    fn test_account_balance_response() -> anyhow::Result<()> {
+        let resp: FullHttpResponse = serde_json::from_str(
+            r#"{"code":"0","msg":"","data":[{"adjEq":"","details":[{"availBal":"","availEq":"9.5","cashBal":"10","ccy":"USDT","eq":"10.2","frozenBal":"0.5","ordFrozen":"0.5","uTime":"1705474164160","upl":"0.2"}],"imr":"","totalEq":"10.2","uTime":"1705474164160"}]}"#,
+        )?;
+        let Some(ResponseData::Account(account)) = resp.data.into_iter().next() else {
+            anyhow::bail!("not an account response");
+        };
+        let balance = Balance::from(account.details.into_iter().next().unwrap());
+        assert_eq!(balance.asset.as_ref(), "USDT");
+        assert_eq!(balance.total.to_string(), "10");
+        assert_eq!(balance.available.to_string(), "9.5");
+        assert_eq!(balance.frozen.to_string(), "0.5");
+        Ok(())
+    }
+
+    #[test]
+    fn test_account_positions_response() -> anyhow::Result<()> {
+        let resp: FullHttpResponse = serde_json::from_str(
+            r#"{"code":"0","msg":"","data":[{"adl":"1","availPos":"1","avgPx":"2566.31","cTime":"1619507758793","ccy":"ETH","instId":"ETH-USD-210430","instType":"FUTURES","lever":"10","liqPx":"2352.8496681818233","markPx":"2353.849","mgnMode":"isolated","pos":"1","posCcy":"","posId":"307173036051017730","posSide":"short","uTime":"1619507761462","upl":"0.0000040656","uplRatio":"0.0001"}]}"#,
+        )?;
+        let Some(ResponseData::Position(position)) = resp.data.into_iter().next() else {
+            anyhow::bail!("not a position response");
+        };
+        let position = Position::from(*position);
+        assert_eq!(position.instrument.as_str(), "ETH-USD-210430");
+        assert_eq!(position.size.to_string(), "-1");
+        assert_eq!(position.margin_mode, MarginMode::Isolated);
+        assert_eq!(position.leverage.unwrap().to_string(), "10");
+        Ok(())
+    }
+}
@@ -1,10 +1,18 @@
-use exc_core::types::BidAsk;
+use exc_core::{
+    types::{BidAsk, LocalOrderBook, OrderBook},
+    ExchangeError,
+};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 
+use super::{Action, Change};
+
 type Depth = [Decimal; 4];
 
+/// Number of levels used to compute the checksum.
+const CHECKSUM_DEPTH: usize = 25;
+
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub(super) struct OkxBook {
@@ -17,13 +25,6 @@ pub(super) struct OkxBook {
     pub(super) seq_id: i64,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-#[serde(rename_all = "camelCase")]
-pub(super) enum Side {
-    Buy,
-    Sell,
-}
-
 impl From<OkxBook> for BidAsk {
     fn from(value: OkxBook) -> Self {
         let bid = value.bids.first().map(|depth| (depth[0], depth[1]));
@@ -35,3 +36,120 @@ impl From<OkxBook> for BidAsk {
         }
     }
 }
+
+/// Order book maintained from the `books` or `books5` channel.
+#[derive(Debug, Default)]
+pub(crate) struct OkxOrderBook {
+    book: LocalOrderBook,
+    seq_id: Option<i64>,
+}
+
+impl OkxOrderBook {
+    fn checksum(&self) -> i32 {
+        let mut bids = self.book.bids().take(CHECKSUM_DEPTH);
+        let mut asks = self.book.asks().take(CHECKSUM_DEPTH);
+        let mut fields = Vec::with_capacity(CHECKSUM_DEPTH * 4);
+        loop {
+            let (bid, ask) = (bids.next(), asks.next());
+            if bid.is_none() && ask.is_none() {
+                break;
+            }
+            for (price, size) in bid.into_iter().chain(ask) {
+                fields.push(price.to_string());
+                fields.push(size.to_string());
+            }
+        }
+        crc32fast::hash(fields.join(":").as_bytes()) as i32
+    }
+
+    fn apply(&mut self, action: Action, data: OkxBook) -> Result<OrderBook, ExchangeError> {
+        let levels = |levels: Vec<Depth>| levels.into_iter().map(|d| (d[0], d[1]));
+        // `books5` only pushes full snapshots, which come without `prevSeqId`.
+        if matches!(action, Action::Snapshot) || data.prev_seq_id.is_none() {
+            self.book.clear();
+        } else {
+            match (self.seq_id, data.prev_seq_id) {
+                (Some(last), Some(prev)) if last == prev => {}
+                (last, prev) => {
+                    return Err(ExchangeError::OutOfSync(anyhow::anyhow!(
+                        "books sequence gap: last={last:?} prev={prev:?}"
+                    )));
+                }
+            }
+        }
+        self.book.update_bids(levels(data.bids));
+        self.book.update_asks(levels(data.asks));
+        self.seq_id = Some(data.seq_id);
+        if let Some(checksum) = data.checksum {
+            let local = self.checksum();
+            if i64::from(local) != checksum {
+                return Err(ExchangeError::OutOfSync(anyhow::anyhow!(
+                    "books checksum mismatch: local={local} remote={checksum}"
+                )));
+            }
+        }
+        Ok(self.book.snapshot(data.ts, None))
+    }
+
+    /// Apply the change and return the updated books.
+    pub(crate) fn apply_change(&mut self, change: Change) -> Result<Vec<OrderBook>, ExchangeError> {
+        let action = change.action;
+        change
+            .deserialize_data::<OkxBook>()
+            .map(|data| {
+                let data = data.map_err(|err| ExchangeError::Other(err.into()))?;
+                self.apply(action, data)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use exc_core::ExchangeError;
+
+    use super::{Change, OkxOrderBook};
+
+    fn change(action: &str, data: &str) -> Change {
+        serde_json::from_str(&format!(
+            r#"{{"arg":{{"channel":"books","instId":"BTC-USDT"}},"action":"{action}","data":[{data}]}}"#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_books_checksum_and_sequence() -> anyhow::Result<()> {
+        let checksum = crc32fast::hash(b"3366.1:7:3366.8:9:3366:6:3368:8") as i32;
+        let mut book = OkxOrderBook::default();
+        let books = book.apply_change(change(
+            "snapshot",
+            &format!(
+                r#"{{"asks":[["3366.8","9","10","3"],["3368","8","3","4"]],"bids":[["3366.1","7","0","3"],["3366","6","3","4"]],"ts":"1597026383085","checksum":{checksum},"prevSeqId":-1,"seqId":123}}"#
+            ),
+        ))?;
+        assert_eq!(books[0].best_bid().unwrap().0.to_string(), "3366.1");
+        assert_eq!(books[0].best_ask().unwrap().0.to_string(), "3366.8");
+
+        let checksum = crc32fast::hash(b"3366:6:3366.8:9:3368:8") as i32;
+        let books = book.apply_change(change(
+            "update",
+            &format!(
+                r#"{{"asks":[],"bids":[["3366.1","0","0","0"]],"ts":"1597026383185","checksum":{checksum},"prevSeqId":123,"seqId":124}}"#
+            ),
+        ))?;
+        assert_eq!(books[0].bids.len(), 1);
+
+        let res = book.apply_change(change(
+            "update",
+            r#"{"asks":[],"bids":[],"ts":"1597026383285","checksum":0,"prevSeqId":124,"seqId":125}"#,
+        ));
+        assert!(matches!(res, Err(ExchangeError::OutOfSync(_))));
+
+        let res = book.apply_change(change(
+            "update",
+            r#"{"asks":[],"bids":[],"ts":"1597026383385","prevSeqId":200,"seqId":201}"#,
+        ));
+        assert!(matches!(res, Err(ExchangeError::OutOfSync(_))));
+        Ok(())
+    }
+}
@@ -0,0 +1,113 @@
+use exc_core::types::{FundingRate, IndexPrice, Liquidation, MarkPrice, OpenInterest};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, NoneAsEmptyString};
+use time::OffsetDateTime;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct OkxMarkPrice {
+    pub(super) inst_id: String,
+    pub(super) mark_px: Decimal,
+    #[serde(with = "crate::utils::timestamp_serde")]
+    pub(super) ts: OffsetDateTime,
+}
+
+impl From<OkxMarkPrice> for MarkPrice {
+    fn from(value: OkxMarkPrice) -> Self {
+        Self {
+            ts: value.ts,
+            price: value.mark_px.normalize(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct OkxIndexTicker {
+    pub(super) inst_id: String,
+    pub(super) idx_px: Decimal,
+    #[serde(with = "crate::utils::timestamp_serde")]
+    pub(super) ts: OffsetDateTime,
+}
+
+impl From<OkxIndexTicker> for IndexPrice {
+    fn from(value: OkxIndexTicker) -> Self {
+        Self {
+            ts: value.ts,
+            price: value.idx_px.normalize(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct OkxFundingRate {
+    pub(super) inst_id: String,
+    pub(super) funding_rate: Decimal,
+    #[serde(with = "crate::utils::timestamp_serde")]
+    pub(super) funding_time: OffsetDateTime,
+    #[serde(default, with = "crate::utils::timestamp_serde_option")]
+    pub(super) ts: Option<OffsetDateTime>,
+}
+
+impl From<OkxFundingRate> for FundingRate {
+    fn from(value: OkxFundingRate) -> Self {
+        Self {
+            ts: value.ts.unwrap_or_else(OffsetDateTime::now_utc),
+            rate: value.funding_rate.normalize(),
+            funding_time: value.funding_time,
+        }
+    }
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct OkxOpenInterest {
+    pub(super) inst_id: String,
+    pub(super) oi: Decimal,
+    #[serde(default)]
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub(super) oi_usd: Option<Decimal>,
+    #[serde(with = "crate::utils::timestamp_serde")]
+    pub(super) ts: OffsetDateTime,
+}
+
+impl From<OkxOpenInterest> for OpenInterest {
+    fn from(value: OkxOpenInterest) -> Self {
+        Self {
+            ts: value.ts,
+            size: value.oi.normalize(),
+            value: value.oi_usd.map(|v| v.normalize()),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct OkxLiquidationOrders {
+    pub(super) inst_id: String,
+    pub(super) details: Vec<OkxLiquidationDetail>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct OkxLiquidationDetail {
+    pub(super) side: String,
+    pub(super) bk_px: Decimal,
+    pub(super) sz: Decimal,
+    #[serde(with = "crate::utils::timestamp_serde")]
+    pub(super) ts: OffsetDateTime,
+}
+
+impl From<OkxLiquidationDetail> for Liquidation {
+    fn from(value: OkxLiquidationDetail) -> Self {
+        let size = value.sz.normalize();
+        Self {
+            ts: value.ts,
+            price: value.bk_px.normalize(),
+            size: if value.side == "sell" { -size } else { size },
+        }
+    }
+}
@@ -1,16 +1,35 @@
 use crate::error::OkxError;
 
-use self::{book::OkxBook, ticker::OkxTicker, trade::OkxTrade};
+use self::{
+    book::OkxBook,
+    derivative::{
+        OkxFundingRate, OkxIndexTicker, OkxLiquidationOrders, OkxMarkPrice, OkxOpenInterest,
+    },
+    ticker::OkxTicker,
+    trade::OkxTrade,
+};
+
+pub(crate) use self::book::OkxOrderBook;
 
 use super::Args;
-use exc_core::types::{ticker::Ticker, BidAsk, Trade};
+use crate::utils::timestamp::millis_to_ts;
+use exc_core::types::{
+    ticker::Ticker, BidAsk, Candle, CandleUpdate, FundingRate, IndexPrice, Liquidation, MarkPrice,
+    OpenInterest, Trade,
+};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::fmt;
 
-pub use self::{instrument::OkxInstrumentMeta, options::OkxOptionSummary};
+pub use self::{
+    account::{OkxAccount, OkxPosition},
+    instrument::OkxInstrumentMeta,
+    options::OkxOptionSummary,
+};
 
 mod book;
+mod derivative;
 mod instrument;
 mod ticker;
 mod trade;
@@ -18,6 +37,9 @@ mod trade;
 /// Order.
 pub mod order;
 
+/// Account.
+pub mod account;
+
 /// Options message.
 pub mod options;
 
@@ -52,6 +74,13 @@ pub struct OrderData {
     pub s_msg: String,
 }
 
+/// Okx mass cancel response data.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MassCancelData {
+    /// Result.
+    pub result: bool,
+}
+
 /// Okx websocket response type.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "event", rename_all = "lowercase")]
@@ -98,6 +127,50 @@ pub enum TradeResponse {
         /// Data.
         data: Vec<OrderData>,
     },
+    /// Amend order.
+    AmendOrder {
+        /// Id.
+        id: String,
+        /// Code.
+        code: String,
+        /// Msg.
+        msg: String,
+        /// Data.
+        data: Vec<OrderData>,
+    },
+    /// Batch orders.
+    BatchOrders {
+        /// Id.
+        id: String,
+        /// Code.
+        code: String,
+        /// Msg.
+        msg: String,
+        /// Data.
+        data: Vec<OrderData>,
+    },
+    /// Batch cancel orders.
+    BatchCancelOrders {
+        /// Id.
+        id: String,
+        /// Code.
+        code: String,
+        /// Msg.
+        msg: String,
+        /// Data.
+        data: Vec<OrderData>,
+    },
+    /// Mass cancel.
+    MassCancel {
+        /// Id.
+        id: String,
+        /// Code.
+        code: String,
+        /// Msg.
+        msg: String,
+        /// Data.
+        data: Vec<MassCancelData>,
+    },
 }
 
 /// Action kind.
@@ -218,3 +291,241 @@ impl TryFrom<Event> for Vec<Result<BidAsk, OkxError>> {
         }
     }
 }
+
+fn deserialize_change<T, U>(event: Event) -> Result<Vec<Result<U, OkxError>>, OkxError>
+where
+    T: for<'de> Deserialize<'de>,
+    U: From<T>,
+{
+    match event {
+        Event::Change(change) => Ok(change
+            .deserialize_data::<T>()
+            .map(|v| v.map(U::from).map_err(OkxError::from))
+            .collect()),
+        Event::Response(resp) => Err(OkxError::UnexpectedDataType(anyhow::anyhow!(
+            "response: {resp:?}"
+        ))),
+        Event::TradeResponse(resp) => Err(OkxError::UnexpectedDataType(anyhow::anyhow!(
+            "response: {resp:?}"
+        ))),
+    }
+}
+
+impl TryFrom<Event> for Vec<Result<MarkPrice, OkxError>> {
+    type Error = OkxError;
+
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        deserialize_change::<OkxMarkPrice, _>(event)
+    }
+}
+
+impl TryFrom<Event> for Vec<Result<IndexPrice, OkxError>> {
+    type Error = OkxError;
+
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        deserialize_change::<OkxIndexTicker, _>(event)
+    }
+}
+
+impl TryFrom<Event> for Vec<Result<FundingRate, OkxError>> {
+    type Error = OkxError;
+
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        deserialize_change::<OkxFundingRate, _>(event)
+    }
+}
+
+impl TryFrom<Event> for Vec<Result<CandleUpdate, OkxError>> {
+    type Error = OkxError;
+
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::Change(change) => Ok(change
+                .deserialize_data::<crate::http::types::response::Candle>()
+                .map(|c| {
+                    let c = c?;
+                    let ts = millis_to_ts(c.0).ok_or_else(|| {
+                        OkxError::UnexpectedDataType(anyhow::anyhow!("cannot parse ts: {}", c.0))
+                    })?;
+                    Ok(CandleUpdate {
+                        candle: Candle {
+                            ts,
+                            open: c.1.normalize(),
+                            high: c.2.normalize(),
+                            low: c.3.normalize(),
+                            close: c.4.normalize(),
+                            volume: c.5.normalize(),
+                        },
+                        is_closed: c.8 == Decimal::ONE,
+                    })
+                })
+                .collect()),
+            Event::Response(resp) => Err(OkxError::UnexpectedDataType(anyhow::anyhow!(
+                "response: {resp:?}"
+            ))),
+            Event::TradeResponse(resp) => Err(OkxError::UnexpectedDataType(anyhow::anyhow!(
+                "response: {resp:?}"
+            ))),
+        }
+    }
+}
+
+impl TryFrom<Event> for Vec<Result<OpenInterest, OkxError>> {
+    type Error = OkxError;
+
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        deserialize_change::<OkxOpenInterest, _>(event)
+    }
+}
+
+impl TryFrom<Event> for Vec<Result<Liquidation, OkxError>> {
+    type Error = OkxError;
+
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::Change(change) => Ok(change
+                .deserialize_data::<OkxLiquidationOrders>()
+                .flat_map(|orders| match orders {
+                    Ok(orders) => orders
+                        .details
+                        .into_iter()
+                        .map(|detail| Ok(Liquidation::from(detail)))
+                        .collect::<Vec<_>>(),
+                    Err(err) => vec![Err(OkxError::from(err))],
+                })
+                .collect()),
+            Event::Response(resp) => Err(OkxError::UnexpectedDataType(anyhow::anyhow!(
+                "response: {resp:?}"
+            ))),
+            Event::TradeResponse(resp) => Err(OkxError::UnexpectedDataType(anyhow::anyhow!(
+                "response: {resp:?}"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_trade_responses() -> anyhow::Result<()> {
+        let batch = r#"{
+            "id": "1512",
+            "op": "batch-orders",
+            "data": [
+                {"clOrdId": "", "ordId": "12345689", "tag": "", "sCode": "0", "sMsg": ""},
+                {"clOrdId": "", "ordId": "", "tag": "", "sCode": "51008", "sMsg": "Insufficient balance"}
+            ],
+            "code": "2",
+            "msg": ""
+        }"#;
+        let Event::TradeResponse(TradeResponse::BatchOrders { id, data, .. }) =
+            serde_json::from_str::<Event>(batch)?
+        else {
+            anyhow::bail!("expecting batch orders response");
+        };
+        assert_eq!(id, "1512");
+        assert_eq!(data.len(), 2);
+        assert_eq!(data[1].s_code, "51008");
+
+        let mass_cancel = r#"{
+            "id": "1513",
+            "op": "mass-cancel",
+            "data": [{"result": true}],
+            "code": "0",
+            "msg": ""
+        }"#;
+        let Event::TradeResponse(TradeResponse::MassCancel { data, .. }) =
+            serde_json::from_str::<Event>(mass_cancel)?
+        else {
+            anyhow::bail!("expecting mass cancel response");
+        };
+        assert!(data[0].result);
+        Ok(())
+    }
+
+    #[test]
+    fn test_funding_rate_change() -> anyhow::Result<()> {
+        let change = r#"{
+            "arg": {"channel": "funding-rate", "instId": "BTC-USD-SWAP"},
+            "data": [{
+                "fundingRate": "0.0001875391284828",
+                "fundingTime": "1700726400000",
+                "instId": "BTC-USD-SWAP",
+                "instType": "SWAP",
+                "method": "next_period",
+                "nextFundingRate": "",
+                "nextFundingTime": "1700755200000",
+                "ts": "1700724675402"
+            }]
+        }"#;
+        let rates: Vec<Result<FundingRate, OkxError>> =
+            serde_json::from_str::<Event>(change)?.try_into()?;
+        let rate = rates.into_iter().next().unwrap()?;
+        assert_eq!(rate.rate.to_string(), "0.0001875391284828");
+        assert_eq!(rate.funding_time.unix_timestamp(), 1700726400);
+        Ok(())
+    }
+
+    #[test]
+    fn test_liquidation_change() -> anyhow::Result<()> {
+        let change = r#"{
+            "arg": {"channel": "liquidation-orders", "instType": "SWAP"},
+            "data": [{
+                "details": [{
+                    "bkLoss": "0",
+                    "bkPx": "0.007831",
+                    "ccy": "",
+                    "posSide": "short",
+                    "side": "buy",
+                    "sz": "13",
+                    "ts": "1692266434010"
+                }, {
+                    "bkLoss": "0",
+                    "bkPx": "0.007829",
+                    "ccy": "",
+                    "posSide": "long",
+                    "side": "sell",
+                    "sz": "2",
+                    "ts": "1692266434011"
+                }],
+                "instFamily": "IOST-USDT",
+                "instId": "IOST-USDT-SWAP",
+                "instType": "SWAP",
+                "uly": "IOST-USDT"
+            }]
+        }"#;
+        let liquidations: Vec<Result<Liquidation, OkxError>> =
+            serde_json::from_str::<Event>(change)?.try_into()?;
+        let liquidations = liquidations.into_iter().collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(liquidations.len(), 2);
+        assert_eq!(liquidations[0].size.to_string(), "13");
+        assert_eq!(liquidations[1].size.to_string(), "-2");
+        Ok(())
+    }
+
+    #[test]
+    fn test_candle_change() -> anyhow::Result<()> {
+        let change = r#"{
+            "arg": {"channel": "candle1D", "instId": "BTC-USDT"},
+            "data": [[
+                "1597026383085",
+                "8533.02",
+                "8553.74",
+                "8527.17",
+                "8548.26",
+                "45247",
+                "529.5858061",
+                "5529.5858061",
+                "1"
+            ]]
+        }"#;
+        let candles: Vec<Result<CandleUpdate, OkxError>> =
+            serde_json::from_str::<Event>(change)?.try_into()?;
+        let candle = candles.into_iter().next().unwrap()?;
+        assert!(candle.is_closed);
+        assert_eq!(candle.candle.close.to_string(), "8548.26");
+        Ok(())
+    }
+}
@@ -10,6 +10,7 @@ use serde_with::{serde_as, DisplayFromStr, NoneAsEmptyString};
 use time::OffsetDateTime;
 
 use crate::error::OkxError;
+use crate::utils::pos_side::parse_pos_side;
 
 /// Okx Order State.
 #[derive(Serialize, Deserialize, Debug)]
@@ -287,6 +288,11 @@ impl TryFrom<OkxOrder> for OrderUpdate {
                     fees,
                 },
                 trade,
+                position_side: order
+                    .pos_side
+                    .as_deref()
+                    .map(parse_pos_side)
+                    .unwrap_or_default(),
             },
         })
     }
@@ -1,9 +1,11 @@
 use super::Args;
 use crate::error::OkxError;
 use crate::key::{OkxKey as Key, Signature};
+use crate::utils::pos_side::format_pos_side;
 use exc_core::types::trading::{OrderKind, Place, PlaceOrderOptions};
 use exc_core::types::TimeInForce;
 use exc_core::Str;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::fmt;
@@ -23,6 +25,14 @@ pub enum Op {
     Order,
     /// Cancel order.
     CancelOrder,
+    /// Amend order.
+    AmendOrder,
+    /// Batch orders.
+    BatchOrders,
+    /// Batch cancel orders.
+    BatchCancelOrders,
+    /// Mass cancel.
+    MassCancel,
 }
 
 /// Okx websocket request messagee.
@@ -59,6 +69,14 @@ pub enum WsRequest {
     Order(String, Args),
     /// Cancel order.
     CancelOrder(String, Args),
+    /// Amend order.
+    AmendOrder(String, Args),
+    /// Batch orders.
+    BatchOrders(String, Vec<Args>),
+    /// Batch cancel orders.
+    BatchCancelOrders(String, Vec<Args>),
+    /// Mass cancel.
+    MassCancel(String, Args),
 }
 
 impl fmt::Display for WsRequest {
@@ -79,6 +97,18 @@ impl fmt::Display for WsRequest {
             Self::CancelOrder(id, args) => {
                 write!(f, "cancel-order:{id}:{args}")
             }
+            Self::AmendOrder(id, args) => {
+                write!(f, "amend-order:{id}:{args}")
+            }
+            Self::BatchOrders(id, args) => {
+                write!(f, "batch-orders:{id}:{}", args.len())
+            }
+            Self::BatchCancelOrders(id, args) => {
+                write!(f, "batch-cancel-orders:{id}:{}", args.len())
+            }
+            Self::MassCancel(id, args) => {
+                write!(f, "mass-cancel:{id}:{args}")
+            }
         }
     }
 }
@@ -96,6 +126,26 @@ impl WsRequest {
 
     /// Order request.
     pub(crate) fn order(place: &Place, opts: &PlaceOrderOptions) -> Self {
+        Self::Order(
+            format!("{:x}", uuid::Uuid::new_v4().as_u128()),
+            Self::order_args(place, opts),
+        )
+    }
+
+    /// Batch orders request.
+    pub(crate) fn batch_orders<'a>(
+        orders: impl IntoIterator<Item = (&'a Place, &'a PlaceOrderOptions)>,
+    ) -> Self {
+        Self::BatchOrders(
+            format!("{:x}", uuid::Uuid::new_v4().as_u128()),
+            orders
+                .into_iter()
+                .map(|(place, opts)| Self::order_args(place, opts))
+                .collect(),
+        )
+    }
+
+    fn order_args(place: &Place, opts: &PlaceOrderOptions) -> Args {
         let inst = opts.instrument();
         let custom = opts.custom();
         let size = place.size.abs();
@@ -111,12 +161,18 @@ impl WsRequest {
                 Str::new(custom.get("tdMode").map(|s| s.as_str()).unwrap_or("cross")),
             ),
             (Str::new_inline("side"), Str::new_inline(side)),
-            (Str::new_inline("posSide"), Str::new_inline("net")),
+            (
+                Str::new_inline("posSide"),
+                Str::new_inline(format_pos_side(opts.position_side())),
+            ),
             (Str::new_inline("sz"), Str::new(size.to_string())),
         ]);
         if let Some(margin) = opts.margin() {
             map.insert(Str::new_inline("ccy"), Str::new(margin));
         }
+        if opts.reduce_only() {
+            map.insert(Str::new_inline("reduceOnly"), Str::new_inline("true"));
+        }
         #[cfg(not(feature = "prefer-client-id"))]
         if let Some(client_id) = opts.client_id() {
             #[cfg(debug_assertions)]
@@ -159,23 +215,84 @@ impl WsRequest {
                 map.insert(Str::new_inline("px"), Str::new(price.to_string()));
                 map.insert(Str::new_inline("ordType"), Str::new_inline("post_only"));
             }
+            OrderKind::StopMarket(_)
+            | OrderKind::StopLimit(_, _)
+            | OrderKind::TakeProfit(_)
+            | OrderKind::TrailingStop(_, _) => {
+                // Conditional orders can only be placed by the algo order API.
+                tracing::error!(
+                    ?place,
+                    "conditional orders are not supported by the websocket API"
+                );
+            }
         }
-        Self::Order(format!("{:x}", uuid::Uuid::new_v4().as_u128()), Args(map))
+        Args(map)
     }
 
     /// Cancel order request.
     pub(crate) fn cancel_order(inst: &str, id: &str) -> Self {
         Self::CancelOrder(
+            format!("{:x}", uuid::Uuid::new_v4().as_u128()),
+            Self::cancel_order_args(inst, id),
+        )
+    }
+
+    /// Batch cancel orders request.
+    pub(crate) fn batch_cancel_orders<'a>(
+        orders: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> Self {
+        Self::BatchCancelOrders(
+            format!("{:x}", uuid::Uuid::new_v4().as_u128()),
+            orders
+                .into_iter()
+                .map(|(inst, id)| Self::cancel_order_args(inst, id))
+                .collect(),
+        )
+    }
+
+    /// Mass cancel request (option only).
+    pub(crate) fn mass_cancel(inst_family: &str) -> Self {
+        Self::MassCancel(
             format!("{:x}", uuid::Uuid::new_v4().as_u128()),
             Args(BTreeMap::from([
-                (Str::new_inline("instId"), Str::new(inst)),
-                #[cfg(not(feature = "prefer-client-id"))]
-                (Str::new_inline("ordId"), Str::new(id)),
-                #[cfg(feature = "prefer-client-id")]
-                (Str::new_inline("clOrdId"), Str::new(id)),
+                (Str::new_inline("instType"), Str::new_inline("OPTION")),
+                (Str::new_inline("instFamily"), Str::new(inst_family)),
             ])),
         )
     }
+
+    fn cancel_order_args(inst: &str, id: &str) -> Args {
+        Args(BTreeMap::from([
+            (Str::new_inline("instId"), Str::new(inst)),
+            #[cfg(not(feature = "prefer-client-id"))]
+            (Str::new_inline("ordId"), Str::new(id)),
+            #[cfg(feature = "prefer-client-id")]
+            (Str::new_inline("clOrdId"), Str::new(id)),
+        ]))
+    }
+
+    /// Amend order request.
+    pub(crate) fn amend_order(
+        inst: &str,
+        id: &str,
+        new_size: Option<Decimal>,
+        new_price: Option<Decimal>,
+    ) -> Self {
+        let mut map = BTreeMap::from([
+            (Str::new_inline("instId"), Str::new(inst)),
+            #[cfg(not(feature = "prefer-client-id"))]
+            (Str::new_inline("ordId"), Str::new(id)),
+            #[cfg(feature = "prefer-client-id")]
+            (Str::new_inline("clOrdId"), Str::new(id)),
+        ]);
+        if let Some(size) = new_size {
+            map.insert(Str::new_inline("newSz"), Str::new(size.abs().to_string()));
+        }
+        if let Some(price) = new_price {
+            map.insert(Str::new_inline("newPx"), Str::new(price.to_string()));
+        }
+        Self::AmendOrder(format!("{:x}", uuid::Uuid::new_v4().as_u128()), Args(map))
+    }
 }
 
 impl From<WsRequest> for WsRequestMessage {
@@ -206,6 +323,26 @@ impl From<WsRequest> for WsRequestMessage {
                 op: Op::CancelOrder,
                 args: vec![args],
             },
+            WsRequest::AmendOrder(id, args) => Self {
+                id: Some(id),
+                op: Op::AmendOrder,
+                args: vec![args],
+            },
+            WsRequest::BatchOrders(id, args) => Self {
+                id: Some(id),
+                op: Op::BatchOrders,
+                args,
+            },
+            WsRequest::BatchCancelOrders(id, args) => Self {
+                id: Some(id),
+                op: Op::BatchCancelOrders,
+                args,
+            },
+            WsRequest::MassCancel(id, args) => Self {
+                id: Some(id),
+                op: Op::MassCancel,
+                args: vec![args],
+            },
         }
     }
 }
@@ -5,16 +5,18 @@ use crate::{
 };
 use async_stream::stream;
 use exc_core::{
-    types::{ticker::SubscribeTickers, PlaceOrder},
+    types::{ticker::SubscribeTickers, CancelOrder, PlaceOrder},
     ExchangeError,
 };
 use futures::stream::{empty, BoxStream, StreamExt};
+use rust_decimal::Decimal;
 
 /// Okx websocket api request.
 pub struct Request {
     cb: Callback,
     inner: BoxStream<'static, ClientFrame>,
     pub(crate) reconnect: bool,
+    pub(crate) inst_filter: Option<String>,
 }
 
 impl Request {
@@ -59,9 +61,19 @@ impl Request {
             cb,
             inner: stream.boxed(),
             reconnect: false,
+            inst_filter: None,
         }
     }
 
+    /// Only keep the data of the given instrument in the response stream.
+    ///
+    /// Useful for the channels that can not be subscribed by instrument,
+    /// e.g. `liquidation-orders`.
+    pub fn with_inst_filter(mut self, inst: &str) -> Self {
+        self.inst_filter = Some(inst.to_string());
+        self
+    }
+
     /// Login request.
     pub(crate) fn login(key: Key) -> Result<Self, SignError> {
         let (cb, _rx) = Callback::new();
@@ -75,6 +87,7 @@ impl Request {
             cb,
             inner: stream.boxed(),
             reconnect: false,
+            inst_filter: None,
         })
     }
 
@@ -92,6 +105,63 @@ impl Request {
             cb,
             inner: stream.boxed(),
             reconnect: false,
+            inst_filter: None,
+        }
+    }
+
+    /// Batch orders request.
+    pub fn batch_orders(reqs: &[PlaceOrder]) -> Self {
+        let (cb, _rx) = Callback::new();
+        let reqs = reqs.to_vec();
+        let stream = stream! {
+            yield ClientFrame {
+                stream_id: 0,
+                inner: WsRequest::batch_orders(reqs.iter().map(|req| (&req.place, &*req.opts))),
+            };
+        };
+
+        Self {
+            cb,
+            inner: stream.boxed(),
+            reconnect: false,
+            inst_filter: None,
+        }
+    }
+
+    /// Batch cancel orders request.
+    pub fn batch_cancel_orders(reqs: &[CancelOrder]) -> Self {
+        let (cb, _rx) = Callback::new();
+        let reqs = reqs.to_vec();
+        let stream = stream! {
+            yield ClientFrame {
+                stream_id: 0,
+                inner: WsRequest::batch_cancel_orders(
+                    reqs.iter().map(|req| (req.instrument.as_str(), req.id.as_str())),
+                ),
+            };
+        };
+
+        Self {
+            cb,
+            inner: stream.boxed(),
+            reconnect: false,
+            inst_filter: None,
+        }
+    }
+
+    /// Mass cancel request (option only).
+    pub fn mass_cancel(inst_family: &str) -> Self {
+        let (cb, _rx) = Callback::new();
+        let inst_family = inst_family.to_string();
+        let stream = stream! {
+            yield ClientFrame { stream_id: 0, inner: WsRequest::mass_cancel(&inst_family) };
+        };
+
+        Self {
+            cb,
+            inner: stream.boxed(),
+            reconnect: false,
+            inst_filter: None,
         }
     }
 
@@ -109,6 +179,32 @@ impl Request {
             cb,
             inner: stream.boxed(),
             reconnect: false,
+            inst_filter: None,
+        }
+    }
+
+    /// Amend order request.
+    pub fn amend_order(
+        inst: &str,
+        id: &str,
+        new_size: Option<Decimal>,
+        new_price: Option<Decimal>,
+    ) -> Self {
+        let (cb, _rx) = Callback::new();
+        let inst = inst.to_string();
+        let id = id.to_string();
+        let stream = stream! {
+            yield ClientFrame {
+                stream_id: 0,
+                inner: WsRequest::amend_order(&inst, &id, new_size, new_price),
+            };
+        };
+
+        Self {
+            cb,
+            inner: stream.boxed(),
+            reconnect: false,
+            inst_filter: None,
         }
     }
 
@@ -119,6 +215,7 @@ impl Request {
             cb,
             inner: empty().boxed(),
             reconnect: true,
+            inst_filter: None,
         }
     }
 }
@@ -0,0 +1,329 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+
+use futures::ready;
+use pin_project_lite::pin_project;
+use tower::{Layer, Service};
+
+use crate::ExchangeError;
+
+const DEFAULT_PROBES: usize = 1;
+
+/// The state of a circuit breaker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Requests are passed through.
+    Closed,
+    /// Requests fail fast until the cooldown has elapsed.
+    Open,
+    /// A limited number of probe requests are passed through
+    /// to decide whether to close or to reopen the circuit.
+    HalfOpen,
+}
+
+#[derive(Debug)]
+enum Phase {
+    Closed { failures: usize },
+    Open { until: Instant },
+    HalfOpen { probing: usize },
+}
+
+#[derive(Debug)]
+struct Breaker {
+    phase: Phase,
+    threshold: usize,
+    cooldown: Duration,
+    probes: usize,
+}
+
+enum Permit {
+    Pass,
+    Probe,
+}
+
+impl Breaker {
+    fn state(&mut self) -> CircuitState {
+        if let Phase::Open { until } = self.phase {
+            if Instant::now() >= until {
+                self.phase = Phase::HalfOpen { probing: 0 };
+            }
+        }
+        match self.phase {
+            Phase::Closed { .. } => CircuitState::Closed,
+            Phase::Open { .. } => CircuitState::Open,
+            Phase::HalfOpen { .. } => CircuitState::HalfOpen,
+        }
+    }
+
+    fn acquire(&mut self) -> Option<Permit> {
+        self.state();
+        match &mut self.phase {
+            Phase::Closed { .. } => Some(Permit::Pass),
+            Phase::Open { .. } => None,
+            Phase::HalfOpen { probing } => {
+                if *probing < self.probes {
+                    *probing += 1;
+                    Some(Permit::Probe)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn open(&mut self) {
+        self.phase = Phase::Open {
+            until: Instant::now() + self.cooldown,
+        };
+    }
+
+    fn record(&mut self, permit: &Permit, failed: bool) {
+        match (&mut self.phase, permit) {
+            (Phase::Closed { failures }, _) => {
+                if failed {
+                    *failures += 1;
+                    if *failures >= self.threshold {
+                        self.open();
+                    }
+                } else {
+                    *failures = 0;
+                }
+            }
+            (Phase::HalfOpen { .. }, Permit::Probe) => {
+                if failed {
+                    self.open();
+                } else {
+                    self.phase = Phase::Closed { failures: 0 };
+                }
+            }
+            // The results of the requests sent before the circuit was opened are ignored.
+            _ => {}
+        }
+    }
+
+    fn release(&mut self) {
+        if let Phase::HalfOpen { probing } = &mut self.phase {
+            *probing = probing.saturating_sub(1);
+        }
+    }
+}
+
+/// Release the probe slot if the request is dropped before it finishes.
+struct Guard {
+    breaker: Arc<Mutex<Breaker>>,
+    permit: Option<Permit>,
+}
+
+impl Guard {
+    fn finish(mut self, failed: bool) {
+        if let Some(permit) = self.permit.take() {
+            self.breaker.lock().unwrap().record(&permit, failed);
+        }
+    }
+}
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        if let Some(Permit::Probe) = self.permit.take() {
+            self.breaker.lock().unwrap().release();
+        }
+    }
+}
+
+/// Circuit breaker layer.
+///
+/// The services created by the same layer share the same circuit.
+#[derive(Debug, Clone)]
+pub struct CircuitBreakerLayer<F = fn(&ExchangeError) -> bool> {
+    breaker: Arc<Mutex<Breaker>>,
+    classify: F,
+}
+
+impl CircuitBreakerLayer {
+    /// Create a circuit breaker that opens after `threshold` consecutive
+    /// temporary errors and stays open for `cooldown`.
+    pub fn new(threshold: usize, cooldown: Duration) -> Self {
+        Self {
+            breaker: Arc::new(Mutex::new(Breaker {
+                phase: Phase::Closed { failures: 0 },
+                threshold: threshold.max(1),
+                cooldown,
+                probes: DEFAULT_PROBES,
+            })),
+            classify: ExchangeError::is_temporary,
+        }
+    }
+}
+
+impl<F> CircuitBreakerLayer<F> {
+    /// Set the number of probe requests allowed when half-open.
+    pub fn probes(self, probes: usize) -> Self {
+        self.breaker.lock().unwrap().probes = probes.max(1);
+        self
+    }
+
+    /// Count the errors on which `f` returns `true` as failures.
+    pub fn failure_on<F2>(self, f: F2) -> CircuitBreakerLayer<F2>
+    where
+        F2: Fn(&ExchangeError) -> bool,
+    {
+        CircuitBreakerLayer {
+            breaker: self.breaker,
+            classify: f,
+        }
+    }
+
+    /// Get the state of the circuit.
+    pub fn state(&self) -> CircuitState {
+        self.breaker.lock().unwrap().state()
+    }
+}
+
+impl<S, F: Clone> Layer<S> for CircuitBreakerLayer<F> {
+    type Service = CircuitBreaker<S, F>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        CircuitBreaker {
+            inner,
+            breaker: self.breaker.clone(),
+            classify: self.classify.clone(),
+            permit: None,
+        }
+    }
+}
+
+/// Circuit breaker service.
+pub struct CircuitBreaker<S, F = fn(&ExchangeError) -> bool> {
+    inner: S,
+    breaker: Arc<Mutex<Breaker>>,
+    classify: F,
+    permit: Option<Guard>,
+}
+
+impl<S: Clone, F: Clone> Clone for CircuitBreaker<S, F> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            breaker: self.breaker.clone(),
+            classify: self.classify.clone(),
+            permit: None,
+        }
+    }
+}
+
+impl<S, F> CircuitBreaker<S, F> {
+    /// Get the state of the circuit.
+    pub fn state(&self) -> CircuitState {
+        self.breaker.lock().unwrap().state()
+    }
+}
+
+impl<S, F, R> Service<R> for CircuitBreaker<S, F>
+where
+    S: Service<R, Error = ExchangeError>,
+    F: Fn(&ExchangeError) -> bool + Clone,
+{
+    type Response = S::Response;
+    type Error = ExchangeError;
+    type Future = ResponseFuture<S::Future, F>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        if self.permit.is_none() {
+            let Some(permit) = self.breaker.lock().unwrap().acquire() else {
+                // Fail fast in `call` without waiting for the inner service.
+                return Poll::Ready(Ok(()));
+            };
+            self.permit = Some(Guard {
+                breaker: self.breaker.clone(),
+                permit: Some(permit),
+            });
+        }
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: R) -> Self::Future {
+        let guard = self.permit.take();
+        ResponseFuture {
+            fut: guard.as_ref().map(|_| self.inner.call(req)),
+            guard,
+            classify: self.classify.clone(),
+        }
+    }
+}
+
+pin_project! {
+    /// Response future of [`CircuitBreaker`].
+    pub struct ResponseFuture<Fut, F> {
+        #[pin]
+        fut: Option<Fut>,
+        guard: Option<Guard>,
+        classify: F,
+    }
+}
+
+impl<Fut, F, T> Future for ResponseFuture<Fut, F>
+where
+    Fut: Future<Output = Result<T, ExchangeError>>,
+    F: Fn(&ExchangeError) -> bool,
+{
+    type Output = Result<T, ExchangeError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let Some(fut) = this.fut.as_pin_mut() else {
+            return Poll::Ready(Err(ExchangeError::Unavailable(anyhow::anyhow!(
+                "circuit breaker is open"
+            ))));
+        };
+        let res = ready!(fut.poll(cx));
+        if let Some(guard) = this.guard.take() {
+            guard.finish(res.as_ref().err().is_some_and(this.classify));
+        }
+        Poll::Ready(res)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use futures::executor::block_on;
+    use tower::{service_fn, ServiceExt};
+
+    use super::*;
+
+    #[test]
+    fn test_circuit_breaker() {
+        let layer = CircuitBreakerLayer::new(2, Duration::ZERO);
+        let mut svc = layer.layer(service_fn(|fail: bool| async move {
+            if fail {
+                Err(ExchangeError::Unavailable(anyhow::anyhow!("down")))
+            } else {
+                Ok(())
+            }
+        }));
+        block_on(async {
+            assert!(svc.ready().await.unwrap().call(true).await.is_err());
+            assert_eq!(layer.state(), CircuitState::Closed);
+            assert!(svc.ready().await.unwrap().call(true).await.is_err());
+            // The cooldown is zero, so it is half-open immediately.
+            assert_eq!(layer.state(), CircuitState::HalfOpen);
+            assert!(svc.ready().await.unwrap().call(false).await.is_ok());
+            assert_eq!(layer.state(), CircuitState::Closed);
+        });
+
+        let layer = CircuitBreakerLayer::new(1, Duration::from_secs(60));
+        let mut svc = layer.layer(service_fn(|_: bool| async move {
+            Err::<(), _>(ExchangeError::RateLimited(anyhow::anyhow!("429")))
+        }));
+        block_on(async {
+            assert!(svc.ready().await.unwrap().call(false).await.is_err());
+            assert_eq!(layer.state(), CircuitState::Open);
+            let err = svc.ready().await.unwrap().call(false).await.unwrap_err();
+            assert!(matches!(err, ExchangeError::Unavailable(_)));
+        });
+    }
+}
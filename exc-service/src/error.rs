@@ -47,6 +47,12 @@ pub enum ExchangeError {
     /// Unexpected response type.
     #[error("unexpected response type: {0}")]
     UnexpectedResponseType(String),
+    /// The request is not supported by the exchange.
+    #[error("unsupported: {0}")]
+    Unsupported(anyhow::Error),
+    /// The stream is out of sync and should be resubscribed.
+    #[error("out of sync: {0}")]
+    OutOfSync(anyhow::Error),
 }
 
 impl ExchangeError {
@@ -65,6 +71,19 @@ impl ExchangeError {
         }
     }
 
+    /// Whether the request is known to have been rejected before it was submitted,
+    /// so that retrying it cannot duplicate its effect.
+    pub fn is_pre_submission(&self) -> bool {
+        matches!(
+            self,
+            Self::RateLimited(_)
+                | Self::KeyError(_)
+                | Self::Forbidden(_)
+                | Self::Instrument(_)
+                | Self::Unsupported(_)
+        )
+    }
+
     /// Flatten.
     pub fn flatten(self) -> Self {
         match self {
@@ -19,10 +19,17 @@ pub mod traits;
 /// The adapt layer.
 pub mod adapt;
 
+/// Circuit breaker.
+pub mod circuit;
+
 #[cfg(feature = "retry")]
 /// Retry utils.
 pub mod retry;
 
+#[cfg(feature = "limit")]
+/// Weight-based rate limit.
+pub mod weight;
+
 pub use layer::ExcLayer;
 pub use {
     adapt::Adaptor,
@@ -102,6 +109,22 @@ where
         self.into_layered(&RateLimitLayer::new(num, per))
     }
 
+    /// Apply weight-limit layer to the channel.
+    #[cfg(feature = "limit")]
+    pub fn into_weight_limited(
+        self,
+        limits: crate::weight::WeightLimits,
+    ) -> Exc<crate::weight::WeightLimit<IntoService<C, Req>>, Req>
+    where
+        Req: crate::weight::Weighted + Send + 'static,
+        Req::Response: crate::weight::ReportUsage,
+        C: Clone + Send + 'static,
+        C::Future: Send + 'static,
+    {
+        use crate::weight::WeightLimitLayer;
+        self.into_layered(&WeightLimitLayer::new(limits))
+    }
+
     #[cfg(feature = "retry")]
     /// Apply retry layer to the channel.
     pub fn into_retry(
@@ -1,15 +1,20 @@
 use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
     marker::PhantomData,
     sync::{
         atomic::{AtomicU64, Ordering},
         Arc,
     },
-    time::Duration,
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
 use futures::{future::BoxFuture, FutureExt};
 use humantime::format_duration;
-use tower::retry::{Policy, RetryLayer};
+use tokio::time::Instant;
+use tower::retry::{budget::Budget, Policy, RetryLayer};
+
+use crate::ExchangeError;
 
 const DEFAULT_MAX_SECS_TO_WAIT: u64 = 128;
 
@@ -193,3 +198,267 @@ where
 pub fn retry_always(max_duration: Duration) -> RetryLayer<Always> {
     RetryLayer::new(Always::with_max_duration(max_duration))
 }
+
+/// Jitter applied to the backoff delays.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Jitter {
+    /// No jitter.
+    #[default]
+    None,
+    /// A random delay between zero and the backoff delay.
+    Full,
+    /// Half of the backoff delay plus a random delay up to the other half.
+    Equal,
+}
+
+/// A random number in `[0, 1)`.
+fn random() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos(),
+    );
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Retry on temporary errors.
+pub fn temporary<T>(_req: &T, err: &ExchangeError) -> bool {
+    err.is_temporary()
+}
+
+/// Retry only on the temporary errors known to be returned before submission,
+/// which is safe for non-idempotent requests such as placing orders.
+pub fn pre_submission<T>(_req: &T, err: &ExchangeError) -> bool {
+    err.is_temporary() && err.is_pre_submission()
+}
+
+/// Exponential backoff retry policy.
+///
+/// The delay before the `n`-th retry is `base * 2^n` capped by the max delay,
+/// with the jitter applied.
+pub struct Backoff<T, E, F = fn(&T, &E) -> bool> {
+    base: Duration,
+    max_delay: Duration,
+    jitter: Jitter,
+    max_attempts: Option<usize>,
+    max_elapsed: Option<Duration>,
+    budget: Option<Arc<Budget>>,
+    predicate: F,
+    attempts: usize,
+    since: Option<Instant>,
+    _req: PhantomData<fn() -> (T, E)>,
+}
+
+impl<T, E, F: Clone> Clone for Backoff<T, E, F> {
+    fn clone(&self) -> Self {
+        Self {
+            base: self.base,
+            max_delay: self.max_delay,
+            jitter: self.jitter,
+            max_attempts: self.max_attempts,
+            max_elapsed: self.max_elapsed,
+            budget: self.budget.clone(),
+            predicate: self.predicate.clone(),
+            attempts: self.attempts,
+            since: self.since,
+            _req: PhantomData,
+        }
+    }
+}
+
+impl<T, E> Default for Backoff<T, E> {
+    fn default() -> Self {
+        Self {
+            base: Duration::from_secs(1),
+            max_delay: Duration::from_secs(DEFAULT_MAX_SECS_TO_WAIT),
+            jitter: Jitter::None,
+            max_attempts: None,
+            max_elapsed: None,
+            budget: None,
+            predicate: |_, _| true,
+            attempts: 0,
+            since: None,
+            _req: PhantomData,
+        }
+    }
+}
+
+impl<T, E, F> Backoff<T, E, F> {
+    /// Set the base delay.
+    pub fn base_delay(mut self, delay: Duration) -> Self {
+        self.base = delay;
+        self
+    }
+
+    /// Set the max delay.
+    pub fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// Set the jitter.
+    pub fn jitter(mut self, jitter: Jitter) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Set the max number of attempts, including the first one.
+    pub fn max_attempts(mut self, attempts: usize) -> Self {
+        self.max_attempts = Some(attempts);
+        self
+    }
+
+    /// Give up if the next retry would start after the given time since the first failure.
+    pub fn max_elapsed(mut self, elapsed: Duration) -> Self {
+        self.max_elapsed = Some(elapsed);
+        self
+    }
+
+    /// Limit the retries by the given budget, which can be shared by policies.
+    pub fn budget(mut self, budget: Arc<Budget>) -> Self {
+        self.budget = Some(budget);
+        self
+    }
+
+    /// Retry only if `f` returns `true` for the request and the error.
+    pub fn retry_on<F2>(self, f: F2) -> Backoff<T, E, F2>
+    where
+        F2: Fn(&T, &E) -> bool,
+    {
+        Backoff {
+            base: self.base,
+            max_delay: self.max_delay,
+            jitter: self.jitter,
+            max_attempts: self.max_attempts,
+            max_elapsed: self.max_elapsed,
+            budget: self.budget,
+            predicate: f,
+            attempts: self.attempts,
+            since: self.since,
+            _req: PhantomData,
+        }
+    }
+
+    fn delay(&self) -> Duration {
+        let factor = 1u32.checked_shl(self.attempts as u32).unwrap_or(u32::MAX);
+        let delay = self
+            .base
+            .checked_mul(factor)
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay);
+        match self.jitter {
+            Jitter::None => delay,
+            Jitter::Full => delay.mul_f64(random()),
+            Jitter::Equal => delay / 2 + (delay / 2).mul_f64(random()),
+        }
+    }
+}
+
+impl<T, U, E, F> Policy<T, U, E> for Backoff<T, E, F>
+where
+    T: 'static + Clone,
+    E: 'static,
+    F: Fn(&T, &E) -> bool,
+    F: Send + 'static + Clone,
+{
+    type Future = BoxFuture<'static, Self>;
+
+    fn retry(&self, req: &T, result: Result<&U, &E>) -> Option<Self::Future> {
+        if self.attempts == 0 {
+            if let Some(budget) = self.budget.as_ref() {
+                budget.deposit();
+            }
+        }
+        let err = result.err()?;
+        if !(self.predicate)(req, err) {
+            tracing::trace!("retry given up; not retryable");
+            return None;
+        }
+        if self
+            .max_attempts
+            .is_some_and(|max| self.attempts + 1 >= max)
+        {
+            tracing::trace!("retry given up; max attempts reached");
+            return None;
+        }
+        let since = self.since.unwrap_or_else(Instant::now);
+        let delay = self.delay();
+        if self
+            .max_elapsed
+            .is_some_and(|max| since.elapsed() + delay > max)
+        {
+            tracing::trace!("retry given up; out of time");
+            return None;
+        }
+        if let Some(budget) = self.budget.as_ref() {
+            if budget.withdraw().is_err() {
+                tracing::trace!("retry given up; out of budget");
+                return None;
+            }
+        }
+        tracing::trace!("retry in {};", format_duration(delay));
+        let mut retry = self.clone();
+        retry.attempts += 1;
+        retry.since = Some(since);
+        let fut = async move {
+            tokio::time::sleep(delay).await;
+            retry
+        };
+        Some(fut.boxed())
+    }
+
+    fn clone_request(&self, req: &T) -> Option<T> {
+        Some(req.clone())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    type Policy = Backoff<&'static str, ExchangeError>;
+
+    fn retry<F>(policy: &Backoff<&'static str, ExchangeError, F>, err: ExchangeError) -> bool
+    where
+        F: Fn(&&'static str, &ExchangeError) -> bool + Clone + Send + 'static,
+    {
+        tower::retry::Policy::<_, (), _>::retry(policy, &"place", Err(&err)).is_some()
+    }
+
+    #[test]
+    fn test_backoff() {
+        let policy = Policy::default()
+            .base_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_secs(1))
+            .jitter(Jitter::Equal);
+        for attempts in 0..8 {
+            let policy = Backoff {
+                attempts,
+                ..policy.clone()
+            };
+            let full = Duration::from_millis(100 << attempts).min(Duration::from_secs(1));
+            let delay = policy.delay();
+            assert!(delay >= full / 2 && delay <= full);
+        }
+
+        let policy = Policy::default().max_attempts(2).retry_on(pre_submission);
+        assert!(retry(
+            &policy,
+            ExchangeError::RateLimited(anyhow::anyhow!("429"))
+        ));
+        assert!(!retry(
+            &policy,
+            ExchangeError::Unavailable(anyhow::anyhow!("503"))
+        ));
+        let policy = Backoff {
+            attempts: 1,
+            ..policy
+        };
+        assert!(!retry(
+            &policy,
+            ExchangeError::RateLimited(anyhow::anyhow!("429"))
+        ));
+    }
+}
@@ -207,6 +207,20 @@ where
         self.into_service().apply(&RateLimitLayer::new(num, per))
     }
 
+    /// Apply a circuit breaker layer to the service.
+    fn circuit_breaker(
+        self,
+        threshold: usize,
+        cooldown: std::time::Duration,
+    ) -> crate::circuit::CircuitBreaker<IntoService<Self, R>>
+    where
+        Self: Sized,
+    {
+        use crate::circuit::CircuitBreakerLayer;
+        self.into_service()
+            .apply(&CircuitBreakerLayer::new(threshold, cooldown))
+    }
+
     /// Apply a retry layer to the service.
     #[cfg(feature = "retry")]
     fn retry(
@@ -224,6 +238,18 @@ where
             .apply(&RetryLayer::new(Always::with_max_duration(max_duration)))
     }
 
+    /// Apply a retry layer with the given policy to the service.
+    #[cfg(feature = "retry")]
+    fn retry_with<P>(self, policy: P) -> tower::retry::Retry<P, IntoService<Self, R>>
+    where
+        Self: Sized + Clone,
+        P: tower::retry::Policy<R, R::Response, ExchangeError> + Clone,
+    {
+        use tower::retry::RetryLayer;
+
+        self.into_service().apply(&RetryLayer::new(policy))
+    }
+
     /// Create a boxed [`ExcService`].
     fn boxed(self) -> BoxExcService<R>
     where
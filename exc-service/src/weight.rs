@@ -0,0 +1,296 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use futures::{future::BoxFuture, FutureExt};
+use tokio::time::Instant;
+use tower::{Layer, Service, ServiceExt};
+
+use crate::ExchangeError;
+
+/// The cost of a request against a limit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cost {
+    /// The name of the limit.
+    pub limit: &'static str,
+    /// The scope of the limit (e.g. an instrument), `None` for the global one.
+    pub scope: Option<String>,
+    /// Weight.
+    pub weight: u64,
+}
+
+impl Cost {
+    /// Create a cost against the global limit.
+    pub fn new(limit: &'static str, weight: u64) -> Self {
+        Self {
+            limit,
+            scope: None,
+            weight,
+        }
+    }
+
+    /// Charge the cost to the given scope instead.
+    pub fn scoped(mut self, scope: impl ToString) -> Self {
+        self.scope = Some(scope.to_string());
+        self
+    }
+}
+
+/// The used weight of a limit in the current window reported by the exchange.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Usage {
+    /// The name of the limit.
+    pub limit: &'static str,
+    /// The scope of the limit.
+    pub scope: Option<String>,
+    /// Used weight.
+    pub used: u64,
+}
+
+/// Requests with costs.
+pub trait Weighted {
+    /// The costs of the request.
+    fn costs(&self) -> Vec<Cost>;
+}
+
+/// Responses (and errors) that report the usage of the limits.
+pub trait ReportUsage {
+    /// The usage reported.
+    fn usage(&self) -> Vec<Usage> {
+        Vec::new()
+    }
+
+    /// Whether the request has been rejected for exceeding the limits.
+    fn is_rate_limited(&self) -> bool {
+        false
+    }
+}
+
+impl ReportUsage for ExchangeError {
+    fn is_rate_limited(&self) -> bool {
+        matches!(self, Self::RateLimited(_))
+    }
+}
+
+/// The limits, each of which allows a capacity of weight per fixed window.
+///
+/// The windows are aligned to the unix epoch, as the exchanges count them.
+/// A cost against a limit that is not set is ignored.
+#[derive(Debug, Clone, Default)]
+pub struct WeightLimits {
+    limits: HashMap<&'static str, (u64, Duration)>,
+}
+
+impl WeightLimits {
+    /// Set a limit.
+    pub fn limit(&mut self, name: &'static str, capacity: u64, window: Duration) -> &mut Self {
+        self.limits.insert(name, (capacity, window));
+        self
+    }
+}
+
+#[derive(Debug, Default)]
+struct Bucket {
+    window: u128,
+    used: u64,
+}
+
+#[derive(Debug)]
+struct State {
+    limits: WeightLimits,
+    buckets: HashMap<(&'static str, Option<String>), Bucket>,
+    origin: (Instant, u128),
+}
+
+impl State {
+    fn now(&self) -> u128 {
+        self.origin.1 + self.origin.0.elapsed().as_millis()
+    }
+
+    /// Get the bucket of the current window and the time left in it.
+    fn bucket(
+        &mut self,
+        limit: &'static str,
+        scope: &Option<String>,
+    ) -> Option<(&mut Bucket, u64, Duration)> {
+        let (capacity, window) = *self.limits.limits.get(limit)?;
+        let millis = window.as_millis().max(1);
+        let now = self.now();
+        let bucket = self.buckets.entry((limit, scope.clone())).or_default();
+        if bucket.window != now / millis {
+            bucket.window = now / millis;
+            bucket.used = 0;
+        }
+        let left = Duration::from_millis((millis - now % millis) as u64);
+        Some((bucket, capacity, left))
+    }
+}
+
+/// The shared counters of [`WeightLimits`].
+#[derive(Debug, Clone)]
+pub struct WeightLimiter {
+    state: Arc<Mutex<State>>,
+}
+
+impl Default for WeightLimiter {
+    fn default() -> Self {
+        Self::new(WeightLimits::default())
+    }
+}
+
+impl WeightLimiter {
+    /// Create a limiter with the given limits.
+    pub fn new(limits: WeightLimits) -> Self {
+        let unix = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        Self {
+            state: Arc::new(Mutex::new(State {
+                limits,
+                buckets: HashMap::default(),
+                origin: (Instant::now(), unix),
+            })),
+        }
+    }
+
+    /// Reserve the costs if all of them fit in the current windows,
+    /// otherwise return the time to wait.
+    pub fn try_acquire(&self, costs: &[Cost]) -> Result<(), Duration> {
+        let mut state = self.state.lock().unwrap();
+        let mut wait = Duration::ZERO;
+        for cost in costs {
+            if let Some((bucket, capacity, left)) = state.bucket(cost.limit, &cost.scope) {
+                // A cost larger than the capacity is allowed in an empty window.
+                if bucket.used > 0 && bucket.used + cost.weight > capacity {
+                    wait = wait.max(left);
+                }
+            }
+        }
+        if !wait.is_zero() {
+            return Err(wait);
+        }
+        for cost in costs {
+            if let Some((bucket, _, _)) = state.bucket(cost.limit, &cost.scope) {
+                bucket.used += cost.weight;
+            }
+        }
+        Ok(())
+    }
+
+    /// Wait until the costs can be reserved.
+    pub async fn acquire(&self, costs: &[Cost]) {
+        while let Err(wait) = self.try_acquire(costs) {
+            tracing::debug!("weight limit reached; wait for {wait:?}");
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Resynchronize the counters with the usage reported by the exchange.
+    pub fn sync(&self, usage: &[Usage]) {
+        let mut state = self.state.lock().unwrap();
+        for usage in usage {
+            if let Some((bucket, _, _)) = state.bucket(usage.limit, &usage.scope) {
+                bucket.used = bucket.used.max(usage.used);
+            }
+        }
+    }
+
+    /// Mark the limits of the costs as used up in the current windows.
+    pub fn exhaust(&self, costs: &[Cost]) {
+        let mut state = self.state.lock().unwrap();
+        for cost in costs {
+            if let Some((bucket, capacity, _)) = state.bucket(cost.limit, &cost.scope) {
+                bucket.used = bucket.used.max(capacity);
+            }
+        }
+    }
+}
+
+/// Weight limit layer.
+#[derive(Debug, Clone, Default)]
+pub struct WeightLimitLayer {
+    limiter: WeightLimiter,
+}
+
+impl WeightLimitLayer {
+    /// Create a layer with the given limits.
+    pub fn new(limits: WeightLimits) -> Self {
+        Self::with_limiter(WeightLimiter::new(limits))
+    }
+
+    /// Create a layer sharing the given limiter.
+    pub fn with_limiter(limiter: WeightLimiter) -> Self {
+        Self { limiter }
+    }
+}
+
+impl<S> Layer<S> for WeightLimitLayer {
+    type Service = WeightLimit<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        WeightLimit {
+            inner,
+            limiter: self.limiter.clone(),
+        }
+    }
+}
+
+/// Weight limit service.
+///
+/// It waits for the budget of each request before sending it,
+/// and resynchronizes the counters from the responses.
+#[derive(Debug, Clone)]
+pub struct WeightLimit<S> {
+    inner: S,
+    limiter: WeightLimiter,
+}
+
+impl<S> WeightLimit<S> {
+    /// Get the limiter.
+    pub fn limiter(&self) -> &WeightLimiter {
+        &self.limiter
+    }
+}
+
+impl<S, R> Service<R> for WeightLimit<S>
+where
+    R: Weighted + Send + 'static,
+    S: Service<R> + Clone + Send + 'static,
+    S::Response: ReportUsage,
+    S::Error: ReportUsage,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // The inner service is driven to ready after the budget is acquired.
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: R) -> Self::Future {
+        let inner = self.inner.clone();
+        let limiter = self.limiter.clone();
+        async move {
+            let costs = req.costs();
+            limiter.acquire(&costs).await;
+            let res = inner.oneshot(req).await;
+            match &res {
+                Ok(resp) => limiter.sync(&resp.usage()),
+                Err(err) => {
+                    limiter.sync(&err.usage());
+                    if err.is_rate_limited() {
+                        limiter.exhaust(&costs);
+                    }
+                }
+            }
+            res
+        }
+        .boxed()
+    }
+}
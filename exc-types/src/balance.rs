@@ -0,0 +1,60 @@
+use derive_more::Display;
+use exc_service::{ExchangeError, Request};
+use futures::{future::BoxFuture, stream::BoxStream};
+use indicator::{Tick, TickValue, Tickable};
+use positions::Asset;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+/// Balance Stream.
+pub type BalanceStream = BoxStream<'static, Result<Balance, ExchangeError>>;
+
+/// Fetch the balances of all assets in the account.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FetchBalances;
+
+impl Request for FetchBalances {
+    type Response = BoxFuture<'static, Result<Vec<Balance>, ExchangeError>>;
+}
+
+/// Subscribe to the balance updates of the account.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SubscribeBalances;
+
+impl Request for SubscribeBalances {
+    type Response = BalanceStream;
+}
+
+/// Balance of an asset.
+#[derive(Debug, Clone, Serialize, Deserialize, Display)]
+#[display(fmt = "ts={ts}, asset={asset}, total={total}, available={available}, frozen={frozen}")]
+pub struct Balance {
+    /// Timestamp.
+    #[serde(with = "time::serde::rfc3339")]
+    pub ts: OffsetDateTime,
+    /// Asset.
+    pub asset: Asset,
+    /// Total balance.
+    pub total: Decimal,
+    /// Available balance.
+    pub available: Decimal,
+    /// Frozen balance (locked by open orders or positions).
+    pub frozen: Decimal,
+}
+
+impl Tickable for Balance {
+    type Value = Self;
+
+    fn tick(&self) -> Tick {
+        Tick::new(self.ts)
+    }
+
+    fn value(&self) -> &Self::Value {
+        self
+    }
+
+    fn into_tick_value(self) -> TickValue<Self::Value> {
+        TickValue::new(self.ts, self)
+    }
+}
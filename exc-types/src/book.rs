@@ -1,4 +1,4 @@
-use std::fmt;
+use std::{collections::BTreeMap, fmt};
 
 use crate::Str;
 use futures::stream::BoxStream;
@@ -9,6 +9,9 @@ use time::OffsetDateTime;
 
 use exc_service::{ExchangeError, Request};
 
+/// Order book Stream.
+pub type OrderBookStream = BoxStream<'static, Result<OrderBook, ExchangeError>>;
+
 /// Best bid and ask Stream.
 pub type BidAskStream = BoxStream<'static, Result<BidAsk, ExchangeError>>;
 
@@ -78,3 +81,168 @@ impl SubscribeBidAsk {
 impl Request for SubscribeBidAsk {
     type Response = BidAskStream;
 }
+
+/// Snapshot of the order book.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderBook {
+    /// Timestamp.
+    #[serde(with = "time::serde::rfc3339")]
+    pub ts: OffsetDateTime,
+    /// Bids (price, size), best first.
+    pub bids: Vec<(Decimal, Decimal)>,
+    /// Asks (price, size), best first.
+    pub asks: Vec<(Decimal, Decimal)>,
+}
+
+impl OrderBook {
+    /// Best bid.
+    pub fn best_bid(&self) -> Option<(Decimal, Decimal)> {
+        self.bids.first().copied()
+    }
+
+    /// Best ask.
+    pub fn best_ask(&self) -> Option<(Decimal, Decimal)> {
+        self.asks.first().copied()
+    }
+
+    /// Keep only the best `depth` levels of each side.
+    pub fn truncate(&mut self, depth: usize) {
+        self.bids.truncate(depth);
+        self.asks.truncate(depth);
+    }
+}
+
+impl fmt::Display for OrderBook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ts={}, bids={}, asks={}",
+            self.ts,
+            self.bids.len(),
+            self.asks.len()
+        )
+    }
+}
+
+impl From<&OrderBook> for BidAsk {
+    fn from(book: &OrderBook) -> Self {
+        Self {
+            ts: book.ts,
+            bid: book.best_bid(),
+            ask: book.best_ask(),
+        }
+    }
+}
+
+impl Tickable for OrderBook {
+    type Value = Self;
+
+    fn tick(&self) -> Tick {
+        Tick::new(self.ts)
+    }
+
+    fn value(&self) -> &Self::Value {
+        self
+    }
+
+    fn into_tick_value(self) -> TickValue<Self::Value> {
+        TickValue::new(self.ts, self)
+    }
+}
+
+/// Order book maintained locally from snapshots and incremental updates.
+#[derive(Debug, Clone, Default)]
+pub struct LocalOrderBook {
+    bids: BTreeMap<Decimal, Decimal>,
+    asks: BTreeMap<Decimal, Decimal>,
+}
+
+impl LocalOrderBook {
+    fn apply(
+        side: &mut BTreeMap<Decimal, Decimal>,
+        levels: impl IntoIterator<Item = (Decimal, Decimal)>,
+    ) {
+        for (price, size) in levels {
+            side.remove(&price);
+            if !size.is_zero() {
+                side.insert(price, size);
+            }
+        }
+    }
+
+    /// Clear the book.
+    pub fn clear(&mut self) {
+        self.bids.clear();
+        self.asks.clear();
+    }
+
+    /// Update bid levels. A level with zero size is removed.
+    pub fn update_bids(&mut self, levels: impl IntoIterator<Item = (Decimal, Decimal)>) {
+        Self::apply(&mut self.bids, levels);
+    }
+
+    /// Update ask levels. A level with zero size is removed.
+    pub fn update_asks(&mut self, levels: impl IntoIterator<Item = (Decimal, Decimal)>) {
+        Self::apply(&mut self.asks, levels);
+    }
+
+    /// Bid levels, best first.
+    pub fn bids(&self) -> impl Iterator<Item = (&Decimal, &Decimal)> {
+        self.bids.iter().rev()
+    }
+
+    /// Ask levels, best first.
+    pub fn asks(&self) -> impl Iterator<Item = (&Decimal, &Decimal)> {
+        self.asks.iter()
+    }
+
+    /// Whether the best bid is not lower than the best ask.
+    pub fn is_crossed(&self) -> bool {
+        match (self.bids.last_key_value(), self.asks.first_key_value()) {
+            (Some((bid, _)), Some((ask, _))) => bid >= ask,
+            _ => false,
+        }
+    }
+
+    /// Take a snapshot of the best `depth` levels (all levels if `None`).
+    pub fn snapshot(&self, ts: OffsetDateTime, depth: Option<usize>) -> OrderBook {
+        let depth = depth.unwrap_or(usize::MAX);
+        OrderBook {
+            ts,
+            bids: self.bids().take(depth).map(|(p, s)| (*p, *s)).collect(),
+            asks: self.asks().take(depth).map(|(p, s)| (*p, *s)).collect(),
+        }
+    }
+}
+
+/// Subscribe to the order book.
+/// Each item of the stream is a consistent snapshot of the book.
+#[derive(Debug, Clone)]
+pub struct SubscribeOrderBook {
+    /// Instrument.
+    pub instrument: Str,
+    /// Number of levels of each side (the full book available if `None`).
+    pub depth: Option<usize>,
+}
+
+impl SubscribeOrderBook {
+    /// Create a new [`SubscribeOrderBook`] request for the full book.
+    pub fn new(inst: impl AsRef<str>) -> Self {
+        Self {
+            instrument: Str::new(inst),
+            depth: None,
+        }
+    }
+
+    /// Create a new [`SubscribeOrderBook`] request with the given depth.
+    pub fn with_depth(inst: impl AsRef<str>, depth: usize) -> Self {
+        Self {
+            instrument: Str::new(inst),
+            depth: Some(depth),
+        }
+    }
+}
+
+impl Request for SubscribeOrderBook {
+    type Response = OrderBookStream;
+}
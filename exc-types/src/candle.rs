@@ -61,6 +61,32 @@ impl Stream for CandleStream {
     }
 }
 
+/// The price source of candles.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CandleSource {
+    /// Trade price.
+    #[default]
+    Trade,
+    /// Mark price.
+    Mark,
+    /// Index price.
+    Index,
+    /// Premium index.
+    Premium,
+}
+
+impl fmt::Display for CandleSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Trade => write!(f, "trade"),
+            Self::Mark => write!(f, "mark"),
+            Self::Index => write!(f, "index"),
+            Self::Premium => write!(f, "premium"),
+        }
+    }
+}
+
 /// Query candles.
 #[derive(Debug, Clone)]
 pub struct QueryCandles {
@@ -68,6 +94,8 @@ pub struct QueryCandles {
     pub inst: Str,
     /// Period.
     pub period: Period,
+    /// Price source.
+    pub source: CandleSource,
     /// Start.
     pub start: Bound<OffsetDateTime>,
     /// End.
@@ -92,11 +120,13 @@ fn fmt_ts_end_bound(bound: &Bound<OffsetDateTime>) -> String {
 
 impl fmt::Display for QueryCandles {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}-", self.inst, self.period)?;
+        if self.source != CandleSource::Trade {
+            write!(f, "{}-", self.source)?;
+        }
         write!(
             f,
-            "{}-{}-{}, {}",
-            self.inst,
-            self.period,
+            "{}, {}",
             fmt_ts_start_bound(&self.start),
             fmt_ts_end_bound(&self.end)
         )
@@ -123,11 +153,18 @@ impl QueryCandles {
         Self {
             inst: Str::new(inst),
             period,
+            source: CandleSource::Trade,
             start,
             end,
         }
     }
 
+    /// Set the price source. Default to [`CandleSource::Trade`].
+    pub fn with_source(mut self, source: CandleSource) -> Self {
+        self.source = source;
+        self
+    }
+
     /// Get Instrument.
     pub fn inst(&self) -> &str {
         self.inst.as_str()
@@ -138,6 +175,11 @@ impl QueryCandles {
         self.period
     }
 
+    /// Get price source.
+    pub fn source(&self) -> CandleSource {
+        self.source
+    }
+
     /// Is empty.
     pub fn is_empty(&self) -> bool {
         match (self.start_bound(), self.end_bound()) {
@@ -199,6 +241,12 @@ impl QueryLastCandles {
         Self { query, last }
     }
 
+    /// Set the price source. Default to [`CandleSource::Trade`].
+    pub fn with_source(mut self, source: CandleSource) -> Self {
+        self.query.source = source;
+        self
+    }
+
     /// Get last.
     pub fn last(&self) -> usize {
         self.last
@@ -240,6 +288,12 @@ impl QueryFirstCandles {
         Self { query, first }
     }
 
+    /// Set the price source. Default to [`CandleSource::Trade`].
+    pub fn with_source(mut self, source: CandleSource) -> Self {
+        self.query.source = source;
+        self
+    }
+
     /// Get first.
     pub fn first(&self) -> usize {
         self.first
@@ -255,6 +309,76 @@ impl Request for QueryFirstCandles {
     type Response = CandleStream;
 }
 
+/// Subscribe candles.
+/// Return a stream that produce the updating and closed candles.
+#[derive(Debug, Clone)]
+pub struct SubscribeCandles {
+    /// Instrument.
+    pub inst: Str,
+    /// Period.
+    pub period: Period,
+}
+
+impl fmt::Display for SubscribeCandles {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.inst, self.period)
+    }
+}
+
+impl SubscribeCandles {
+    /// Create a new [`SubscribeCandles`] request.
+    pub fn new(inst: impl AsRef<str>, period: Period) -> Self {
+        Self {
+            inst: Str::new(inst),
+            period,
+        }
+    }
+
+    /// Get instrument.
+    pub fn inst(&self) -> &str {
+        self.inst.as_str()
+    }
+
+    /// Get period.
+    pub fn period(&self) -> Period {
+        self.period
+    }
+}
+
+impl Request for SubscribeCandles {
+    type Response = CandleUpdateStream;
+}
+
+/// Candle update stream.
+pub type CandleUpdateStream = BoxStream<'static, Result<CandleUpdate, ExchangeError>>;
+
+/// Candle update.
+#[derive(Debug, Clone, Serialize, Deserialize, Display)]
+#[display(fmt = "{candle} closed={is_closed}")]
+pub struct CandleUpdate {
+    /// Candle.
+    #[serde(flatten)]
+    pub candle: Candle,
+    /// Whether the candle is closed (will not be updated anymore).
+    pub is_closed: bool,
+}
+
+impl Tickable for CandleUpdate {
+    type Value = Self;
+
+    fn tick(&self) -> Tick {
+        Tick::new(self.candle.ts)
+    }
+
+    fn value(&self) -> &Self::Value {
+        self
+    }
+
+    fn into_tick_value(self) -> TickValue<Self::Value> {
+        TickValue::new(self.candle.ts, self)
+    }
+}
+
 /// Candle (OHLCV).
 #[derive(Debug, Clone, Serialize, Deserialize, Display)]
 #[display(fmt = "ts={ts} ohlcv=[{open}, {high}, {low}, {close}, {volume}]")]
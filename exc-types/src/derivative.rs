@@ -0,0 +1,325 @@
+use std::ops::{Bound, RangeBounds};
+
+use derive_more::Display;
+use exc_service::{ExchangeError, Request};
+use futures::{future::BoxFuture, stream::BoxStream};
+use indicator::{Tick, TickValue, Tickable};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::{Period, Str};
+
+/// Mark price stream.
+pub type MarkPriceStream = BoxStream<'static, Result<MarkPrice, ExchangeError>>;
+
+/// Index price stream.
+pub type IndexPriceStream = BoxStream<'static, Result<IndexPrice, ExchangeError>>;
+
+/// Funding rate stream.
+pub type FundingRateStream = BoxStream<'static, Result<FundingRate, ExchangeError>>;
+
+/// Open interest stream.
+pub type OpenInterestStream = BoxStream<'static, Result<OpenInterest, ExchangeError>>;
+
+/// Liquidation stream.
+pub type LiquidationStream = BoxStream<'static, Result<Liquidation, ExchangeError>>;
+
+/// Mark price.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Display)]
+#[display(fmt = "ts={ts}, mark={price}")]
+pub struct MarkPrice {
+    /// Timestamp.
+    #[serde(with = "time::serde::rfc3339")]
+    pub ts: OffsetDateTime,
+    /// Mark price.
+    pub price: Decimal,
+}
+
+/// Index price.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Display)]
+#[display(fmt = "ts={ts}, index={price}")]
+pub struct IndexPrice {
+    /// Timestamp.
+    #[serde(with = "time::serde::rfc3339")]
+    pub ts: OffsetDateTime,
+    /// Index price.
+    pub price: Decimal,
+}
+
+/// Funding rate.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Display)]
+#[display(fmt = "ts={ts}, rate={rate}, funding_time={funding_time}")]
+pub struct FundingRate {
+    /// Timestamp.
+    #[serde(with = "time::serde::rfc3339")]
+    pub ts: OffsetDateTime,
+    /// Funding rate.
+    pub rate: Decimal,
+    /// The time when the funding rate is (or was) settled.
+    #[serde(with = "time::serde::rfc3339")]
+    pub funding_time: OffsetDateTime,
+}
+
+/// Open interest.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Display)]
+#[display(fmt = "ts={ts}, oi={size}, value={value:?}")]
+pub struct OpenInterest {
+    /// Timestamp.
+    #[serde(with = "time::serde::rfc3339")]
+    pub ts: OffsetDateTime,
+    /// Open interest (in the unit of order size).
+    pub size: Decimal,
+    /// Notional value of the open interest (in USD or the quote asset), if provided.
+    #[serde(default)]
+    pub value: Option<Decimal>,
+}
+
+/// Liquidation (forced order).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Display)]
+#[display(fmt = "ts={ts}, ({price}, {size})")]
+pub struct Liquidation {
+    /// Timestamp.
+    #[serde(with = "time::serde::rfc3339")]
+    pub ts: OffsetDateTime,
+    /// Price.
+    pub price: Decimal,
+    /// Size (negative for sell, i.e. a long position is liquidated).
+    pub size: Decimal,
+}
+
+/// Subscribe mark price.
+#[derive(Debug, Clone)]
+pub struct SubscribeMarkPrice {
+    /// Instrument.
+    pub instrument: Str,
+}
+
+impl SubscribeMarkPrice {
+    /// Create a new [`SubscribeMarkPrice`] request.
+    pub fn new(inst: impl AsRef<str>) -> Self {
+        Self {
+            instrument: Str::new(inst),
+        }
+    }
+}
+
+impl Request for SubscribeMarkPrice {
+    type Response = MarkPriceStream;
+}
+
+/// Subscribe index price.
+///
+/// The `instrument` can be a derivative instrument, in which case
+/// the index price of its underlying is subscribed.
+#[derive(Debug, Clone)]
+pub struct SubscribeIndexPrice {
+    /// Instrument.
+    pub instrument: Str,
+}
+
+impl SubscribeIndexPrice {
+    /// Create a new [`SubscribeIndexPrice`] request.
+    pub fn new(inst: impl AsRef<str>) -> Self {
+        Self {
+            instrument: Str::new(inst),
+        }
+    }
+}
+
+impl Request for SubscribeIndexPrice {
+    type Response = IndexPriceStream;
+}
+
+/// Subscribe funding rate.
+#[derive(Debug, Clone)]
+pub struct SubscribeFundingRate {
+    /// Instrument.
+    pub instrument: Str,
+}
+
+impl SubscribeFundingRate {
+    /// Create a new [`SubscribeFundingRate`] request.
+    pub fn new(inst: impl AsRef<str>) -> Self {
+        Self {
+            instrument: Str::new(inst),
+        }
+    }
+}
+
+impl Request for SubscribeFundingRate {
+    type Response = FundingRateStream;
+}
+
+/// Fetch the funding rates settled in range.
+/// Return the funding rates, newest first.
+#[derive(Debug, Clone)]
+pub struct FetchFundingRateHistory {
+    /// Instrument.
+    pub instrument: Str,
+    /// Start.
+    pub start: Bound<OffsetDateTime>,
+    /// End.
+    pub end: Bound<OffsetDateTime>,
+    /// Fetch at most the last `n` funding rates (exchange-defined if `None`).
+    pub last: Option<usize>,
+}
+
+impl FetchFundingRateHistory {
+    /// Create a new query.
+    pub fn new<R>(inst: impl AsRef<str>, range: R) -> Self
+    where
+        R: RangeBounds<OffsetDateTime>,
+    {
+        Self {
+            instrument: Str::new(inst),
+            start: range.start_bound().cloned(),
+            end: range.end_bound().cloned(),
+            last: None,
+        }
+    }
+
+    /// Fetch at most the last `n` funding rates.
+    pub fn with_last(mut self, last: usize) -> Self {
+        self.last = Some(last);
+        self
+    }
+}
+
+impl RangeBounds<OffsetDateTime> for FetchFundingRateHistory {
+    fn start_bound(&self) -> Bound<&OffsetDateTime> {
+        self.start.as_ref()
+    }
+
+    fn end_bound(&self) -> Bound<&OffsetDateTime> {
+        self.end.as_ref()
+    }
+}
+
+impl Request for FetchFundingRateHistory {
+    type Response = BoxFuture<'static, Result<Vec<FundingRate>, ExchangeError>>;
+}
+
+/// Subscribe open interest.
+#[derive(Debug, Clone)]
+pub struct SubscribeOpenInterest {
+    /// Instrument.
+    pub instrument: Str,
+}
+
+impl SubscribeOpenInterest {
+    /// Create a new [`SubscribeOpenInterest`] request.
+    pub fn new(inst: impl AsRef<str>) -> Self {
+        Self {
+            instrument: Str::new(inst),
+        }
+    }
+}
+
+impl Request for SubscribeOpenInterest {
+    type Response = OpenInterestStream;
+}
+
+/// Fetch the open interest statistics of the given period in range.
+/// Return the open interests, newest first.
+#[derive(Debug, Clone)]
+pub struct FetchOpenInterestHistory {
+    /// Instrument.
+    pub instrument: Str,
+    /// Period.
+    pub period: Period,
+    /// Start.
+    pub start: Bound<OffsetDateTime>,
+    /// End.
+    pub end: Bound<OffsetDateTime>,
+    /// Fetch at most the last `n` open interests (exchange-defined if `None`).
+    pub last: Option<usize>,
+}
+
+impl FetchOpenInterestHistory {
+    /// Create a new query.
+    pub fn new<R>(inst: impl AsRef<str>, period: Period, range: R) -> Self
+    where
+        R: RangeBounds<OffsetDateTime>,
+    {
+        Self {
+            instrument: Str::new(inst),
+            period,
+            start: range.start_bound().cloned(),
+            end: range.end_bound().cloned(),
+            last: None,
+        }
+    }
+
+    /// Fetch at most the last `n` open interests.
+    pub fn with_last(mut self, last: usize) -> Self {
+        self.last = Some(last);
+        self
+    }
+}
+
+impl RangeBounds<OffsetDateTime> for FetchOpenInterestHistory {
+    fn start_bound(&self) -> Bound<&OffsetDateTime> {
+        self.start.as_ref()
+    }
+
+    fn end_bound(&self) -> Bound<&OffsetDateTime> {
+        self.end.as_ref()
+    }
+}
+
+impl Request for FetchOpenInterestHistory {
+    type Response = BoxFuture<'static, Result<Vec<OpenInterest>, ExchangeError>>;
+}
+
+/// Subscribe liquidations.
+#[derive(Debug, Clone)]
+pub struct SubscribeLiquidations {
+    /// Instrument.
+    pub instrument: Str,
+}
+
+impl SubscribeLiquidations {
+    /// Create a new [`SubscribeLiquidations`] request.
+    pub fn new(inst: impl AsRef<str>) -> Self {
+        Self {
+            instrument: Str::new(inst),
+        }
+    }
+}
+
+impl Request for SubscribeLiquidations {
+    type Response = LiquidationStream;
+}
+
+impl Tickable for MarkPrice {
+    type Value = Self;
+
+    fn tick(&self) -> Tick {
+        Tick::new(self.ts)
+    }
+
+    fn value(&self) -> &Self::Value {
+        self
+    }
+
+    fn into_tick_value(self) -> TickValue<Self::Value> {
+        TickValue::new(self.ts, self)
+    }
+}
+
+impl Tickable for IndexPrice {
+    type Value = Self;
+
+    fn tick(&self) -> Tick {
+        Tick::new(self.ts)
+    }
+
+    fn value(&self) -> &Self::Value {
+        self
+    }
+
+    fn into_tick_value(self) -> TickValue<Self::Value> {
+        TickValue::new(self.ts, self)
+    }
+}
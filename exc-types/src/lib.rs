@@ -20,6 +20,15 @@ pub mod instrument;
 /// Trading.
 pub mod trading;
 
+/// Balance.
+pub mod balance;
+
+/// Position.
+pub mod position;
+
+/// Derivative.
+pub mod derivative;
+
 /// Utils.
 pub mod utils;
 
@@ -31,15 +40,29 @@ pub mod symbol {
 pub use self::instrument::{
     FetchInstruments, InstrumentMeta, InstrumentStream, SubscribeInstruments,
 };
-pub use book::{BidAsk, BidAskStream, SubscribeBidAsk};
+pub use balance::{Balance, BalanceStream, FetchBalances, SubscribeBalances};
+pub use book::{
+    BidAsk, BidAskStream, LocalOrderBook, OrderBook, OrderBookStream, SubscribeBidAsk,
+    SubscribeOrderBook,
+};
 pub use candle::{
-    Candle, CandleStream, Period, PeriodKind, QueryCandles, QueryFirstCandles, QueryLastCandles,
+    Candle, CandleSource, CandleStream, CandleUpdate, CandleUpdateStream, Period, PeriodKind,
+    QueryCandles, QueryFirstCandles, QueryLastCandles, SubscribeCandles,
+};
+pub use derivative::{
+    FetchFundingRateHistory, FetchOpenInterestHistory, FundingRate, FundingRateStream, IndexPrice,
+    IndexPriceStream, Liquidation, LiquidationStream, MarkPrice, MarkPriceStream, OpenInterest,
+    OpenInterestStream, SubscribeFundingRate, SubscribeIndexPrice, SubscribeLiquidations,
+    SubscribeMarkPrice, SubscribeOpenInterest,
 };
+pub use position::{FetchPositions, MarginMode, Position, PositionStream, SubscribePositions};
 pub use positions::prelude::Str;
 pub use ticker::{SubscribeTickers, Ticker, TickerStream};
 pub use trade::{SubscribeTrades, Trade, TradeStream};
 pub use trading::{
-    CancelOrder, Canceled, GetOrder, Order, OrderId, OrderKind, OrderState, OrderStatus,
-    OrderStream, OrderTrade, OrderUpdate, Place, PlaceOrder, PlaceOrderOptions, Placed,
-    SubscribeOrders, TimeInForce,
+    AmendOrder, Amended, CancelAllAfter, CancelAllOrders, CancelOrder, CancelOrders, Canceled,
+    FetchFills, FetchLastFills, FetchLastOrders, FetchOpenOrders, FetchOrderHistory, Fill,
+    FillStream, GetOrder, Liquidity, Order, OrderId, OrderKind, OrderState, OrderStatus,
+    OrderStream, OrderTrade, OrderUpdate, OrdersPage, Place, PlaceOrder, PlaceOrderOptions,
+    PlaceOrders, Placed, PositionSide, SubscribeOrders, TimeInForce, Trigger, TriggerSource,
 };
@@ -0,0 +1,102 @@
+use crate::Str;
+use derive_more::Display;
+use exc_service::{ExchangeError, Request};
+use futures::{future::BoxFuture, stream::BoxStream};
+use indicator::{Tick, TickValue, Tickable};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+/// Position Stream.
+pub type PositionStream = BoxStream<'static, Result<Position, ExchangeError>>;
+
+/// Fetch the positions of the account.
+#[derive(Debug, Clone, Default)]
+pub struct FetchPositions {
+    /// Only fetch the position of this instrument if set.
+    pub instrument: Option<Str>,
+}
+
+impl FetchPositions {
+    /// Create a new [`FetchPositions`] request for all instruments.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only fetch the position of the given instrument.
+    pub fn with_instrument(inst: impl AsRef<str>) -> Self {
+        Self {
+            instrument: Some(Str::new(inst)),
+        }
+    }
+}
+
+impl Request for FetchPositions {
+    type Response = BoxFuture<'static, Result<Vec<Position>, ExchangeError>>;
+}
+
+/// Subscribe to the position updates of the account.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SubscribePositions;
+
+impl Request for SubscribePositions {
+    type Response = PositionStream;
+}
+
+/// Margin mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Display)]
+#[serde(rename_all = "snake_case")]
+pub enum MarginMode {
+    /// Cross margin.
+    #[display(fmt = "cross")]
+    Cross,
+    /// Isolated margin.
+    #[display(fmt = "isolated")]
+    Isolated,
+}
+
+/// Position of an instrument.
+#[derive(Debug, Clone, Serialize, Deserialize, Display)]
+#[display(
+    fmt = "ts={ts}, inst={instrument}, size={size}, entry={entry_price}, mark={mark_price:?}, upnl={unrealized_pnl}, liq={liquidation_price:?}, mode={margin_mode}, lever={leverage:?}"
+)]
+pub struct Position {
+    /// Timestamp.
+    #[serde(with = "time::serde::rfc3339")]
+    pub ts: OffsetDateTime,
+    /// Instrument.
+    pub instrument: Str,
+    /// Size (positive for long and negative for short).
+    pub size: Decimal,
+    /// Average entry price.
+    pub entry_price: Decimal,
+    /// Mark price.
+    #[serde(default)]
+    pub mark_price: Option<Decimal>,
+    /// Unrealized profit and loss.
+    pub unrealized_pnl: Decimal,
+    /// Estimated liquidation price.
+    #[serde(default)]
+    pub liquidation_price: Option<Decimal>,
+    /// Margin mode.
+    pub margin_mode: MarginMode,
+    /// Leverage.
+    #[serde(default)]
+    pub leverage: Option<Decimal>,
+}
+
+impl Tickable for Position {
+    type Value = Self;
+
+    fn tick(&self) -> Tick {
+        Tick::new(self.ts)
+    }
+
+    fn value(&self) -> &Self::Value {
+        self
+    }
+
+    fn into_tick_value(self) -> TickValue<Self::Value> {
+        TickValue::new(self.ts, self)
+    }
+}
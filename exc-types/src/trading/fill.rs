@@ -0,0 +1,121 @@
+use std::ops::{Bound, RangeBounds};
+
+use exc_service::{ExchangeError, Request};
+use futures::{future::BoxFuture, stream::BoxStream};
+use positions::Asset;
+use rust_decimal::Decimal;
+use time::OffsetDateTime;
+
+use crate::Str;
+
+/// Liquidity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Liquidity {
+    /// Maker.
+    Maker,
+    /// Taker.
+    Taker,
+}
+
+/// Fill (execution of an order).
+#[derive(Debug, Clone)]
+pub struct Fill {
+    /// Order id assigned by the exchange.
+    pub order_id: Str,
+    /// Trade id.
+    pub trade_id: Str,
+    /// Price.
+    pub price: Decimal,
+    /// Size (negative for sell).
+    pub size: Decimal,
+    /// Fee (negative for paid).
+    pub fee: Decimal,
+    /// Fee asset.
+    pub fee_asset: Asset,
+    /// Liquidity.
+    pub liquidity: Liquidity,
+    /// Timestamp.
+    pub ts: OffsetDateTime,
+}
+
+/// Fill stream.
+pub type FillStream = BoxStream<'static, Result<Fill, ExchangeError>>;
+
+/// Fetch the fills in range.
+/// Return a stream that produce the fills backward.
+#[derive(Debug, Clone)]
+pub struct FetchFills {
+    /// Instrument.
+    pub instrument: Str,
+    /// Start.
+    pub start: Bound<OffsetDateTime>,
+    /// End.
+    pub end: Bound<OffsetDateTime>,
+}
+
+impl FetchFills {
+    /// Create a new query.
+    pub fn new<R>(inst: impl AsRef<str>, range: R) -> Self
+    where
+        R: RangeBounds<OffsetDateTime>,
+    {
+        Self {
+            instrument: Str::new(inst),
+            start: range.start_bound().cloned(),
+            end: range.end_bound().cloned(),
+        }
+    }
+
+    /// Is empty.
+    pub fn is_empty(&self) -> bool {
+        match (self.start_bound(), self.end_bound()) {
+            (Bound::Unbounded, _) => false,
+            (_, Bound::Unbounded) => false,
+            (Bound::Included(start), Bound::Included(end)) => *start > *end,
+            (Bound::Included(start), Bound::Excluded(end)) => *start >= *end,
+            (Bound::Excluded(start), Bound::Included(end)) => *start >= *end,
+            (Bound::Excluded(start), Bound::Excluded(end)) => *start >= *end,
+        }
+    }
+}
+
+impl RangeBounds<OffsetDateTime> for FetchFills {
+    fn start_bound(&self) -> Bound<&OffsetDateTime> {
+        self.start.as_ref()
+    }
+
+    fn end_bound(&self) -> Bound<&OffsetDateTime> {
+        self.end.as_ref()
+    }
+}
+
+impl Request for FetchFills {
+    type Response = FillStream;
+}
+
+/// Fetch the last `n` fills in range.
+/// Return the fills, newest first.
+#[derive(Debug, Clone)]
+pub struct FetchLastFills {
+    /// Query.
+    pub query: FetchFills,
+    /// Last.
+    pub last: usize,
+}
+
+impl FetchLastFills {
+    /// Create a new query.
+    pub fn new<R>(inst: impl AsRef<str>, range: R, last: usize) -> Self
+    where
+        R: RangeBounds<OffsetDateTime>,
+    {
+        Self {
+            query: FetchFills::new(inst, range),
+            last,
+        }
+    }
+}
+
+impl Request for FetchLastFills {
+    type Response = BoxFuture<'static, Result<Vec<Fill>, ExchangeError>>;
+}
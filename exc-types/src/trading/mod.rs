@@ -4,14 +4,26 @@ pub mod place;
 /// Order.
 pub mod order;
 
-use std::{collections::BTreeMap, fmt, sync::Arc};
+/// Order queries.
+pub mod query;
+
+/// Fills.
+pub mod fill;
+
+use std::{collections::BTreeMap, fmt, sync::Arc, time::Duration};
 
 use exc_service::{ExchangeError, Request};
+pub use fill::{FetchFills, FetchLastFills, Fill, FillStream, Liquidity};
 use futures::{future::BoxFuture, stream::BoxStream};
 use indicator::{Tick, TickValue, Tickable};
-pub use order::{Order, OrderId, OrderKind, OrderState, OrderStatus, OrderTrade, TimeInForce};
+pub use order::{
+    Order, OrderId, OrderKind, OrderState, OrderStatus, OrderTrade, PositionSide, TimeInForce,
+    Trigger, TriggerSource,
+};
 pub use place::Place;
 use positions::Asset;
+pub use query::{FetchLastOrders, FetchOpenOrders, FetchOrderHistory, OrdersPage};
+use rust_decimal::Decimal;
 use time::OffsetDateTime;
 
 use crate::Str;
@@ -25,6 +37,10 @@ pub struct PlaceOrderOptions {
     client_id: Option<Str>,
     /// Margin currency perferred to use.
     margin: Option<Asset>,
+    /// Reduce only.
+    reduce_only: bool,
+    /// Position side.
+    position_side: PositionSide,
     /// Exchange-defined options.
     custom: BTreeMap<Str, Str>,
 }
@@ -36,6 +52,8 @@ impl PlaceOrderOptions {
             instrument: Str::new(inst),
             client_id: None,
             margin: None,
+            reduce_only: false,
+            position_side: PositionSide::default(),
             custom: BTreeMap::default(),
         }
     }
@@ -55,6 +73,19 @@ impl PlaceOrderOptions {
         self
     }
 
+    /// Set whether the order should only reduce the position.
+    pub fn with_reduce_only(&mut self, reduce_only: bool) -> &mut Self {
+        self.reduce_only = reduce_only;
+        self
+    }
+
+    /// Set the position side to trade (default to [`PositionSide::Net`]).
+    /// Use [`PositionSide::Long`] or [`PositionSide::Short`] for hedge-mode accounts.
+    pub fn with_position_side(&mut self, side: PositionSide) -> &mut Self {
+        self.position_side = side;
+        self
+    }
+
     /// Insert an exchange-defined custom option.
     pub fn insert<K, V>(&mut self, key: K, value: V) -> &mut Self
     where
@@ -81,6 +112,16 @@ impl PlaceOrderOptions {
         self.margin.as_deref()
     }
 
+    /// Is reduce only.
+    pub fn reduce_only(&self) -> bool {
+        self.reduce_only
+    }
+
+    /// Get the position side to trade.
+    pub fn position_side(&self) -> PositionSide {
+        self.position_side
+    }
+
     /// Get the exchange-defined custom options.
     pub fn custom(&self) -> &BTreeMap<Str, Str> {
         &self.custom
@@ -172,6 +213,136 @@ impl Request for CancelOrder {
     type Response = BoxFuture<'static, Result<Canceled, ExchangeError>>;
 }
 
+/// Place a batch of orders.
+#[derive(Debug, Clone, Default)]
+pub struct PlaceOrders(pub Vec<PlaceOrder>);
+
+impl Request for PlaceOrders {
+    type Response = BoxFuture<'static, Result<Vec<Result<Placed, ExchangeError>>, ExchangeError>>;
+}
+
+/// Cancel a batch of orders.
+#[derive(Debug, Clone, Default)]
+pub struct CancelOrders(pub Vec<CancelOrder>);
+
+impl Request for CancelOrders {
+    type Response = BoxFuture<'static, Result<Vec<Result<Canceled, ExchangeError>>, ExchangeError>>;
+}
+
+/// Cancel all orders.
+#[derive(Debug, Clone, Default)]
+pub struct CancelAllOrders {
+    /// Only cancel the orders of this instrument if set.
+    pub instrument: Option<Str>,
+}
+
+impl CancelAllOrders {
+    /// Create a new [`CancelAllOrders`] request for all instruments.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only cancel the orders of the given instrument.
+    pub fn with_instrument(inst: impl AsRef<str>) -> Self {
+        Self {
+            instrument: Some(Str::new(inst)),
+        }
+    }
+}
+
+impl Request for CancelAllOrders {
+    type Response = BoxFuture<'static, Result<(), ExchangeError>>;
+}
+
+/// Cancel all orders if the request is not renewed before the timeout
+/// (a.k.a. dead man's switch). A zero timeout disarms the switch.
+#[derive(Debug, Clone)]
+pub struct CancelAllAfter {
+    /// Only cancel the orders of this instrument if set.
+    /// # Note
+    /// Some exchanges require it while some others ignore it.
+    pub instrument: Option<Str>,
+    /// Timeout.
+    pub timeout: Duration,
+}
+
+impl CancelAllAfter {
+    /// Create a new [`CancelAllAfter`] request for all instruments.
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            instrument: None,
+            timeout,
+        }
+    }
+
+    /// Create a new [`CancelAllAfter`] request for the given instrument.
+    pub fn with_instrument(inst: impl AsRef<str>, timeout: Duration) -> Self {
+        Self {
+            instrument: Some(Str::new(inst)),
+            timeout,
+        }
+    }
+}
+
+impl Request for CancelAllAfter {
+    type Response = BoxFuture<'static, Result<(), ExchangeError>>;
+}
+
+/// Amend order.
+#[derive(Debug, Clone)]
+pub struct AmendOrder {
+    /// Instrument.
+    pub instrument: Str,
+    /// Id.
+    pub id: OrderId,
+    /// New size (signed, the same as [`Place::size`]).
+    pub new_size: Option<Decimal>,
+    /// New price.
+    pub new_price: Option<Decimal>,
+}
+
+impl AmendOrder {
+    /// Create a new [`AmendOrder`] request.
+    pub fn new(
+        inst: impl AsRef<str>,
+        id: OrderId,
+        new_size: Option<Decimal>,
+        new_price: Option<Decimal>,
+    ) -> Self {
+        Self {
+            instrument: Str::new(inst),
+            id,
+            new_size,
+            new_price,
+        }
+    }
+}
+
+/// Amend order response.
+#[derive(Clone)]
+pub struct Amended {
+    /// Order id (may differ from the original id if the exchange replaces the order).
+    pub id: OrderId,
+    /// The updated order.
+    pub order: Option<Order>,
+    /// Timestamp.
+    pub ts: OffsetDateTime,
+}
+
+impl fmt::Debug for Amended {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Amended")
+            .field("ts", &self.ts.to_string())
+            .field("id", &self.id.as_str())
+            .field("order", &self.order)
+            .finish()
+    }
+}
+
+impl Request for AmendOrder {
+    type Response = BoxFuture<'static, Result<Amended, ExchangeError>>;
+}
+
 /// Get order.
 #[derive(Debug, Clone)]
 pub struct GetOrder {
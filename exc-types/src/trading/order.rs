@@ -6,9 +6,10 @@ use rust_decimal::Decimal;
 use super::place::Place;
 
 /// Time in force.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum TimeInForce {
     /// Good-Til-Cancelled.
+    #[default]
     GoodTilCancelled,
     /// Fill-Or-Kill.
     FillOrKill,
@@ -16,9 +17,40 @@ pub enum TimeInForce {
     ImmediateOrCancel,
 }
 
-impl Default for TimeInForce {
-    fn default() -> Self {
-        Self::GoodTilCancelled
+/// The price that a trigger watches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TriggerSource {
+    /// Last traded price.
+    #[default]
+    Last,
+    /// Mark price.
+    Mark,
+    /// Index price.
+    Index,
+}
+
+/// Trigger of a conditional order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Trigger {
+    /// Trigger price.
+    pub price: Decimal,
+    /// Trigger source.
+    pub source: TriggerSource,
+}
+
+impl Trigger {
+    /// Create a new trigger watching the last traded price.
+    pub fn new(price: Decimal) -> Self {
+        Self {
+            price,
+            source: TriggerSource::default(),
+        }
+    }
+
+    /// Set the trigger source.
+    pub fn with_source(mut self, source: TriggerSource) -> Self {
+        self.source = source;
+        self
     }
 }
 
@@ -31,11 +63,35 @@ pub enum OrderKind {
     Limit(Decimal, TimeInForce),
     /// Post-Only.
     PostOnly(Decimal),
+    /// Stop-Market: place a market order once triggered.
+    StopMarket(Trigger),
+    /// Stop-Limit: place a limit order with the given price once triggered.
+    StopLimit(Trigger, Decimal),
+    /// Take-Profit: place a market order once triggered.
+    TakeProfit(Trigger),
+    /// Trailing-Stop: place a market order once the price retraces by the callback rate
+    /// (e.g. `0.01` for 1%) after the optional activation.
+    TrailingStop(Option<Trigger>, Decimal),
+}
+
+impl OrderKind {
+    /// Is conditional, i.e. waits for a trigger.
+    pub fn is_conditional(&self) -> bool {
+        matches!(
+            self,
+            Self::StopMarket(_)
+                | Self::StopLimit(_, _)
+                | Self::TakeProfit(_)
+                | Self::TrailingStop(_, _)
+        )
+    }
 }
 
 /// Order Status.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum OrderStatus {
+    /// Conditional order waiting for the trigger.
+    Untriggered,
     /// Pending.
     Pending,
     /// Finished.
@@ -68,6 +124,18 @@ impl Default for OrderState {
     }
 }
 
+/// Position side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PositionSide {
+    /// Long (hedge mode).
+    Long,
+    /// Short (hedge mode).
+    Short,
+    /// Net (one-way mode).
+    #[default]
+    Net,
+}
+
 /// Order.
 #[derive(Debug, Clone)]
 pub struct Order {
@@ -79,6 +147,8 @@ pub struct Order {
     pub state: OrderState,
     /// Trade.
     pub trade: Option<OrderTrade>,
+    /// Position side.
+    pub position_side: PositionSide,
 }
 
 impl Order {
@@ -89,6 +159,7 @@ impl Order {
             target,
             state: OrderState::default(),
             trade: None,
+            position_side: PositionSide::default(),
         }
     }
 
@@ -97,10 +168,16 @@ impl Order {
         self.state = state;
         self
     }
+
+    /// Change the position side.
+    pub fn with_position_side(&mut self, side: PositionSide) -> &mut Self {
+        self.position_side = side;
+        self
+    }
 }
 
 /// Order identity.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct OrderId {
     inner: Str,
 }
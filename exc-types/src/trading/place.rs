@@ -1,4 +1,7 @@
-use super::{order::TimeInForce, OrderKind, PlaceOrder, PlaceOrderOptions};
+use super::{
+    order::{TimeInForce, Trigger},
+    OrderKind, PlaceOrder, PlaceOrderOptions,
+};
 use rust_decimal::Decimal;
 
 /// A [`Place`] describes how exchange build an order, i.e. the order builder.
@@ -38,6 +41,31 @@ impl Place {
         self
     }
 
+    /// Convert to a stop-market order.
+    pub fn stop_market(mut self, trigger: Trigger) -> Self {
+        self.kind = OrderKind::StopMarket(trigger);
+        self
+    }
+
+    /// Convert to a stop-limit order.
+    pub fn stop_limit(mut self, trigger: Trigger, price: Decimal) -> Self {
+        self.kind = OrderKind::StopLimit(trigger, price);
+        self
+    }
+
+    /// Convert to a take-profit (market) order.
+    pub fn take_profit(mut self, trigger: Trigger) -> Self {
+        self.kind = OrderKind::TakeProfit(trigger);
+        self
+    }
+
+    /// Convert to a trailing-stop (market) order.
+    /// The `callback_rate` is a ratio, e.g. `0.01` for 1%.
+    pub fn trailing_stop(mut self, activation: Option<Trigger>, callback_rate: Decimal) -> Self {
+        self.kind = OrderKind::TrailingStop(activation, callback_rate);
+        self
+    }
+
     /// Build the [`PlaceOrder`] request.
     pub fn into_request(self, opts: &PlaceOrderOptions) -> PlaceOrder {
         PlaceOrder::new(self, opts)
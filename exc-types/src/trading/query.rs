@@ -0,0 +1,121 @@
+use std::ops::{Bound, RangeBounds};
+
+use exc_service::{ExchangeError, Request};
+use futures::future::BoxFuture;
+use time::OffsetDateTime;
+
+use crate::Str;
+
+use super::{OrderStream, OrderUpdate};
+
+/// Fetch the open orders.
+#[derive(Debug, Clone, Default)]
+pub struct FetchOpenOrders {
+    /// Instrument (all instruments if `None`).
+    pub instrument: Option<Str>,
+}
+
+impl FetchOpenOrders {
+    /// Fetch the open orders of all instruments.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fetch the open orders of the given instrument.
+    pub fn with_instrument(inst: impl AsRef<str>) -> Self {
+        Self {
+            instrument: Some(Str::new(inst)),
+        }
+    }
+}
+
+impl Request for FetchOpenOrders {
+    type Response = OrderStream;
+}
+
+/// Fetch the orders created in range.
+/// Return a stream that produce the orders backward.
+#[derive(Debug, Clone)]
+pub struct FetchOrderHistory {
+    /// Instrument.
+    pub instrument: Str,
+    /// Start.
+    pub start: Bound<OffsetDateTime>,
+    /// End.
+    pub end: Bound<OffsetDateTime>,
+}
+
+impl FetchOrderHistory {
+    /// Create a new query.
+    pub fn new<R>(inst: impl AsRef<str>, range: R) -> Self
+    where
+        R: RangeBounds<OffsetDateTime>,
+    {
+        Self {
+            instrument: Str::new(inst),
+            start: range.start_bound().cloned(),
+            end: range.end_bound().cloned(),
+        }
+    }
+
+    /// Is empty.
+    pub fn is_empty(&self) -> bool {
+        match (self.start_bound(), self.end_bound()) {
+            (Bound::Unbounded, _) => false,
+            (_, Bound::Unbounded) => false,
+            (Bound::Included(start), Bound::Included(end)) => *start > *end,
+            (Bound::Included(start), Bound::Excluded(end)) => *start >= *end,
+            (Bound::Excluded(start), Bound::Included(end)) => *start >= *end,
+            (Bound::Excluded(start), Bound::Excluded(end)) => *start >= *end,
+        }
+    }
+}
+
+impl RangeBounds<OffsetDateTime> for FetchOrderHistory {
+    fn start_bound(&self) -> Bound<&OffsetDateTime> {
+        self.start.as_ref()
+    }
+
+    fn end_bound(&self) -> Bound<&OffsetDateTime> {
+        self.end.as_ref()
+    }
+}
+
+impl Request for FetchOrderHistory {
+    type Response = OrderStream;
+}
+
+/// Fetch the last `n` orders created in range.
+#[derive(Debug, Clone)]
+pub struct FetchLastOrders {
+    /// Query.
+    pub query: FetchOrderHistory,
+    /// Last.
+    pub last: usize,
+}
+
+impl FetchLastOrders {
+    /// Create a new query.
+    pub fn new<R>(inst: impl AsRef<str>, range: R, last: usize) -> Self
+    where
+        R: RangeBounds<OffsetDateTime>,
+    {
+        Self {
+            query: FetchOrderHistory::new(inst, range),
+            last,
+        }
+    }
+}
+
+/// A page of orders.
+#[derive(Debug, Default)]
+pub struct OrdersPage {
+    /// Orders, newest first.
+    pub orders: Vec<Result<OrderUpdate, ExchangeError>>,
+    /// Creation time of the oldest order in the page.
+    pub oldest: Option<OffsetDateTime>,
+}
+
+impl Request for FetchLastOrders {
+    type Response = BoxFuture<'static, Result<OrdersPage, ExchangeError>>;
+}
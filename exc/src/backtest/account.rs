@@ -0,0 +1,161 @@
+use std::collections::BTreeMap;
+
+use exc_core::{
+    types::{MarginMode, OrderTrade, Position},
+    Str,
+};
+use rust_decimal::Decimal;
+use time::OffsetDateTime;
+
+/// The state of the simulated account at a tick.
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    /// Timestamp.
+    pub ts: OffsetDateTime,
+    /// Cash, i.e. the initial cash with the realized profit and loss and the fees.
+    pub cash: Decimal,
+    /// Equity, i.e. the cash with the unrealized profit and loss.
+    pub equity: Decimal,
+    /// The non-empty positions.
+    pub positions: Vec<Position>,
+}
+
+#[derive(Debug, Default)]
+struct Holding {
+    size: Decimal,
+    entry: Decimal,
+    last: Decimal,
+    unit: Decimal,
+}
+
+impl Holding {
+    fn unrealized(&self) -> Decimal {
+        self.size * self.unit * (self.last - self.entry)
+    }
+}
+
+/// Account of linear instruments, valued in the quote currency.
+#[derive(Debug, Default)]
+pub(super) struct Account {
+    cash: Decimal,
+    units: BTreeMap<Str, Decimal>,
+    holdings: BTreeMap<Str, Holding>,
+}
+
+impl Account {
+    pub(super) fn set_cash(&mut self, cash: Decimal) {
+        self.cash = cash;
+    }
+
+    pub(super) fn set_unit(&mut self, inst: &Str, unit: Decimal) {
+        self.units.insert(inst.clone(), unit);
+    }
+
+    fn holding(&mut self, inst: &Str) -> &mut Holding {
+        let unit = self.units.get(inst).copied().unwrap_or(Decimal::ONE);
+        self.holdings
+            .entry(inst.clone())
+            .or_insert_with(|| Holding {
+                unit,
+                ..Default::default()
+            })
+    }
+
+    /// Apply a fill.
+    pub(super) fn fill(&mut self, inst: &Str, trade: &OrderTrade) {
+        let mut realized = trade.fee;
+        let holding = self.holding(inst);
+        let size = trade.size;
+        if holding.size.is_zero() || holding.size.is_sign_positive() == size.is_sign_positive() {
+            let total = holding.size + size;
+            holding.entry =
+                (holding.entry * holding.size.abs() + trade.price * size.abs()) / total.abs();
+            holding.size = total;
+        } else {
+            let closed = size.abs().min(holding.size.abs());
+            let closed = if holding.size.is_sign_positive() {
+                closed
+            } else {
+                -closed
+            };
+            realized += closed * holding.unit * (trade.price - holding.entry);
+            holding.size += size;
+            if holding.size.is_zero() {
+                holding.entry = Decimal::ZERO;
+            } else if holding.size.is_sign_positive() == size.is_sign_positive() {
+                // The position is reversed.
+                holding.entry = trade.price;
+            }
+        }
+        holding.last = trade.price;
+        self.cash += realized;
+    }
+
+    /// Mark the holding of the instrument to the price.
+    pub(super) fn mark(&mut self, inst: &Str, price: Decimal) {
+        if let Some(holding) = self.holdings.get_mut(inst) {
+            holding.last = price;
+        }
+    }
+
+    pub(super) fn snapshot(&self, ts: OffsetDateTime) -> Snapshot {
+        let unrealized = self
+            .holdings
+            .values()
+            .map(Holding::unrealized)
+            .sum::<Decimal>();
+        Snapshot {
+            ts,
+            cash: self.cash,
+            equity: self.cash + unrealized,
+            positions: self
+                .holdings
+                .iter()
+                .filter(|(_, holding)| !holding.size.is_zero())
+                .map(|(inst, holding)| Position {
+                    ts,
+                    instrument: inst.clone(),
+                    size: holding.size,
+                    entry_price: holding.entry,
+                    mark_price: Some(holding.last),
+                    unrealized_pnl: holding.unrealized(),
+                    liquidation_price: None,
+                    margin_mode: MarginMode::Cross,
+                    leverage: None,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use time::macros::datetime;
+
+    fn trade(price: i64, size: i64) -> OrderTrade {
+        OrderTrade {
+            price: Decimal::from(price),
+            size: Decimal::from(size),
+            fee: Decimal::new(-1, 1),
+            fee_asset: None,
+        }
+    }
+
+    #[test]
+    fn test_account() {
+        let inst = Str::new("BTC-USDT");
+        let mut account = Account::default();
+        account.set_cash(Decimal::from(1000));
+        account.fill(&inst, &trade(100, 2));
+        account.fill(&inst, &trade(110, -3));
+        account.mark(&inst, Decimal::from(105));
+        let snapshot = account.snapshot(datetime!(2023-01-01 00:00:00 +00:00));
+        // Realized 2 * (110 - 100) = 20 with fees 0.2, and unrealized -1 * (105 - 110) = 5.
+        assert_eq!(snapshot.cash, Decimal::new(10198, 1));
+        assert_eq!(snapshot.equity, Decimal::new(10248, 1));
+        assert_eq!(snapshot.positions.len(), 1);
+        assert_eq!(snapshot.positions[0].size, Decimal::from(-1));
+        assert_eq!(snapshot.positions[0].entry_price, Decimal::from(110));
+    }
+}
@@ -0,0 +1,587 @@
+use std::{
+    collections::HashMap,
+    ops::RangeBounds,
+    path::Path,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+use async_stream::try_stream;
+use exc_core::{
+    exchange::{
+        MakeCancelOrderOptions, MakeCheckOrderOptions, MakeExchange, MakeFetchCandlesOptions,
+        MakeInstrumentsOptions, MakePlaceOrderOptions, MakeSubscribeOrdersOptions,
+        MakeTickersOptions,
+    },
+    service::{BoxCloneExcService, BoxExcService},
+    types::{
+        CancelOrder, Canceled, Candle, CandleStream, GetOrder, InstrumentMeta, InstrumentStream,
+        OrderStream, OrderUpdate, Period, PlaceOrder, Placed, QueryCandles, SubscribeInstruments,
+        SubscribeOrders, SubscribeTickers, SubscribeTrades, Ticker, TickerStream, Trade,
+        TradeStream,
+    },
+    util::period_bounds,
+    ExcServiceExt, ExchangeError, InstrumentError, Request, Str,
+};
+use futures::{
+    future::{ready, Ready},
+    stream::{self, BoxStream},
+    FutureExt, StreamExt, TryFutureExt, TryStreamExt,
+};
+use rust_decimal::Decimal;
+use time::OffsetDateTime;
+use tower::{Service, ServiceExt};
+
+use crate::{
+    paper::{venue::Venue, Fees, MarketEvent},
+    record::{Replay, ReplayClock},
+};
+
+use self::account::Account;
+
+pub use self::account::Snapshot;
+
+mod account;
+
+/// The assumption of the price path inside a candle.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum IntraBar {
+    /// Visit the nearer one of the high and the low first.
+    #[default]
+    NearestFirst,
+    /// Open, high, low and then close.
+    OpenHighLowClose,
+    /// Open, low, high and then close.
+    OpenLowHighClose,
+}
+
+impl IntraBar {
+    fn path(&self, candle: &Candle) -> [Decimal; 4] {
+        let high_first = match self {
+            Self::NearestFirst => candle.high - candle.open <= candle.open - candle.low,
+            Self::OpenHighLowClose => true,
+            Self::OpenLowHighClose => false,
+        };
+        if high_first {
+            [candle.open, candle.high, candle.low, candle.close]
+        } else {
+            [candle.open, candle.low, candle.high, candle.close]
+        }
+    }
+}
+
+/// Historical market data of an instrument.
+enum Source {
+    Candles(Period, CandleStream),
+    Trades(TradeStream),
+}
+
+/// A step of the replay.
+struct Step {
+    trade: Trade,
+    /// The candle closed by this step.
+    closed: Option<Candle>,
+}
+
+type StepStream = BoxStream<'static, Result<Step, ExchangeError>>;
+
+impl Source {
+    fn into_steps(self, intra_bar: IntraBar) -> StepStream {
+        match self {
+            Self::Trades(trades) => trades
+                .map_ok(|trade| Step {
+                    trade,
+                    closed: None,
+                })
+                .boxed(),
+            Self::Candles(period, candles) => {
+                let forward = candles.is_forward();
+                try_stream! {
+                    let candles = if forward {
+                        candles.boxed()
+                    } else {
+                        let mut candles: Vec<_> = candles.try_collect().await?;
+                        candles.reverse();
+                        stream::iter(candles.into_iter().map(Ok)).boxed()
+                    };
+                    let mut last = None;
+                    for await candle in candles {
+                        let candle = candle?;
+                        let (start, end) = period_bounds(&period, candle.ts)?;
+                        let path = intra_bar.path(&candle);
+                        let mut closed = Some(candle);
+                        for (idx, price) in path.into_iter().enumerate() {
+                            // Open, the first extremum and the second extremum at the first three
+                            // thirds of the period, and close at the end of the period.
+                            let ts = start + (end - start) * (idx as f64 / 3.0);
+                            let buy = last.map_or(true, |last| price >= last);
+                            last = Some(price);
+                            yield Step {
+                                trade: Trade {
+                                    ts,
+                                    price,
+                                    // Unknown size.
+                                    size: Decimal::ZERO,
+                                    buy,
+                                },
+                                closed: if idx == 3 { closed.take() } else { None },
+                            };
+                        }
+                    }
+                }
+                .boxed()
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct Shared {
+    venue: Venue,
+    intra_bar: IntraBar,
+    clock: Option<OffsetDateTime>,
+    metas: Vec<InstrumentMeta<Decimal>>,
+    sources: HashMap<Str, Source>,
+    candles: HashMap<Str, (Period, Vec<Candle>)>,
+    account: Account,
+    snapshots: Vec<Snapshot>,
+}
+
+impl Shared {
+    fn now(&self) -> OffsetDateTime {
+        self.clock.unwrap_or(OffsetDateTime::UNIX_EPOCH)
+    }
+
+    fn apply(&mut self, inst: &Str, updates: &[OrderUpdate]) {
+        for update in updates {
+            if let Some(trade) = update.order.trade.as_ref() {
+                self.account.fill(inst, trade);
+            }
+        }
+    }
+
+    fn step(&mut self, inst: &Str, step: Step) -> Ticker {
+        let Step { trade, closed } = step;
+        let ts = trade.ts;
+        self.clock = Some(self.clock.map_or(ts, |clock| clock.max(ts)));
+        let updates = self.venue.on_event(inst, MarketEvent::Trade(trade));
+        self.apply(inst, &updates);
+        self.account.mark(inst, trade.price);
+        if let Some(candle) = closed {
+            if let Some((_, candles)) = self.candles.get_mut(inst) {
+                candles.push(candle);
+            }
+        }
+        self.snapshots.push(self.account.snapshot(ts));
+        Ticker {
+            ts,
+            last: trade.price,
+            size: trade.size,
+            buy: Some(trade.buy),
+            bid: None,
+            bid_size: None,
+            ask: None,
+            ask_size: None,
+        }
+    }
+}
+
+/// Backtesting exchange.
+///
+/// Replay the historical candles and trades of the instruments through the tickers,
+/// and match the orders against them with a virtual clock.
+/// - Each ticker is yielded after the orders placed before it have been matched against it,
+///   so the clock advances as the tickers are consumed.
+/// - A candle is replayed as four trades of unknown size following the [`IntraBar`] assumption,
+///   the last of which (the close) is at the end of the period.
+/// - Only the candles closed before the clock can be queried.
+/// - See [`Paper`](crate::paper::Paper) for how the orders are matched.
+///
+/// The account is valued in the quote currency as if all instruments were linear,
+/// and a [`Snapshot`] is taken at each tick.
+#[derive(Clone, Default)]
+pub struct Backtest {
+    shared: Arc<Mutex<Shared>>,
+}
+
+impl Backtest {
+    /// Create a new backtesting exchange.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn shared(&self) -> std::sync::MutexGuard<'_, Shared> {
+        self.shared.lock().unwrap()
+    }
+
+    /// Set the fee rates. Default to zero.
+    pub fn fees(&mut self, fees: Fees) -> &mut Self {
+        self.shared().venue.engine().set_fees(fees);
+        self
+    }
+
+    /// Set the initial cash. Default to zero.
+    pub fn cash(&mut self, cash: Decimal) -> &mut Self {
+        self.shared().account.set_cash(cash);
+        self
+    }
+
+    /// Set the price path assumption of the candles. Default to [`IntraBar::NearestFirst`].
+    pub fn intra_bar(&mut self, intra_bar: IntraBar) -> &mut Self {
+        self.shared().intra_bar = intra_bar;
+        self
+    }
+
+    /// Add the meta of an instrument.
+    pub fn instrument(&mut self, meta: InstrumentMeta<Decimal>) -> &mut Self {
+        {
+            let mut shared = self.shared();
+            shared.account.set_unit(meta.smol_name(), meta.attrs().unit);
+            shared.venue.engine().insert_meta(meta.clone());
+            shared.metas.push(meta);
+        }
+        self
+    }
+
+    /// Replay the candles of the period for the instrument.
+    pub fn candles(
+        &mut self,
+        inst: impl AsRef<str>,
+        period: Period,
+        candles: CandleStream,
+    ) -> &mut Self {
+        let inst = Str::new(inst);
+        {
+            let mut shared = self.shared();
+            shared.candles.insert(inst.clone(), (period, Vec::new()));
+            shared
+                .sources
+                .insert(inst, Source::Candles(period, candles));
+        }
+        self
+    }
+
+    /// Replay the trades for the instrument.
+    pub fn trades(&mut self, inst: impl AsRef<str>, trades: TradeStream) -> &mut Self {
+        let inst = Str::new(inst);
+        {
+            let mut shared = self.shared();
+            shared.candles.remove(&inst);
+            shared.sources.insert(inst, Source::Trades(trades));
+        }
+        self
+    }
+
+    /// Replay the trades of the instrument recorded under the `root` directory
+    /// (see [`RecordLayer`](crate::record::RecordLayer)).
+    pub fn recorded_trades(&mut self, inst: impl AsRef<str>, root: impl AsRef<Path>) -> &mut Self {
+        let mut replay = Replay::new(root);
+        replay.clock(ReplayClock::AsFastAsPossible);
+        let trades = replay
+            .oneshot(SubscribeTrades::new(inst.as_ref()))
+            .try_flatten_stream()
+            .boxed();
+        self.trades(inst, trades)
+    }
+
+    /// Get the virtual clock, i.e. the time of the latest tick.
+    pub fn now(&self) -> Option<OffsetDateTime> {
+        self.shared().clock
+    }
+
+    /// Get the snapshots of the account taken at each tick.
+    pub fn snapshots(&self) -> Vec<Snapshot> {
+        self.shared().snapshots.clone()
+    }
+}
+
+impl Service<SubscribeTickers> for Backtest {
+    type Response = TickerStream;
+    type Error = ExchangeError;
+    type Future = Ready<Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: SubscribeTickers) -> Self::Future {
+        let inst = req.instrument;
+        let (source, intra_bar) = {
+            let mut shared = self.shared();
+            (shared.sources.remove(&inst), shared.intra_bar)
+        };
+        let Some(source) = source else {
+            return ready(Err(ExchangeError::Instrument(InstrumentError::NotFound)));
+        };
+        let shared = self.shared.clone();
+        let stream = source
+            .into_steps(intra_bar)
+            .map_ok(move |step| shared.lock().unwrap().step(&inst, step));
+        ready(Ok(stream.boxed()))
+    }
+}
+
+impl Service<SubscribeInstruments> for Backtest {
+    type Response = InstrumentStream;
+    type Error = ExchangeError;
+    type Future = Ready<Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, _req: SubscribeInstruments) -> Self::Future {
+        let metas = self.shared().metas.clone();
+        ready(Ok(stream::iter(metas.into_iter().map(Ok)).boxed()))
+    }
+}
+
+impl Service<QueryCandles> for Backtest {
+    type Response = CandleStream;
+    type Error = ExchangeError;
+    type Future = Ready<Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, query: QueryCandles) -> Self::Future {
+        let shared = self.shared();
+        let Some((period, candles)) = shared.candles.get(query.inst()) else {
+            return ready(Err(ExchangeError::Instrument(InstrumentError::NotFound)));
+        };
+        if *period != query.period() {
+            return ready(Err(ExchangeError::Unsupported(anyhow::anyhow!(
+                "only the candles of {period} are available for {}",
+                query.inst()
+            ))));
+        }
+        let candles = candles
+            .iter()
+            .filter(|candle| query.contains(&candle.ts))
+            .cloned()
+            .collect::<Vec<_>>();
+        ready(Ok(CandleStream::new_forward(stream::iter(
+            candles.into_iter().map(Ok),
+        ))))
+    }
+}
+
+impl Service<PlaceOrder> for Backtest {
+    type Response = <PlaceOrder as Request>::Response;
+    type Error = ExchangeError;
+    type Future = Ready<Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: PlaceOrder) -> Self::Future {
+        let mut shared = self.shared();
+        let ts = shared.now();
+        let res = shared.venue.place(&req, ts).map(|mut updates| {
+            shared.apply(&Str::new(req.opts.instrument()), &updates);
+            let order = updates.pop().expect("must have the placed order").order;
+            Placed {
+                id: order.id.clone(),
+                order: Some(order),
+                ts,
+            }
+        });
+        ready(Ok(ready(res).boxed()))
+    }
+}
+
+impl Service<CancelOrder> for Backtest {
+    type Response = <CancelOrder as Request>::Response;
+    type Error = ExchangeError;
+    type Future = Ready<Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: CancelOrder) -> Self::Future {
+        let shared = self.shared();
+        let ts = shared.now();
+        let res = shared
+            .venue
+            .cancel(&req.instrument, &req.id, ts)
+            .map(|order| Canceled {
+                order: Some(order),
+                ts,
+            });
+        ready(Ok(ready(res).boxed()))
+    }
+}
+
+impl Service<GetOrder> for Backtest {
+    type Response = <GetOrder as Request>::Response;
+    type Error = ExchangeError;
+    type Future = Ready<Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: GetOrder) -> Self::Future {
+        let shared = self.shared();
+        let ts = shared.now();
+        let res = shared
+            .venue
+            .get(&req.instrument, &req.id)
+            .map(|order| OrderUpdate { ts, order });
+        ready(Ok(ready(res).boxed()))
+    }
+}
+
+impl Service<SubscribeOrders> for Backtest {
+    type Response = OrderStream;
+    type Error = ExchangeError;
+    type Future = Ready<Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: SubscribeOrders) -> Self::Future {
+        ready(Ok(self.shared().venue.subscribe(req.instrument)))
+    }
+}
+
+macro_rules! make_service {
+    ($options:ty, $req:ty, $response:ty, $make:expr) => {
+        impl Service<$options> for Backtest {
+            type Response = $response;
+            type Error = ExchangeError;
+            type Future = Ready<Result<Self::Response, Self::Error>>;
+
+            fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+                Poll::Ready(Ok(()))
+            }
+
+            fn call(&mut self, _req: $options) -> Self::Future {
+                let make: fn(&Self) -> $response = $make;
+                ready(Ok(make(self)))
+            }
+        }
+    };
+    ($options:ty, $req:ty) => {
+        make_service!($options, $req, BoxCloneExcService<$req>, |svc| {
+            ExcServiceExt::<$req>::boxed_clone(svc)
+        });
+    };
+}
+
+make_service!(MakeInstrumentsOptions, SubscribeInstruments);
+make_service!(MakeTickersOptions, SubscribeTickers);
+make_service!(MakePlaceOrderOptions, PlaceOrder);
+make_service!(MakeCancelOrderOptions, CancelOrder);
+make_service!(MakeCheckOrderOptions, GetOrder);
+make_service!(MakeSubscribeOrdersOptions, SubscribeOrders);
+make_service!(
+    MakeFetchCandlesOptions,
+    QueryCandles,
+    BoxExcService<QueryCandles>,
+    |svc| ExcServiceExt::<QueryCandles>::boxed(svc.clone())
+);
+
+impl MakeExchange for Backtest {
+    fn name(&self) -> &str {
+        "backtest"
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use exc_core::{
+        exchange::MakePlaceOrder,
+        types::{OrderStatus, Place, PlaceOrderOptions},
+        ExcService,
+    };
+    use time::macros::{datetime, offset};
+
+    fn candle(ts: OffsetDateTime, [open, high, low, close]: [i64; 4]) -> Candle {
+        Candle {
+            ts,
+            open: Decimal::from(open),
+            high: Decimal::from(high),
+            low: Decimal::from(low),
+            close: Decimal::from(close),
+            volume: Decimal::ONE,
+        }
+    }
+
+    async fn make_place<M: MakeExchange>(
+        make: &mut M,
+    ) -> Result<<M as MakePlaceOrder>::Service, ExchangeError> {
+        make.as_make_place_order_service()
+            .oneshot(MakePlaceOrderOptions::default())
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_backtest() -> anyhow::Result<()> {
+        let t0 = datetime!(2023-01-01 00:00:00 +00:00);
+        let hour = time::Duration::HOUR;
+        let period = Period::hours(offset!(+0), 1);
+        let candles = vec![
+            candle(t0, [100, 102, 99, 101]),
+            candle(t0 + hour, [101, 103, 96, 97]),
+            candle(t0 + 2 * hour, [97, 99, 95, 98]),
+        ];
+        let mut backtest = Backtest::new();
+        backtest.cash(Decimal::from(1000)).candles(
+            "BTC-USDT",
+            period,
+            CandleStream::new_forward(stream::iter(candles.into_iter().map(Ok))),
+        );
+        let mut place = make_place(&mut backtest).await?;
+        let mut tickers = ServiceExt::<SubscribeTickers>::oneshot(
+            backtest.clone(),
+            SubscribeTickers::new("BTC-USDT"),
+        )
+        .await?;
+
+        // Consume the first candle.
+        for _ in 0..4 {
+            tickers.try_next().await?;
+        }
+        assert_eq!(backtest.now(), Some(t0 + hour));
+        let candles: Vec<_> = ServiceExt::<QueryCandles>::oneshot(
+            backtest.clone(),
+            QueryCandles::new("BTC-USDT", period, ..),
+        )
+        .await?
+        .try_collect()
+        .await?;
+        assert_eq!(candles.len(), 1);
+
+        let opts = PlaceOrderOptions::new("BTC-USDT");
+        let placed = ExcService::<PlaceOrder>::as_service(&mut place)
+            .oneshot(
+                Place::with_size(Decimal::ONE)
+                    .limit(Decimal::from(98))
+                    .into_request(&opts),
+            )
+            .await?
+            .await?;
+        assert_eq!(placed.order.unwrap().state.status, OrderStatus::Pending);
+        // The second candle visits the high first and then goes through the limit price.
+        while tickers.try_next().await?.is_some() {}
+        let order =
+            ServiceExt::<GetOrder>::oneshot(backtest.clone(), GetOrder::new("BTC-USDT", placed.id))
+                .await?
+                .await?
+                .order;
+        assert_eq!(order.state.filled, Decimal::ONE);
+        assert_eq!(order.state.cost, Decimal::from(98));
+
+        let last = backtest.snapshots().pop().unwrap();
+        assert_eq!(last.ts, t0 + 3 * hour);
+        assert_eq!(last.positions.len(), 1);
+        assert_eq!(last.equity, Decimal::from(1000));
+        Ok(())
+    }
+}
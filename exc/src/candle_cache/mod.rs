@@ -0,0 +1,186 @@
+use std::{
+    collections::HashMap,
+    ops::{Bound, RangeBounds},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+use exc_core::{
+    types::{Candle, CandleStream, QueryCandles},
+    util::period_bounds,
+    ExcService, ExcServiceExt, ExchangeError, IntoService,
+};
+use futures::{future::BoxFuture, FutureExt, TryStreamExt};
+use time::OffsetDateTime;
+use tower::{buffer::Buffer, Layer, Service, ServiceExt};
+
+use self::store::{cache_file, Entry};
+
+mod store;
+
+const DEFAULT_BOUND: usize = 64;
+
+type Entries = HashMap<PathBuf, Arc<tokio::sync::Mutex<Option<Entry>>>>;
+
+/// Candle cache layer.
+#[derive(Debug, Clone)]
+pub struct CandleCacheLayer {
+    root: PathBuf,
+    bound: usize,
+}
+
+impl CandleCacheLayer {
+    /// Create a new candle cache layer that stores the candles under the `root` directory.
+    pub fn new(root: impl AsRef<Path>) -> Self {
+        Self {
+            root: root.as_ref().to_path_buf(),
+            bound: DEFAULT_BOUND + 1,
+        }
+    }
+
+    /// Set the buffer bound of the inner service. Default to `64`.
+    pub fn bound(&mut self, bound: usize) -> &mut Self {
+        self.bound = bound + 1;
+        self
+    }
+}
+
+impl<S> Layer<S> for CandleCacheLayer
+where
+    S: ExcService<QueryCandles> + Send + 'static,
+    S::Future: Send,
+{
+    type Service = CandleCache<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        CandleCache {
+            svc: Buffer::new(inner.into_service(), self.bound),
+            root: Arc::new(self.root.clone()),
+            entries: Arc::default(),
+        }
+    }
+}
+
+/// Candle cache.
+///
+/// The closed candles are persisted per instrument, period and source,
+/// so only the ranges that have never been fetched are queried from the inner service.
+/// The still-open latest candle is always fetched and never cached,
+/// and queries without a start bound bypass the cache.
+pub struct CandleCache<S>
+where
+    S: ExcService<QueryCandles> + 'static,
+{
+    svc: Buffer<IntoService<S, QueryCandles>, QueryCandles>,
+    root: Arc<PathBuf>,
+    entries: Arc<Mutex<Entries>>,
+}
+
+impl<S> Clone for CandleCache<S>
+where
+    S: ExcService<QueryCandles> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            svc: self.svc.clone(),
+            root: self.root.clone(),
+            entries: self.entries.clone(),
+        }
+    }
+}
+
+async fn fetch(
+    svc: &mut Buffer<
+        IntoService<impl ExcService<QueryCandles> + 'static, QueryCandles>,
+        QueryCandles,
+    >,
+    query: QueryCandles,
+) -> Result<Vec<Candle>, ExchangeError> {
+    tracing::trace!("candle cache: fetch {query}");
+    let stream = svc.oneshot(query).await.map_err(ExchangeError::Layer)?;
+    let mut candles: Vec<_> = stream.try_collect().await?;
+    candles.sort_by_key(|c| c.ts);
+    Ok(candles)
+}
+
+impl<S> Service<QueryCandles> for CandleCache<S>
+where
+    S: ExcService<QueryCandles> + 'static,
+    S::Future: Send,
+{
+    type Response = CandleStream;
+    type Error = ExchangeError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Service::poll_ready(&mut self.svc, cx).map_err(ExchangeError::from)
+    }
+
+    fn call(&mut self, query: QueryCandles) -> Self::Future {
+        let mut svc = self.svc.clone();
+        let start = match query.start_bound() {
+            Bound::Included(start) => *start,
+            Bound::Excluded(start) => *start + time::Duration::NANOSECOND,
+            Bound::Unbounded => {
+                return async move {
+                    let candles = fetch(&mut svc, query).await?;
+                    Ok(CandleStream::new_forward(futures::stream::iter(
+                        candles.into_iter().map(Ok),
+                    )))
+                }
+                .boxed()
+            }
+        };
+        let end = match query.end_bound() {
+            Bound::Included(end) => Some(*end + time::Duration::NANOSECOND),
+            Bound::Excluded(end) => Some(*end),
+            Bound::Unbounded => None,
+        };
+        let path = cache_file(&self.root, query.inst(), &query.period(), query.source());
+        let entry = self
+            .entries
+            .lock()
+            .unwrap()
+            .entry(path.clone())
+            .or_default()
+            .clone();
+        async move {
+            let period = query.period();
+            let now = OffsetDateTime::now_utc().to_offset(period.utc_offset());
+            // The start of the still-open candle.
+            let (open, _) = period_bounds(&period, now)?;
+            let cached_end = end.map_or(open, |end| end.min(open));
+            let mut candles = if start < cached_end {
+                let mut entry = entry.lock().await;
+                if entry.is_none() {
+                    *entry = Some(Entry::load(path).await?);
+                }
+                let entry = entry.as_mut().unwrap();
+                for (gap_start, gap_end) in entry.gaps(start, cached_end) {
+                    let gap = QueryCandles::new(query.inst(), period, gap_start..gap_end)
+                        .with_source(query.source());
+                    let mut fetched = fetch(&mut svc, gap).await?;
+                    fetched.retain(|c| gap_start <= c.ts && c.ts < gap_end);
+                    entry.insert(gap_start, gap_end, fetched).await?;
+                }
+                entry.candles(start, cached_end)
+            } else {
+                Vec::new()
+            };
+            if end.map_or(true, |end| open < end) {
+                let tail = QueryCandles::new(
+                    query.inst(),
+                    period,
+                    (Bound::Included(open.max(start)), query.end),
+                )
+                .with_source(query.source());
+                candles.extend(fetch(&mut svc, tail).await?);
+            }
+            Ok(CandleStream::new_forward(futures::stream::iter(
+                candles.into_iter().map(Ok),
+            )))
+        }
+        .boxed()
+    }
+}
@@ -0,0 +1,226 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use exc_core::{
+    types::{Candle, CandleSource, Period},
+    ExchangeError,
+};
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use tokio::io::AsyncWriteExt;
+
+use crate::fs::sanitize;
+
+/// A record of the cache file.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum Record {
+    /// A closed candle.
+    Candle(Candle),
+    /// All the candles starting in `[start, end)` have been fetched.
+    Covered {
+        #[serde(with = "time::serde::rfc3339")]
+        start: OffsetDateTime,
+        #[serde(with = "time::serde::rfc3339")]
+        end: OffsetDateTime,
+    },
+}
+
+/// Get the path of the cache file of the given instrument, period and source.
+pub(super) fn cache_file(
+    root: &Path,
+    inst: &str,
+    period: &Period,
+    source: CandleSource,
+) -> PathBuf {
+    let name = format!(
+        "{}_{}_{source}.jsonl",
+        period.kind(),
+        period.utc_offset().whole_seconds()
+    );
+    root.join(sanitize(inst)).join(sanitize(&name))
+}
+
+/// Cached candles of an instrument, period and source,
+/// backed by an append-only JSON-lines file.
+#[derive(Debug)]
+pub(super) struct Entry {
+    path: PathBuf,
+    candles: BTreeMap<OffsetDateTime, Candle>,
+    /// Sorted and disjoint covered ranges.
+    covered: Vec<(OffsetDateTime, OffsetDateTime)>,
+}
+
+impl Entry {
+    /// Load the entry from the file, or create an empty one if the file does not exist.
+    pub(super) async fn load(path: PathBuf) -> Result<Self, ExchangeError> {
+        let mut entry = Self {
+            path,
+            candles: BTreeMap::new(),
+            covered: Vec::new(),
+        };
+        let content = match tokio::fs::read_to_string(&entry.path).await {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(entry),
+            Err(err) => return Err(ExchangeError::Other(err.into())),
+        };
+        for line in content.lines().filter(|line| !line.is_empty()) {
+            match serde_json::from_str::<Record>(line) {
+                Ok(Record::Candle(candle)) => {
+                    entry.candles.insert(candle.ts, candle);
+                }
+                Ok(Record::Covered { start, end }) => entry.cover(start, end),
+                Err(err) => {
+                    // The last write may have been interrupted.
+                    tracing::warn!("skip a broken record of {}: {err}", entry.path.display());
+                }
+            }
+        }
+        Ok(entry)
+    }
+
+    fn cover(&mut self, start: OffsetDateTime, end: OffsetDateTime) {
+        if start >= end {
+            return;
+        }
+        self.covered.push((start, end));
+        self.covered.sort();
+        let mut merged: Vec<(OffsetDateTime, OffsetDateTime)> = Vec::new();
+        for (start, end) in self.covered.drain(..) {
+            match merged.last_mut() {
+                Some(last) if start <= last.1 => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+        self.covered = merged;
+    }
+
+    /// Get the ranges in `[start, end)` that are not covered.
+    pub(super) fn gaps(
+        &self,
+        start: OffsetDateTime,
+        end: OffsetDateTime,
+    ) -> Vec<(OffsetDateTime, OffsetDateTime)> {
+        let mut gaps = Vec::new();
+        let mut current = start;
+        for &(covered_start, covered_end) in &self.covered {
+            if current >= end {
+                break;
+            }
+            if covered_end <= current {
+                continue;
+            }
+            if covered_start > current {
+                gaps.push((current, covered_start.min(end)));
+            }
+            current = current.max(covered_end);
+        }
+        if current < end {
+            gaps.push((current, end));
+        }
+        gaps
+    }
+
+    /// Get the cached candles starting in `[start, end)`.
+    pub(super) fn candles(&self, start: OffsetDateTime, end: OffsetDateTime) -> Vec<Candle> {
+        if start >= end {
+            return Vec::new();
+        }
+        self.candles
+            .range(start..end)
+            .map(|(_, c)| c.clone())
+            .collect()
+    }
+
+    /// Insert the candles fetched for `[start, end)` and persist them.
+    pub(super) async fn insert(
+        &mut self,
+        start: OffsetDateTime,
+        end: OffsetDateTime,
+        candles: Vec<Candle>,
+    ) -> Result<(), ExchangeError> {
+        let mut buf = Vec::new();
+        for candle in candles.iter() {
+            serde_json::to_writer(&mut buf, &Record::Candle(candle.clone()))
+                .map_err(|err| ExchangeError::Other(err.into()))?;
+            buf.push(b'\n');
+        }
+        // The covered record is written last, so that an interrupted write never marks a range
+        // as covered without its candles.
+        serde_json::to_writer(&mut buf, &Record::Covered { start, end })
+            .map_err(|err| ExchangeError::Other(err.into()))?;
+        buf.push(b'\n');
+        if let Some(dir) = self.path.parent() {
+            tokio::fs::create_dir_all(dir)
+                .await
+                .map_err(|err| ExchangeError::Other(err.into()))?;
+        }
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await
+            .map_err(|err| ExchangeError::Other(err.into()))?;
+        file.write_all(&buf)
+            .await
+            .map_err(|err| ExchangeError::Other(err.into()))?;
+        file.flush()
+            .await
+            .map_err(|err| ExchangeError::Other(err.into()))?;
+        for candle in candles {
+            self.candles.insert(candle.ts, candle);
+        }
+        self.cover(start, end);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rust_decimal::Decimal;
+    use time::macros::{datetime, offset};
+
+    fn candle(ts: OffsetDateTime) -> Candle {
+        Candle {
+            ts,
+            open: Decimal::ONE,
+            high: Decimal::ONE,
+            low: Decimal::ONE,
+            close: Decimal::ONE,
+            volume: Decimal::ONE,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_entry() -> anyhow::Result<()> {
+        let root = std::env::temp_dir().join(format!("exc-candle-cache-{}", std::process::id()));
+        let path = cache_file(
+            &root,
+            "BTC-USDT",
+            &Period::hours(offset!(+0), 1),
+            CandleSource::Trade,
+        );
+        let t0 = datetime!(2023-01-01 00:00:00 +00:00);
+        let hour = time::Duration::HOUR;
+        let mut entry = Entry::load(path.clone()).await?;
+        entry
+            .insert(t0, t0 + 2 * hour, vec![candle(t0), candle(t0 + hour)])
+            .await?;
+        entry.insert(t0 + 4 * hour, t0 + 5 * hour, vec![]).await?;
+
+        let entry = Entry::load(path).await?;
+        assert_eq!(
+            entry.gaps(t0 + hour, t0 + 6 * hour),
+            [
+                (t0 + 2 * hour, t0 + 4 * hour),
+                (t0 + 5 * hour, t0 + 6 * hour)
+            ]
+        );
+        assert_eq!(entry.candles(t0, t0 + 6 * hour).len(), 2);
+        tokio::fs::remove_dir_all(root).await?;
+        Ok(())
+    }
+}
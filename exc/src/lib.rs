@@ -5,6 +5,25 @@
 #[cfg(feature = "instrument")]
 pub mod instrument;
 
+/// Candle cache layer.
+#[cfg(feature = "candle-cache")]
+pub mod candle_cache;
+
+/// Record market data streams and replay them.
+#[cfg(feature = "record")]
+pub mod record;
+
+/// Paper-trading exchange.
+#[cfg(feature = "paper")]
+pub mod paper;
+
+/// Backtesting exchange.
+#[cfg(feature = "backtest")]
+pub mod backtest;
+
+#[cfg(any(feature = "candle-cache", feature = "record"))]
+mod fs;
+
 /// Types.
 pub mod types;
 
@@ -16,12 +35,25 @@ pub use self::core::{
 };
 pub use exc_core as core;
 pub use util::{
-    book::SubscribeBidAskService,
+    account::{
+        FetchBalancesService, FetchPositionsService, SubscribeBalancesService,
+        SubscribePositionsService,
+    },
+    book::{SubscribeBidAskService, SubscribeOrderBookService},
+    derivative::{
+        FetchFundingRateHistoryService, FetchOpenInterestHistoryService,
+        SubscribeFundingRateService, SubscribeIndexPriceService, SubscribeLiquidationsService,
+        SubscribeMarkPriceService, SubscribeOpenInterestService,
+    },
     fetch_candles::FetchCandlesService,
     instrument::{FetchInstrumentsService, SubscribeInstrumentsService},
+    subscribe_candles::SubscribeCandlesService,
     subscribe_tickers::SubscribeTickersService,
     trade::SubscribeTradesService,
-    trading::{CheckOrderService, SubscribeOrdersService, TradingService},
+    trading::{
+        BatchTradingService, CancelAllOrdersService, CheckOrderService, FetchFillsService,
+        FetchOrderHistoryService, FetchOrdersService, SubscribeOrdersService, TradingService,
+    },
     ExcExt,
 };
 
@@ -32,13 +64,26 @@ pub mod prelude {
         Adaptor, Exc, ExcService, ExcServiceExt, ExchangeError, Request,
     };
     pub use crate::util::{
-        book::SubscribeBidAskService,
+        account::{
+            FetchBalancesService, FetchPositionsService, SubscribeBalancesService,
+            SubscribePositionsService,
+        },
+        book::{SubscribeBidAskService, SubscribeOrderBookService},
+        derivative::{
+            FetchFundingRateHistoryService, FetchOpenInterestHistoryService,
+            SubscribeFundingRateService, SubscribeIndexPriceService, SubscribeLiquidationsService,
+            SubscribeMarkPriceService, SubscribeOpenInterestService,
+        },
         fetch_candles::{FetchCandlesService, FetchCandlesServiceExt},
         instrument::{FetchInstrumentsService, SubscribeInstrumentsService},
         reconnect::ReconnectService,
+        subscribe_candles::SubscribeCandlesService,
         subscribe_tickers::SubscribeTickersService,
         trade::SubscribeTradesService,
-        trading::{CheckOrderService, SubscribeOrdersService, TradingService},
+        trading::{
+            BatchTradingService, CancelAllOrdersService, CheckOrderService, FetchFillsService,
+            FetchOrderHistoryService, FetchOrdersService, SubscribeOrdersService, TradingService,
+        },
         ExcExt,
     };
 
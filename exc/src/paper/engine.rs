@@ -0,0 +1,509 @@
+use std::collections::HashMap;
+
+use exc_core::{
+    types::{
+        InstrumentMeta, Order, OrderId, OrderKind, OrderStatus, OrderTrade, OrderUpdate,
+        PlaceOrder, TimeInForce,
+    },
+    ExchangeError, Str,
+};
+use rust_decimal::Decimal;
+use time::OffsetDateTime;
+
+use super::MarketEvent;
+
+/// Fee rates, e.g. `0.001` for 0.1%. A negative rate is a rebate.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Fees {
+    /// The rate of the fills providing liquidity.
+    pub maker: Decimal,
+    /// The rate of the fills taking liquidity.
+    pub taker: Decimal,
+}
+
+impl Fees {
+    /// Create a new fee rates.
+    pub fn new(maker: Decimal, taker: Decimal) -> Self {
+        Self { maker, taker }
+    }
+}
+
+/// A simulated order.
+#[derive(Debug)]
+struct Sim {
+    inst: Str,
+    order: Order,
+    /// Has not met the market yet.
+    fresh: bool,
+}
+
+impl Sim {
+    fn is_buy(&self) -> bool {
+        self.order.target.size.is_sign_positive()
+    }
+
+    fn remaining(&self) -> Decimal {
+        self.order.target.size.abs() - self.order.state.filled.abs()
+    }
+
+    fn finish(&mut self) {
+        self.order.state.status = OrderStatus::Finished;
+    }
+}
+
+/// The market of an instrument.
+#[derive(Debug, Default)]
+struct Book {
+    last: Option<MarketEvent>,
+    open: Vec<OrderId>,
+}
+
+/// The matching engine of the simulated exchanges.
+#[derive(Debug, Default)]
+pub(crate) struct Engine {
+    fees: Fees,
+    metas: HashMap<Str, InstrumentMeta<Decimal>>,
+    books: HashMap<Str, Book>,
+    orders: HashMap<OrderId, Sim>,
+    next_id: u64,
+}
+
+impl Engine {
+    pub(crate) fn set_fees(&mut self, fees: Fees) {
+        self.fees = fees;
+    }
+
+    pub(crate) fn insert_meta(&mut self, meta: InstrumentMeta<Decimal>) {
+        self.metas.insert(meta.smol_name().clone(), meta);
+    }
+
+    fn check(&self, req: &PlaceOrder) -> Result<(), ExchangeError> {
+        let place = &req.place;
+        if place.kind.is_conditional() {
+            return Err(ExchangeError::Unsupported(anyhow::anyhow!(
+                "conditional orders are not supported by the simulated exchange"
+            )));
+        }
+        if place.size.is_zero() {
+            return Err(ExchangeError::Api(anyhow::anyhow!("order size is zero")));
+        }
+        let Some(meta) = self.metas.get(req.opts.instrument()) else {
+            return Ok(());
+        };
+        if !meta.is_live() {
+            return Err(ExchangeError::Api(anyhow::anyhow!(
+                "{} is not live for trading",
+                meta.name()
+            )));
+        }
+        let attrs = meta.attrs();
+        let size = place.size.abs();
+        if !attrs.size_tick.is_zero() && !(size % attrs.size_tick).is_zero() {
+            return Err(ExchangeError::Api(anyhow::anyhow!(
+                "size {size} is not a multiple of the size tick {}",
+                attrs.size_tick
+            )));
+        }
+        if size < attrs.min_size {
+            return Err(ExchangeError::Api(anyhow::anyhow!(
+                "size {size} is less than the min size {}",
+                attrs.min_size
+            )));
+        }
+        if let OrderKind::Limit(price, _) | OrderKind::PostOnly(price) = place.kind {
+            if price <= Decimal::ZERO {
+                return Err(ExchangeError::Api(anyhow::anyhow!(
+                    "price {price} is not positive"
+                )));
+            }
+            if !attrs.price_tick.is_zero() && !(price % attrs.price_tick).is_zero() {
+                return Err(ExchangeError::Api(anyhow::anyhow!(
+                    "price {price} is not a multiple of the price tick {}",
+                    attrs.price_tick
+                )));
+            }
+            if size * attrs.unit * price < attrs.min_value {
+                return Err(ExchangeError::Api(anyhow::anyhow!(
+                    "order value is less than the min value {}",
+                    attrs.min_value
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Place an order, returning the updates it causes.
+    /// The first update is the new order and the last one is its current state.
+    pub(crate) fn place(
+        &mut self,
+        req: &PlaceOrder,
+        ts: OffsetDateTime,
+    ) -> Result<Vec<OrderUpdate>, ExchangeError> {
+        self.check(req)?;
+        let id = match req.opts.client_id() {
+            Some(id) => OrderId::from(Str::new(id)),
+            None => {
+                self.next_id += 1;
+                OrderId::from(format!("paper-{}", self.next_id))
+            }
+        };
+        if self.orders.contains_key(&id) {
+            return Err(ExchangeError::Api(anyhow::anyhow!(
+                "duplicated order id: {}",
+                id.as_str()
+            )));
+        }
+        let inst = Str::new(req.opts.instrument());
+        let mut order = Order::new(id.clone(), req.place);
+        order.with_position_side(req.opts.position_side());
+        self.orders.insert(
+            id.clone(),
+            Sim {
+                inst: inst.clone(),
+                order: order.clone(),
+                fresh: true,
+            },
+        );
+        let book = self.books.entry(inst.clone()).or_default();
+        book.open.push(id.clone());
+        let last = book.last.clone();
+        let mut updates = vec![OrderUpdate { ts, order }];
+        if let Some(event) = last {
+            self.take(&inst, &id, &event, ts, &mut updates);
+            self.prune(&inst);
+        }
+        Ok(updates)
+    }
+
+    /// Cancel an order.
+    pub(crate) fn cancel(
+        &mut self,
+        inst: &str,
+        id: &OrderId,
+        ts: OffsetDateTime,
+    ) -> Result<OrderUpdate, ExchangeError> {
+        let sim = match self.orders.get_mut(id) {
+            Some(sim) if sim.inst == inst => sim,
+            _ => return Err(ExchangeError::OrderNotFound),
+        };
+        sim.finish();
+        sim.order.trade = None;
+        let order = sim.order.clone();
+        if let Some(book) = self.books.get_mut(inst) {
+            book.open.retain(|open| open != id);
+        }
+        Ok(OrderUpdate { ts, order })
+    }
+
+    /// Get an order.
+    pub(crate) fn get(&self, inst: &str, id: &OrderId) -> Result<Order, ExchangeError> {
+        match self.orders.get(id) {
+            Some(sim) if sim.inst == inst => {
+                let mut order = sim.order.clone();
+                order.trade = None;
+                Ok(order)
+            }
+            _ => Err(ExchangeError::OrderNotFound),
+        }
+    }
+
+    /// Match the open orders of the instrument against the event.
+    pub(crate) fn on_event(&mut self, inst: &str, event: MarketEvent) -> Vec<OrderUpdate> {
+        let ts = event.ts();
+        let book = self.books.entry(Str::new(inst)).or_default();
+        book.last = Some(event.clone());
+        let open = book.open.clone();
+        let mut updates = Vec::new();
+        for id in open.iter() {
+            let Some(sim) = self.orders.get(id) else {
+                continue;
+            };
+            if sim.fresh {
+                self.take(inst, id, &event, ts, &mut updates);
+            } else {
+                self.make(id, &event, ts, &mut updates);
+            }
+        }
+        self.prune(inst);
+        updates
+    }
+
+    /// Remove the finished orders from the book.
+    fn prune(&mut self, inst: &str) {
+        let orders = &self.orders;
+        if let Some(book) = self.books.get_mut(inst) {
+            book.open.retain(|id| {
+                orders
+                    .get(id)
+                    .is_some_and(|sim| sim.order.state.status != OrderStatus::Finished)
+            });
+        }
+    }
+
+    /// Match a fresh order as a taker.
+    fn take(
+        &mut self,
+        inst: &str,
+        id: &OrderId,
+        event: &MarketEvent,
+        ts: OffsetDateTime,
+        updates: &mut Vec<OrderUpdate>,
+    ) {
+        let Some(sim) = self.orders.get_mut(id) else {
+            return;
+        };
+        let buy = sim.is_buy();
+        let best = event.best(buy);
+        let crossed = |price: Decimal| {
+            best.filter(|(best, _)| if buy { *best <= price } else { *best >= price })
+        };
+        let kind = sim.order.target.kind;
+        match kind {
+            OrderKind::Market => {
+                let Some((price, _)) = best else {
+                    // Wait for the opposite side.
+                    return;
+                };
+                sim.fresh = false;
+                let size = sim.remaining();
+                self.fill(inst, id, price, size, true, ts, updates);
+            }
+            OrderKind::Limit(price, tif) => {
+                sim.fresh = false;
+                let remaining = sim.remaining();
+                match crossed(price) {
+                    Some((_, available))
+                        if tif == TimeInForce::FillOrKill
+                            && !available.is_zero()
+                            && available < remaining =>
+                    {
+                        self.expire(id, ts, updates);
+                    }
+                    Some((best, available)) => {
+                        self.fill(
+                            inst,
+                            id,
+                            best,
+                            available_size(available, remaining),
+                            true,
+                            ts,
+                            updates,
+                        );
+                        if tif != TimeInForce::GoodTilCancelled {
+                            self.expire(id, ts, updates);
+                        }
+                    }
+                    None if tif != TimeInForce::GoodTilCancelled => {
+                        self.expire(id, ts, updates);
+                    }
+                    None => {}
+                }
+            }
+            OrderKind::PostOnly(price) => {
+                sim.fresh = false;
+                if crossed(price).is_some() {
+                    tracing::debug!("paper: post-only order {} rejected", id.as_str());
+                    self.expire(id, ts, updates);
+                }
+            }
+            _ => {
+                sim.fresh = false;
+                self.expire(id, ts, updates);
+            }
+        }
+    }
+
+    /// Match a resting order as a maker.
+    fn make(
+        &mut self,
+        id: &OrderId,
+        event: &MarketEvent,
+        ts: OffsetDateTime,
+        updates: &mut Vec<OrderUpdate>,
+    ) {
+        let Some(sim) = self.orders.get(id) else {
+            return;
+        };
+        let (OrderKind::Limit(price, _) | OrderKind::PostOnly(price)) = sim.order.target.kind
+        else {
+            return;
+        };
+        if let Some(available) = event.through(sim.is_buy(), price) {
+            let inst = sim.inst.clone();
+            let size = available_size(available, sim.remaining());
+            self.fill(&inst, id, price, size, false, ts, updates);
+        }
+    }
+
+    /// Finish an order without further fills.
+    fn expire(&mut self, id: &OrderId, ts: OffsetDateTime, updates: &mut Vec<OrderUpdate>) {
+        if let Some(sim) = self.orders.get_mut(id) {
+            if sim.order.state.status == OrderStatus::Finished {
+                return;
+            }
+            sim.finish();
+            sim.order.trade = None;
+            updates.push(OrderUpdate {
+                ts,
+                order: sim.order.clone(),
+            });
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn fill(
+        &mut self,
+        inst: &str,
+        id: &OrderId,
+        price: Decimal,
+        size: Decimal,
+        taker: bool,
+        ts: OffsetDateTime,
+        updates: &mut Vec<OrderUpdate>,
+    ) {
+        if size <= Decimal::ZERO {
+            return;
+        }
+        let rate = if taker {
+            self.fees.taker
+        } else {
+            self.fees.maker
+        };
+        let meta = self.metas.get(inst);
+        let Some(sim) = self.orders.get_mut(id) else {
+            return;
+        };
+        let unit = meta.map_or(Decimal::ONE, |meta| meta.attrs().unit);
+        let (fee, fee_asset) = match meta {
+            Some(meta) if meta.attrs().reversed => (
+                -(size * unit / price * rate),
+                Some(meta.instrument().base().clone()),
+            ),
+            Some(meta) => (
+                -(size * unit * price * rate),
+                Some(meta.instrument().quote().clone()),
+            ),
+            None => (-(size * unit * price * rate), None),
+        };
+        let state = &mut sim.order.state;
+        let filled = state.filled.abs();
+        state.cost = if filled.is_zero() {
+            price
+        } else {
+            (state.cost * filled + price * size) / (filled + size)
+        };
+        let signed = if sim.order.target.size.is_sign_positive() {
+            size
+        } else {
+            -size
+        };
+        state.filled += signed;
+        if let Some(asset) = fee_asset.clone() {
+            *state.fees.entry(asset).or_default() += fee;
+        }
+        if sim.remaining().is_zero() {
+            sim.finish();
+        }
+        sim.order.trade = Some(OrderTrade {
+            price,
+            size: signed,
+            fee,
+            fee_asset,
+        });
+        updates.push(OrderUpdate {
+            ts,
+            order: sim.order.clone(),
+        });
+    }
+}
+
+/// A zero available size means the size is unknown, so the whole remaining size is filled.
+fn available_size(available: Decimal, remaining: Decimal) -> Decimal {
+    if available.is_zero() {
+        remaining
+    } else {
+        available.min(remaining)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use exc_core::types::{BidAsk, Place, PlaceOrderOptions, Trade};
+    use time::macros::datetime;
+
+    fn dec(s: &str) -> Decimal {
+        s.parse().unwrap()
+    }
+
+    fn bid_ask(bid: Decimal, ask: Decimal) -> MarketEvent {
+        MarketEvent::BidAsk(BidAsk {
+            ts: datetime!(2023-01-01 00:00:00 +00:00),
+            bid: Some((bid, dec("1"))),
+            ask: Some((ask, dec("1"))),
+        })
+    }
+
+    #[test]
+    fn test_matching() -> anyhow::Result<()> {
+        let ts = datetime!(2023-01-01 00:00:00 +00:00);
+        let opts = PlaceOrderOptions::new("BTC-USDT");
+        let mut engine = Engine::default();
+        engine.set_fees(Fees::new(dec("0.0002"), dec("0.0005")));
+        engine.on_event("BTC-USDT", bid_ask(dec("99"), dec("101")));
+
+        // Market orders take the opposite best price.
+        let updates = engine.place(&Place::with_size(dec("2")).into_request(&opts), ts)?;
+        let order = &updates.last().unwrap().order;
+        assert_eq!(order.state.status, OrderStatus::Finished);
+        assert_eq!(order.state.filled, dec("2"));
+        assert_eq!(order.state.cost, dec("101"));
+        assert_eq!(order.trade.as_ref().unwrap().fee, dec("-0.101"));
+
+        // Post-only orders that would take liquidity are rejected.
+        let updates = engine.place(
+            &Place::with_size(dec("1"))
+                .post_only(dec("101"))
+                .into_request(&opts),
+            ts,
+        )?;
+        let order = &updates.last().unwrap().order;
+        assert_eq!(order.state.status, OrderStatus::Finished);
+        assert!(order.state.filled.is_zero());
+
+        // IOC orders never rest.
+        let ioc =
+            Place::with_size(dec("-1")).limit_with_tif(dec("99"), TimeInForce::ImmediateOrCancel);
+        let updates = engine.place(&ioc.into_request(&opts), ts)?;
+        let order = &updates.last().unwrap().order;
+        assert_eq!(order.state.status, OrderStatus::Finished);
+        assert_eq!(order.state.filled, dec("-1"));
+
+        // Resting orders are filled by trades through their prices.
+        let updates = engine.place(
+            &Place::with_size(dec("-3"))
+                .limit(dec("100"))
+                .into_request(&opts),
+            ts,
+        )?;
+        let id = updates[0].order.id.clone();
+        assert_eq!(updates[0].order.state.status, OrderStatus::Pending);
+        let trade = |price| {
+            MarketEvent::Trade(Trade {
+                ts,
+                price,
+                size: dec("2"),
+                buy: true,
+            })
+        };
+        assert!(engine.on_event("BTC-USDT", trade(dec("100"))).is_empty());
+        let updates = engine.on_event("BTC-USDT", trade(dec("100.5")));
+        assert_eq!(updates[0].order.state.filled, dec("-2"));
+        assert_eq!(updates[0].order.trade.as_ref().unwrap().fee, dec("-0.04"));
+        engine.cancel("BTC-USDT", &id, ts)?;
+        let order = engine.get("BTC-USDT", &id)?;
+        assert_eq!(order.state.status, OrderStatus::Finished);
+        assert_eq!(order.state.filled, dec("-2"));
+        Ok(())
+    }
+}
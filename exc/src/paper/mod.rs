@@ -0,0 +1,407 @@
+use std::{
+    collections::HashSet,
+    marker::PhantomData,
+    sync::{Arc, Mutex, Weak},
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use exc_core::{
+    types::{
+        BidAsk, CancelOrder, Canceled, GetOrder, InstrumentMeta, OrderStream, OrderUpdate,
+        PlaceOrder, Placed, SubscribeBidAsk, SubscribeOrders, SubscribeTrades, Trade,
+    },
+    ExcService, ExcServiceExt, ExchangeError, Request, Str,
+};
+use futures::{future::BoxFuture, stream::BoxStream, FutureExt, StreamExt};
+use rust_decimal::Decimal;
+use time::OffsetDateTime;
+use tower::{Service, ServiceExt};
+
+use self::venue::Venue;
+
+pub use self::engine::Fees;
+
+pub(crate) mod engine;
+pub(crate) mod venue;
+
+/// A market data event that orders are matched against.
+#[derive(Debug, Clone)]
+pub enum MarketEvent {
+    /// Best bid and ask.
+    BidAsk(BidAsk),
+    /// Trade.
+    Trade(Trade),
+}
+
+impl MarketEvent {
+    /// Get the timestamp.
+    pub fn ts(&self) -> OffsetDateTime {
+        match self {
+            Self::BidAsk(bid_ask) => bid_ask.ts,
+            Self::Trade(trade) => trade.ts,
+        }
+    }
+
+    /// The best `(price, size)` that a taker of the given side trades at.
+    fn best(&self, buy: bool) -> Option<(Decimal, Decimal)> {
+        match self {
+            Self::BidAsk(bid_ask) => {
+                if buy {
+                    bid_ask.ask
+                } else {
+                    bid_ask.bid
+                }
+            }
+            Self::Trade(trade) => Some((trade.price, trade.size)),
+        }
+    }
+
+    /// The size that a resting order of the given side and price is filled with.
+    ///
+    /// A resting order is filled when the opposite best price reaches its price,
+    /// or when a trade goes through its price.
+    fn through(&self, buy: bool, price: Decimal) -> Option<Decimal> {
+        match self {
+            Self::BidAsk(_) => self
+                .best(buy)
+                .filter(|(best, _)| if buy { *best <= price } else { *best >= price })
+                .map(|(_, size)| size),
+            Self::Trade(trade) => {
+                let through = if buy {
+                    trade.price < price
+                } else {
+                    trade.price > price
+                };
+                through.then_some(trade.size)
+            }
+        }
+    }
+}
+
+impl From<BidAsk> for MarketEvent {
+    fn from(bid_ask: BidAsk) -> Self {
+        Self::BidAsk(bid_ask)
+    }
+}
+
+impl From<Trade> for MarketEvent {
+    fn from(trade: Trade) -> Self {
+        Self::Trade(trade)
+    }
+}
+
+/// A subscription that feeds the simulated exchange.
+///
+/// The response of the request must be a stream of [`PaperFeed::Item`].
+pub trait PaperFeed: Request + Send + 'static {
+    /// The item of the stream.
+    type Item: Into<MarketEvent> + Send + 'static;
+
+    /// Create the subscription of the instrument.
+    fn subscribe(inst: &str) -> Self;
+}
+
+impl PaperFeed for SubscribeBidAsk {
+    type Item = BidAsk;
+
+    fn subscribe(inst: &str) -> Self {
+        Self::new(inst)
+    }
+}
+
+impl PaperFeed for SubscribeTrades {
+    type Item = Trade;
+
+    fn subscribe(inst: &str) -> Self {
+        Self::new(inst)
+    }
+}
+
+#[derive(Default)]
+struct Shared {
+    venue: Venue,
+    feeds: Mutex<HashSet<Str>>,
+}
+
+/// Paper-trading exchange.
+///
+/// A simulated exchange that matches orders against the live market data
+/// subscribed from the inner service (best bid/ask by default, see [`Paper::with_trades`]),
+/// without sending any order.
+/// - Market orders and crossing limit orders are filled at the best price as takers.
+/// - Resting limit orders are filled at their prices as makers once the market reaches them.
+/// - Post-only orders that would take liquidity are rejected (finished without fills).
+/// - The ticks, min size and min value of the instruments added by [`Paper::instrument`] are checked.
+///
+/// Conditional orders are not supported, and positions and balances are not tracked.
+pub struct Paper<S, R = SubscribeBidAsk> {
+    svc: S,
+    latency: Duration,
+    shared: Arc<Shared>,
+    _feed: PhantomData<fn() -> R>,
+}
+
+impl<S, R> Clone for Paper<S, R>
+where
+    S: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            svc: self.svc.clone(),
+            latency: self.latency,
+            shared: self.shared.clone(),
+            _feed: PhantomData,
+        }
+    }
+}
+
+impl<S> Paper<S, SubscribeBidAsk> {
+    /// Create a paper-trading exchange matching orders against the best bid/ask
+    /// subscribed from the `market` service.
+    pub fn new(market: S) -> Self {
+        Self::with_feed(market)
+    }
+}
+
+impl<S> Paper<S, SubscribeTrades> {
+    /// Create a paper-trading exchange matching orders against the trades
+    /// subscribed from the `market` service.
+    pub fn with_trades(market: S) -> Self {
+        Self::with_feed(market)
+    }
+}
+
+impl<S, R> Paper<S, R> {
+    fn with_feed(market: S) -> Self {
+        Self {
+            svc: market,
+            latency: Duration::ZERO,
+            shared: Arc::default(),
+            _feed: PhantomData,
+        }
+    }
+
+    /// Set the fee rates. Default to zero.
+    pub fn fees(&mut self, fees: Fees) -> &mut Self {
+        self.shared.venue.engine().set_fees(fees);
+        self
+    }
+
+    /// Set the latency of placing and cancelling orders. Default to zero.
+    pub fn latency(&mut self, latency: Duration) -> &mut Self {
+        self.latency = latency;
+        self
+    }
+
+    /// Add the meta of an instrument to check the orders with.
+    pub fn instrument(&mut self, meta: InstrumentMeta<Decimal>) -> &mut Self {
+        self.shared.venue.engine().insert_meta(meta);
+        self
+    }
+}
+
+impl<S, R> Paper<S, R>
+where
+    R: PaperFeed<Response = BoxStream<'static, Result<<R as PaperFeed>::Item, ExchangeError>>>,
+    S: ExcService<R> + Clone + Send + 'static,
+    S::Future: Send,
+{
+    /// Start the market data feed of the instrument if it is not running.
+    fn feed(&self, inst: &str) {
+        if !self.shared.feeds.lock().unwrap().insert(Str::new(inst)) {
+            return;
+        }
+        let svc = self.svc.clone().into_service();
+        let shared = Arc::downgrade(&self.shared);
+        let inst = Str::new(inst);
+        tokio::spawn(async move {
+            if let Err(err) = drive(svc.oneshot(R::subscribe(&inst)), &inst, &shared).await {
+                tracing::error!("paper: market feed of {inst} stopped: {err}");
+            }
+            if let Some(shared) = shared.upgrade() {
+                shared.feeds.lock().unwrap().remove(&inst);
+            }
+        });
+    }
+}
+
+async fn drive<T: Into<MarketEvent>>(
+    stream: impl std::future::Future<
+        Output = Result<BoxStream<'static, Result<T, ExchangeError>>, ExchangeError>,
+    >,
+    inst: &Str,
+    shared: &Weak<Shared>,
+) -> Result<(), ExchangeError> {
+    let mut stream = stream.await?;
+    while let Some(item) = stream.next().await {
+        let Some(shared) = shared.upgrade() else {
+            // The exchange has been dropped.
+            return Ok(());
+        };
+        shared.venue.on_event(inst, item?.into());
+    }
+    Ok(())
+}
+
+impl<S, R> Service<PlaceOrder> for Paper<S, R>
+where
+    R: PaperFeed<Response = BoxStream<'static, Result<<R as PaperFeed>::Item, ExchangeError>>>,
+    S: ExcService<R> + Clone + Send + 'static,
+    S::Future: Send,
+{
+    type Response = <PlaceOrder as Request>::Response;
+    type Error = ExchangeError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: PlaceOrder) -> Self::Future {
+        self.feed(req.opts.instrument());
+        let shared = self.shared.clone();
+        let latency = self.latency;
+        let res = async move {
+            tokio::time::sleep(latency).await;
+            let ts = OffsetDateTime::now_utc();
+            let mut updates = shared.venue.place(&req, ts)?;
+            let order = updates.pop().expect("must have the placed order").order;
+            Ok(Placed {
+                id: order.id.clone(),
+                order: Some(order),
+                ts,
+            })
+        }
+        .boxed();
+        futures::future::ready(Ok(res)).boxed()
+    }
+}
+
+impl<S, R> Service<CancelOrder> for Paper<S, R> {
+    type Response = <CancelOrder as Request>::Response;
+    type Error = ExchangeError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: CancelOrder) -> Self::Future {
+        let shared = self.shared.clone();
+        let latency = self.latency;
+        let res = async move {
+            tokio::time::sleep(latency).await;
+            let ts = OffsetDateTime::now_utc();
+            let order = shared.venue.cancel(&req.instrument, &req.id, ts)?;
+            Ok(Canceled {
+                order: Some(order),
+                ts,
+            })
+        }
+        .boxed();
+        futures::future::ready(Ok(res)).boxed()
+    }
+}
+
+impl<S, R> Service<GetOrder> for Paper<S, R> {
+    type Response = <GetOrder as Request>::Response;
+    type Error = ExchangeError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: GetOrder) -> Self::Future {
+        let res = self
+            .shared
+            .venue
+            .get(&req.instrument, &req.id)
+            .map(|order| OrderUpdate {
+                ts: OffsetDateTime::now_utc(),
+                order,
+            });
+        futures::future::ready(Ok(futures::future::ready(res).boxed())).boxed()
+    }
+}
+
+impl<S, R> Service<SubscribeOrders> for Paper<S, R> {
+    type Response = OrderStream;
+    type Error = ExchangeError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: SubscribeOrders) -> Self::Future {
+        let stream = self.shared.venue.subscribe(req.instrument);
+        futures::future::ready(Ok(stream)).boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use exc_core::types::{BidAskStream, OrderStatus, Place, PlaceOrderOptions};
+    use futures::{channel::mpsc, TryStreamExt};
+    use time::macros::datetime;
+
+    #[tokio::test]
+    async fn test_paper() -> anyhow::Result<()> {
+        let (tx, rx) = mpsc::unbounded();
+        let rx = Arc::new(Mutex::new(Some(rx)));
+        let market = tower::service_fn(move |_: SubscribeBidAsk| {
+            let rx = rx.lock().unwrap().take();
+            async move {
+                let rx = rx.ok_or_else(|| anyhow::anyhow!("subscribed twice"))?;
+                Ok::<BidAskStream, ExchangeError>(rx.map(Ok).boxed())
+            }
+        });
+        let mut paper = Paper::new(market);
+        paper.fees(Fees::new(Decimal::ZERO, Decimal::new(1, 3)));
+        let mut updates = paper
+            .clone()
+            .oneshot(SubscribeOrders::new("BTC-USDT"))
+            .await?;
+
+        let opts = PlaceOrderOptions::new("BTC-USDT");
+        let placed = (&mut paper)
+            .oneshot(Place::with_size(Decimal::ONE).into_request(&opts))
+            .await?
+            .await?;
+        assert_eq!(
+            placed.order.as_ref().unwrap().state.status,
+            OrderStatus::Pending
+        );
+        tx.unbounded_send(BidAsk {
+            ts: datetime!(2023-01-01 00:00:00 +00:00),
+            bid: Some((Decimal::from(99), Decimal::ONE)),
+            ask: Some((Decimal::from(101), Decimal::ONE)),
+        })?;
+        let filled = loop {
+            let update = updates.try_next().await?.unwrap();
+            if update.order.state.status == OrderStatus::Finished {
+                break update.order;
+            }
+        };
+        assert_eq!(filled.id, placed.id);
+        assert_eq!(filled.state.filled, Decimal::ONE);
+        assert_eq!(filled.state.cost, Decimal::from(101));
+        assert_eq!(filled.trade.unwrap().fee, Decimal::new(-101, 3));
+
+        let placed = (&mut paper)
+            .oneshot(
+                Place::with_size(Decimal::ONE)
+                    .post_only(Decimal::from(101))
+                    .into_request(&opts),
+            )
+            .await?
+            .await?;
+        let order = placed.order.unwrap();
+        assert_eq!(order.state.status, OrderStatus::Finished);
+        assert!(order.state.filled.is_zero());
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+use std::sync::{Mutex, MutexGuard};
+
+use async_stream::stream;
+use exc_core::{
+    types::{Order, OrderId, OrderStream, OrderUpdate, PlaceOrder},
+    ExchangeError, Str,
+};
+use futures::StreamExt;
+use time::OffsetDateTime;
+use tokio::sync::broadcast;
+
+use super::{engine::Engine, MarketEvent};
+
+const DEFAULT_CAPACITY: usize = 1024;
+
+/// The matching engine with the order updates published.
+pub(crate) struct Venue {
+    engine: Mutex<Engine>,
+    tx: broadcast::Sender<(Str, OrderUpdate)>,
+}
+
+impl Default for Venue {
+    fn default() -> Self {
+        let (tx, _) = broadcast::channel(DEFAULT_CAPACITY);
+        Self {
+            engine: Mutex::default(),
+            tx,
+        }
+    }
+}
+
+impl Venue {
+    pub(crate) fn engine(&self) -> MutexGuard<'_, Engine> {
+        self.engine.lock().unwrap()
+    }
+
+    fn publish(&self, inst: &Str, updates: &[OrderUpdate]) {
+        for update in updates {
+            // It is fine that there is no subscriber.
+            let _ = self.tx.send((inst.clone(), update.clone()));
+        }
+    }
+
+    /// Place an order and return the updates it causes, the last of which is the placed order.
+    pub(crate) fn place(
+        &self,
+        req: &PlaceOrder,
+        ts: OffsetDateTime,
+    ) -> Result<Vec<OrderUpdate>, ExchangeError> {
+        let updates = self.engine().place(req, ts)?;
+        self.publish(&Str::new(req.opts.instrument()), &updates);
+        Ok(updates)
+    }
+
+    /// Cancel an order.
+    pub(crate) fn cancel(
+        &self,
+        inst: &Str,
+        id: &OrderId,
+        ts: OffsetDateTime,
+    ) -> Result<Order, ExchangeError> {
+        let update = self.engine().cancel(inst, id, ts)?;
+        let order = update.order.clone();
+        self.publish(inst, &[update]);
+        Ok(order)
+    }
+
+    /// Get an order.
+    pub(crate) fn get(&self, inst: &str, id: &OrderId) -> Result<Order, ExchangeError> {
+        self.engine().get(inst, id)
+    }
+
+    /// Match the orders against the event and return the updates.
+    pub(crate) fn on_event(&self, inst: &Str, event: MarketEvent) -> Vec<OrderUpdate> {
+        let updates = self.engine().on_event(inst, event);
+        self.publish(inst, &updates);
+        updates
+    }
+
+    /// Subscribe the order updates of the instrument.
+    pub(crate) fn subscribe(&self, inst: Str) -> OrderStream {
+        let mut rx = self.tx.subscribe();
+        stream! {
+            loop {
+                match rx.recv().await {
+                    Ok((update_inst, update)) => {
+                        if update_inst == inst {
+                            yield Ok(update);
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(n)) => {
+                        yield Err(ExchangeError::OutOfSync(anyhow::anyhow!(
+                            "lagged behind {n} order updates"
+                        )));
+                        break;
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        }
+        .boxed()
+    }
+}
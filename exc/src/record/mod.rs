@@ -0,0 +1,184 @@
+use std::path::{Path, PathBuf};
+
+use exc_core::{
+    types::{
+        BidAsk, OrderBook, SubscribeBidAsk, SubscribeOrderBook, SubscribeTickers, SubscribeTrades,
+        Ticker, Trade,
+    },
+    ExchangeError, Request,
+};
+use futures::stream::BoxStream;
+use serde::{de::DeserializeOwned, Serialize};
+use time::OffsetDateTime;
+
+use crate::fs::sanitize;
+
+pub use self::{
+    recorder::{RecordLayer, Recorder},
+    replay::{Replay, ReplayClock},
+};
+
+/// Recorder.
+pub mod recorder;
+
+/// Replay.
+pub mod replay;
+
+/// The stream of recordable items.
+pub type ItemStream<T> = BoxStream<'static, Result<T, ExchangeError>>;
+
+/// A subscription whose stream can be recorded and replayed.
+///
+/// The response of the request must be an [`ItemStream`] of [`Recordable::Item`].
+pub trait Recordable: Request + Send + 'static {
+    /// The item of the stream.
+    type Item: Serialize + DeserializeOwned + Send + 'static;
+
+    /// The name of the channel.
+    const CHANNEL: &'static str;
+
+    /// Get the instrument.
+    fn instrument(&self) -> &str;
+
+    /// Get the timestamp of the item.
+    fn ts(item: &Self::Item) -> OffsetDateTime;
+
+    /// Adjust a replayed item to the request. Do nothing by default.
+    fn replayed(&self, item: Self::Item) -> Self::Item {
+        item
+    }
+}
+
+/// Get the path of the file recording the channel of the instrument.
+pub(crate) fn record_file(root: &Path, channel: &str, inst: &str) -> PathBuf {
+    root.join(sanitize(inst)).join(format!("{channel}.jsonl"))
+}
+
+impl Recordable for SubscribeTrades {
+    type Item = Trade;
+
+    const CHANNEL: &'static str = "trades";
+
+    fn instrument(&self) -> &str {
+        &self.instrument
+    }
+
+    fn ts(item: &Self::Item) -> OffsetDateTime {
+        item.ts
+    }
+}
+
+impl Recordable for SubscribeBidAsk {
+    type Item = BidAsk;
+
+    const CHANNEL: &'static str = "bid_ask";
+
+    fn instrument(&self) -> &str {
+        &self.instrument
+    }
+
+    fn ts(item: &Self::Item) -> OffsetDateTime {
+        item.ts
+    }
+}
+
+impl Recordable for SubscribeTickers {
+    type Item = Ticker;
+
+    const CHANNEL: &'static str = "tickers";
+
+    fn instrument(&self) -> &str {
+        &self.instrument
+    }
+
+    fn ts(item: &Self::Item) -> OffsetDateTime {
+        item.ts
+    }
+}
+
+impl Recordable for SubscribeOrderBook {
+    type Item = OrderBook;
+
+    const CHANNEL: &'static str = "order_book";
+
+    fn instrument(&self) -> &str {
+        &self.instrument
+    }
+
+    fn ts(item: &Self::Item) -> OffsetDateTime {
+        item.ts
+    }
+
+    fn replayed(&self, mut item: Self::Item) -> Self::Item {
+        if let Some(depth) = self.depth {
+            item.truncate(depth);
+        }
+        item
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use exc_core::types::TradeStream;
+    use futures::{stream, StreamExt, TryStreamExt};
+    use rust_decimal::Decimal;
+    use time::macros::datetime;
+    use tower::{Layer, ServiceExt};
+
+    #[tokio::test]
+    async fn test_record_and_replay() -> anyhow::Result<()> {
+        let root = std::env::temp_dir().join(format!("exc-record-{}", std::process::id()));
+        let t0 = datetime!(2023-01-01 00:00:00 +00:00);
+        let trades = (0..3)
+            .map(|idx| Trade {
+                ts: t0 + time::Duration::milliseconds(idx),
+                price: Decimal::from(idx),
+                size: Decimal::ONE,
+                buy: idx % 2 == 0,
+            })
+            .collect::<Vec<_>>();
+        let inner = {
+            let trades = trades.clone();
+            tower::service_fn(move |_: SubscribeTrades| {
+                let trades = trades.clone();
+                async move {
+                    Ok::<TradeStream, ExchangeError>(
+                        stream::iter(trades.into_iter().map(Ok)).boxed(),
+                    )
+                }
+            })
+        };
+        let recorded: Vec<_> = RecordLayer::new(&root)
+            .layer(inner)
+            .oneshot(SubscribeTrades::new("BTC-USDT"))
+            .await?
+            .try_collect()
+            .await?;
+        assert_eq!(recorded.len(), trades.len());
+
+        // Wait for the writer to finish.
+        let path = record_file(&root, SubscribeTrades::CHANNEL, "BTC-USDT");
+        for _ in 0..100 {
+            let content = tokio::fs::read_to_string(&path).await.unwrap_or_default();
+            if content.lines().count() == trades.len() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+
+        let mut replay = Replay::new(&root);
+        replay.clock(ReplayClock::AsFastAsPossible);
+        let replayed: Vec<_> = replay
+            .oneshot(SubscribeTrades::new("BTC-USDT"))
+            .await?
+            .try_collect()
+            .await?;
+        assert_eq!(
+            replayed.iter().map(|t| (t.ts, t.price)).collect::<Vec<_>>(),
+            trades.iter().map(|t| (t.ts, t.price)).collect::<Vec<_>>()
+        );
+        tokio::fs::remove_dir_all(root).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,116 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use exc_core::{ExcService, ExchangeError};
+use futures::{future::BoxFuture, FutureExt, StreamExt};
+use tokio::{
+    io::{AsyncWriteExt, BufWriter},
+    sync::mpsc::{unbounded_channel, UnboundedReceiver},
+};
+use tower::{Layer, Service};
+
+use super::{record_file, ItemStream, Recordable};
+
+/// Record layer.
+#[derive(Debug, Clone)]
+pub struct RecordLayer {
+    root: PathBuf,
+}
+
+impl RecordLayer {
+    /// Create a new record layer that appends the streams to the files under the `root` directory.
+    pub fn new(root: impl AsRef<Path>) -> Self {
+        Self {
+            root: root.as_ref().to_path_buf(),
+        }
+    }
+}
+
+impl<S> Layer<S> for RecordLayer {
+    type Service = Recorder<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        Recorder {
+            svc: inner,
+            root: Arc::new(self.root.clone()),
+        }
+    }
+}
+
+/// Recorder.
+///
+/// Tee the items of the subscribed streams into the record files
+/// (one JSON line per item, errors are not recorded).
+/// Each subscription appends to the file of its instrument and channel.
+#[derive(Debug, Clone)]
+pub struct Recorder<S> {
+    svc: S,
+    root: Arc<PathBuf>,
+}
+
+async fn write_lines(path: PathBuf, mut rx: UnboundedReceiver<String>) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        tokio::fs::create_dir_all(dir).await?;
+    }
+    let file = tokio::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .await?;
+    let mut writer = BufWriter::new(file);
+    while let Some(line) = rx.recv().await {
+        writer.write_all(line.as_bytes()).await?;
+        writer.write_all(b"\n").await?;
+        // Flush when there is no pending line.
+        while let Ok(line) = rx.try_recv() {
+            writer.write_all(line.as_bytes()).await?;
+            writer.write_all(b"\n").await?;
+        }
+        writer.flush().await?;
+    }
+    Ok(())
+}
+
+impl<S, R> Service<R> for Recorder<S>
+where
+    R: Recordable<Response = ItemStream<<R as Recordable>::Item>>,
+    S: ExcService<R>,
+    S::Future: Send + 'static,
+{
+    type Response = ItemStream<R::Item>;
+    type Error = ExchangeError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.svc.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: R) -> Self::Future {
+        let path = record_file(&self.root, R::CHANNEL, req.instrument());
+        let stream = self.svc.call(req);
+        async move {
+            let stream = stream.await?;
+            let (tx, rx) = unbounded_channel();
+            tokio::spawn(async move {
+                if let Err(err) = write_lines(path.clone(), rx).await {
+                    tracing::error!("recorder: failed to write {}: {err}", path.display());
+                }
+            });
+            let stream = stream.inspect(move |item| {
+                if let Ok(item) = item {
+                    match serde_json::to_string(item) {
+                        Ok(line) => {
+                            let _ = tx.send(line);
+                        }
+                        Err(err) => tracing::error!("recorder: failed to serialize: {err}"),
+                    }
+                }
+            });
+            Ok(stream.boxed())
+        }
+        .boxed()
+    }
+}
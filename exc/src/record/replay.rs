@@ -0,0 +1,112 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use async_stream::try_stream;
+use exc_core::ExchangeError;
+use futures::{future::BoxFuture, FutureExt, StreamExt};
+use tokio::{
+    io::{AsyncBufReadExt, BufReader},
+    time::Instant,
+};
+use tower::Service;
+
+use super::{record_file, ItemStream, Recordable};
+
+/// The clock of replay.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ReplayClock {
+    /// Replay at the recorded pace.
+    #[default]
+    RealTime,
+    /// Replay at the given multiple of the recorded pace.
+    Accelerated(f64),
+    /// Replay as fast as possible.
+    AsFastAsPossible,
+}
+
+impl ReplayClock {
+    fn speed(&self) -> Option<f64> {
+        match self {
+            Self::RealTime => Some(1.0),
+            Self::Accelerated(speed) if *speed > 0.0 => Some(*speed),
+            _ => None,
+        }
+    }
+}
+
+/// Replay the recorded streams.
+///
+/// The items are paced by their timestamps,
+/// counting from the first item of each subscription.
+#[derive(Debug, Clone)]
+pub struct Replay {
+    root: Arc<PathBuf>,
+    clock: ReplayClock,
+}
+
+impl Replay {
+    /// Create a new replay service reading the record files under the `root` directory.
+    pub fn new(root: impl AsRef<Path>) -> Self {
+        Self {
+            root: Arc::new(root.as_ref().to_path_buf()),
+            clock: ReplayClock::default(),
+        }
+    }
+
+    /// Set the clock. Default to [`ReplayClock::RealTime`].
+    pub fn clock(&mut self, clock: ReplayClock) -> &mut Self {
+        self.clock = clock;
+        self
+    }
+}
+
+impl<R> Service<R> for Replay
+where
+    R: Recordable<Response = ItemStream<<R as Recordable>::Item>>,
+{
+    type Response = ItemStream<R::Item>;
+    type Error = ExchangeError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: R) -> Self::Future {
+        let path = record_file(&self.root, R::CHANNEL, req.instrument());
+        let speed = self.clock.speed();
+        async move {
+            let file = tokio::fs::File::open(&path).await.map_err(|err| {
+                ExchangeError::Other(anyhow::anyhow!("cannot open {}: {err}", path.display()))
+            })?;
+            let stream = try_stream! {
+                let mut lines = BufReader::new(file).lines();
+                let mut origin = None;
+                while let Some(line) = lines
+                    .next_line()
+                    .await
+                    .map_err(|err| ExchangeError::Other(err.into()))?
+                {
+                    if line.is_empty() {
+                        continue;
+                    }
+                    let item: R::Item = serde_json::from_str(&line)
+                        .map_err(|err| ExchangeError::Other(err.into()))?;
+                    if let Some(speed) = speed {
+                        let ts = R::ts(&item);
+                        let (first, start) = *origin.get_or_insert((ts, Instant::now()));
+                        let delay = std::time::Duration::try_from((ts - first) / speed)
+                            .unwrap_or_default();
+                        tokio::time::sleep_until(start + delay).await;
+                    }
+                    yield req.replayed(item);
+                }
+            };
+            Ok(stream.boxed())
+        }
+        .boxed()
+    }
+}
@@ -3,10 +3,19 @@ pub use crate::core::types::{Period, PeriodKind};
 // These types are going to be replaced by theirs
 // higer-level versions in the future.
 pub use crate::core::types::{
-    BidAsk, BidAskStream, CancelOrder, Canceled, Candle, CandleStream, GetOrder, Order, OrderId,
-    OrderKind, OrderState, OrderStatus, OrderStream, OrderTrade, OrderUpdate, Place, PlaceOrder,
-    PlaceOrderOptions, Placed, QueryCandles, SubscribeBidAsk, SubscribeOrders, SubscribeTickers,
-    Ticker, TickerStream, TimeInForce,
+    AmendOrder, Amended, Balance, BalanceStream, BidAsk, BidAskStream, CancelAllAfter,
+    CancelAllOrders, CancelOrder, CancelOrders, Canceled, Candle, CandleSource, CandleStream,
+    CandleUpdate, CandleUpdateStream, FetchBalances, FetchFills, FetchFundingRateHistory,
+    FetchLastFills, FetchLastOrders, FetchOpenInterestHistory, FetchOpenOrders, FetchOrderHistory,
+    FetchPositions, Fill, FillStream, FundingRate, FundingRateStream, GetOrder, IndexPrice,
+    IndexPriceStream, Liquidation, LiquidationStream, Liquidity, MarginMode, MarkPrice,
+    MarkPriceStream, OpenInterest, OpenInterestStream, Order, OrderBook, OrderBookStream, OrderId,
+    OrderKind, OrderState, OrderStatus, OrderStream, OrderTrade, OrderUpdate, OrdersPage, Place,
+    PlaceOrder, PlaceOrderOptions, PlaceOrders, Placed, Position, PositionSide, PositionStream,
+    QueryCandles, SubscribeBalances, SubscribeBidAsk, SubscribeCandles, SubscribeFundingRate,
+    SubscribeIndexPrice, SubscribeLiquidations, SubscribeMarkPrice, SubscribeOpenInterest,
+    SubscribeOrderBook, SubscribeOrders, SubscribePositions, SubscribeTickers, Ticker,
+    TickerStream, TimeInForce, Trigger, TriggerSource,
 };
 
 /// Instrument.
@@ -0,0 +1,90 @@
+use futures::{future::BoxFuture, FutureExt, TryFutureExt};
+use tower::ServiceExt;
+
+use crate::{
+    core::types::{
+        balance::{Balance, BalanceStream, FetchBalances, SubscribeBalances},
+        position::{FetchPositions, Position, PositionStream, SubscribePositions},
+    },
+    ExcService,
+};
+
+/// Fetch balances service.
+pub trait FetchBalancesService {
+    /// Fetch the balances of the account.
+    fn fetch_balances(&mut self) -> BoxFuture<'_, crate::Result<Vec<Balance>>>;
+}
+
+impl<S> FetchBalancesService for S
+where
+    S: ExcService<FetchBalances> + Send,
+    S::Future: Send,
+{
+    fn fetch_balances(&mut self) -> BoxFuture<'_, crate::Result<Vec<Balance>>> {
+        ServiceExt::<FetchBalances>::oneshot(self.as_service(), FetchBalances)
+            .try_flatten()
+            .boxed()
+    }
+}
+
+/// Subscribe balances service.
+pub trait SubscribeBalancesService {
+    /// Subscribe to the balance updates of the account.
+    fn subscribe_balances(&mut self) -> BoxFuture<'_, crate::Result<BalanceStream>>;
+}
+
+impl<S> SubscribeBalancesService for S
+where
+    S: ExcService<SubscribeBalances> + Send,
+    S::Future: Send,
+{
+    fn subscribe_balances(&mut self) -> BoxFuture<'_, crate::Result<BalanceStream>> {
+        ServiceExt::<SubscribeBalances>::oneshot(self.as_service(), SubscribeBalances).boxed()
+    }
+}
+
+/// Fetch positions service.
+pub trait FetchPositionsService {
+    /// Fetch the positions of the account.
+    fn fetch_positions(&mut self) -> BoxFuture<'_, crate::Result<Vec<Position>>>;
+
+    /// Fetch the position of the given instrument.
+    fn fetch_position(&mut self, inst: &str) -> BoxFuture<'_, crate::Result<Vec<Position>>>;
+}
+
+impl<S> FetchPositionsService for S
+where
+    S: ExcService<FetchPositions> + Send,
+    S::Future: Send,
+{
+    fn fetch_positions(&mut self) -> BoxFuture<'_, crate::Result<Vec<Position>>> {
+        ServiceExt::<FetchPositions>::oneshot(self.as_service(), FetchPositions::new())
+            .try_flatten()
+            .boxed()
+    }
+
+    fn fetch_position(&mut self, inst: &str) -> BoxFuture<'_, crate::Result<Vec<Position>>> {
+        ServiceExt::<FetchPositions>::oneshot(
+            self.as_service(),
+            FetchPositions::with_instrument(inst),
+        )
+        .try_flatten()
+        .boxed()
+    }
+}
+
+/// Subscribe positions service.
+pub trait SubscribePositionsService {
+    /// Subscribe to the position updates of the account.
+    fn subscribe_positions(&mut self) -> BoxFuture<'_, crate::Result<PositionStream>>;
+}
+
+impl<S> SubscribePositionsService for S
+where
+    S: ExcService<SubscribePositions> + Send,
+    S::Future: Send,
+{
+    fn subscribe_positions(&mut self) -> BoxFuture<'_, crate::Result<PositionStream>> {
+        ServiceExt::<SubscribePositions>::oneshot(self.as_service(), SubscribePositions).boxed()
+    }
+}
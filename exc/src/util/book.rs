@@ -1,9 +1,9 @@
-use exc_core::types::BidAskStream;
+use exc_core::types::{BidAskStream, OrderBookStream};
 use futures::future::BoxFuture;
-use futures::FutureExt;
+use futures::{FutureExt, StreamExt, TryFutureExt};
 use tower::ServiceExt;
 
-use crate::core::types::SubscribeBidAsk;
+use crate::core::types::{SubscribeBidAsk, SubscribeOrderBook};
 
 use crate::ExcService;
 
@@ -24,6 +24,47 @@ where
     }
 }
 
+/// Subscribe order book service.
+pub trait SubscribeOrderBookService {
+    /// Subscribe to the order book with the given depth (the full book if `None`).
+    fn subscribe_order_book(
+        &mut self,
+        inst: &str,
+        depth: Option<usize>,
+    ) -> BoxFuture<'_, crate::Result<OrderBookStream>>;
+}
+
+impl<S> SubscribeOrderBookService for S
+where
+    S: ExcService<SubscribeOrderBook> + Send,
+    S::Future: Send,
+{
+    fn subscribe_order_book(
+        &mut self,
+        inst: &str,
+        depth: Option<usize>,
+    ) -> BoxFuture<'_, crate::Result<OrderBookStream>> {
+        let req = SubscribeOrderBook {
+            instrument: inst.into(),
+            depth,
+        };
+        ServiceExt::oneshot(self.as_service(), req)
+            .map_ok(move |books| {
+                books
+                    .map(move |book| {
+                        book.map(|mut book| {
+                            if let Some(depth) = depth {
+                                book.truncate(depth);
+                            }
+                            book
+                        })
+                    })
+                    .boxed()
+            })
+            .boxed()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
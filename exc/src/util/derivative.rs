@@ -0,0 +1,184 @@
+use exc_core::types::{
+    FetchFundingRateHistory, FetchOpenInterestHistory, FundingRate, FundingRateStream,
+    IndexPriceStream, LiquidationStream, MarkPriceStream, OpenInterest, OpenInterestStream, Period,
+    SubscribeFundingRate, SubscribeIndexPrice, SubscribeLiquidations, SubscribeMarkPrice,
+    SubscribeOpenInterest,
+};
+use futures::{future::BoxFuture, FutureExt, TryFutureExt};
+use std::ops::Bound;
+use time::OffsetDateTime;
+use tower::ServiceExt;
+
+use crate::ExcService;
+
+/// Subscribe mark price service.
+pub trait SubscribeMarkPriceService {
+    /// Subscribe the mark price.
+    fn subscribe_mark_price(&mut self, inst: &str)
+        -> BoxFuture<'_, crate::Result<MarkPriceStream>>;
+}
+
+impl<S> SubscribeMarkPriceService for S
+where
+    S: ExcService<SubscribeMarkPrice> + Send,
+    S::Future: Send,
+{
+    fn subscribe_mark_price(
+        &mut self,
+        inst: &str,
+    ) -> BoxFuture<'_, crate::Result<MarkPriceStream>> {
+        ServiceExt::oneshot(self.as_service(), SubscribeMarkPrice::new(inst)).boxed()
+    }
+}
+
+/// Subscribe index price service.
+pub trait SubscribeIndexPriceService {
+    /// Subscribe the index price.
+    fn subscribe_index_price(
+        &mut self,
+        inst: &str,
+    ) -> BoxFuture<'_, crate::Result<IndexPriceStream>>;
+}
+
+impl<S> SubscribeIndexPriceService for S
+where
+    S: ExcService<SubscribeIndexPrice> + Send,
+    S::Future: Send,
+{
+    fn subscribe_index_price(
+        &mut self,
+        inst: &str,
+    ) -> BoxFuture<'_, crate::Result<IndexPriceStream>> {
+        ServiceExt::oneshot(self.as_service(), SubscribeIndexPrice::new(inst)).boxed()
+    }
+}
+
+/// Subscribe funding rate service.
+pub trait SubscribeFundingRateService {
+    /// Subscribe the funding rate.
+    fn subscribe_funding_rate(
+        &mut self,
+        inst: &str,
+    ) -> BoxFuture<'_, crate::Result<FundingRateStream>>;
+}
+
+impl<S> SubscribeFundingRateService for S
+where
+    S: ExcService<SubscribeFundingRate> + Send,
+    S::Future: Send,
+{
+    fn subscribe_funding_rate(
+        &mut self,
+        inst: &str,
+    ) -> BoxFuture<'_, crate::Result<FundingRateStream>> {
+        ServiceExt::oneshot(self.as_service(), SubscribeFundingRate::new(inst)).boxed()
+    }
+}
+
+/// Fetch funding rate history service.
+pub trait FetchFundingRateHistoryService {
+    /// Fetch the funding rates settled in range, newest first.
+    fn fetch_funding_rate_history(
+        &mut self,
+        inst: &str,
+        start: Bound<OffsetDateTime>,
+        end: Bound<OffsetDateTime>,
+    ) -> BoxFuture<'_, crate::Result<Vec<FundingRate>>>;
+}
+
+impl<S> FetchFundingRateHistoryService for S
+where
+    S: ExcService<FetchFundingRateHistory> + Send,
+    S::Future: Send,
+{
+    fn fetch_funding_rate_history(
+        &mut self,
+        inst: &str,
+        start: Bound<OffsetDateTime>,
+        end: Bound<OffsetDateTime>,
+    ) -> BoxFuture<'_, crate::Result<Vec<FundingRate>>> {
+        ServiceExt::oneshot(
+            self.as_service(),
+            FetchFundingRateHistory::new(inst, (start, end)),
+        )
+        .and_then(|fut| fut)
+        .boxed()
+    }
+}
+
+/// Subscribe open interest service.
+pub trait SubscribeOpenInterestService {
+    /// Subscribe the open interest.
+    fn subscribe_open_interest(
+        &mut self,
+        inst: &str,
+    ) -> BoxFuture<'_, crate::Result<OpenInterestStream>>;
+}
+
+impl<S> SubscribeOpenInterestService for S
+where
+    S: ExcService<SubscribeOpenInterest> + Send,
+    S::Future: Send,
+{
+    fn subscribe_open_interest(
+        &mut self,
+        inst: &str,
+    ) -> BoxFuture<'_, crate::Result<OpenInterestStream>> {
+        ServiceExt::oneshot(self.as_service(), SubscribeOpenInterest::new(inst)).boxed()
+    }
+}
+
+/// Fetch open interest history service.
+pub trait FetchOpenInterestHistoryService {
+    /// Fetch the open interests of the given period in range, newest first.
+    fn fetch_open_interest_history(
+        &mut self,
+        inst: &str,
+        period: Period,
+        start: Bound<OffsetDateTime>,
+        end: Bound<OffsetDateTime>,
+    ) -> BoxFuture<'_, crate::Result<Vec<OpenInterest>>>;
+}
+
+impl<S> FetchOpenInterestHistoryService for S
+where
+    S: ExcService<FetchOpenInterestHistory> + Send,
+    S::Future: Send,
+{
+    fn fetch_open_interest_history(
+        &mut self,
+        inst: &str,
+        period: Period,
+        start: Bound<OffsetDateTime>,
+        end: Bound<OffsetDateTime>,
+    ) -> BoxFuture<'_, crate::Result<Vec<OpenInterest>>> {
+        ServiceExt::oneshot(
+            self.as_service(),
+            FetchOpenInterestHistory::new(inst, period, (start, end)),
+        )
+        .and_then(|fut| fut)
+        .boxed()
+    }
+}
+
+/// Subscribe liquidations service.
+pub trait SubscribeLiquidationsService {
+    /// Subscribe the liquidations.
+    fn subscribe_liquidations(
+        &mut self,
+        inst: &str,
+    ) -> BoxFuture<'_, crate::Result<LiquidationStream>>;
+}
+
+impl<S> SubscribeLiquidationsService for S
+where
+    S: ExcService<SubscribeLiquidations> + Send,
+    S::Future: Send,
+{
+    fn subscribe_liquidations(
+        &mut self,
+        inst: &str,
+    ) -> BoxFuture<'_, crate::Result<LiquidationStream>> {
+        ServiceExt::oneshot(self.as_service(), SubscribeLiquidations::new(inst)).boxed()
+    }
+}
@@ -7,21 +7,38 @@ pub mod trade;
 /// Book.
 pub mod book;
 
+/// Derivative.
+pub mod derivative;
+
 /// Subscribe instruments.
 pub mod instrument;
 
 /// Fetch candles.
 pub mod fetch_candles;
 
+/// Subscribe candles.
+pub mod subscribe_candles;
+
 /// Trading.
 pub mod trading;
 
 /// Reconnect.
 pub mod reconnect;
 
+/// Account.
+pub mod account;
+
 use exc_core::{
-    types::{SubscribeBidAsk, SubscribeTickers, SubscribeTrades},
-    util::trade_bid_ask::{TradeBidAsk, TradeBidAskLayer},
+    types::{
+        Period, QueryCandles, SubscribeBidAsk, SubscribeCandles, SubscribeOrderBook,
+        SubscribeTickers, SubscribeTrades,
+    },
+    util::{
+        resample_candles::{ResampleCandles, ResampleCandlesLayer},
+        resync_order_book::{ResyncOrderBook, ResyncOrderBookLayer},
+        trade_bid_ask::{TradeBidAsk, TradeBidAskLayer},
+        trades_to_candles::{TradesToCandles, TradesToCandlesLayer},
+    },
     Adaptor, Exc, ExcService, Request,
 };
 use tower::Layer;
@@ -32,7 +49,19 @@ use self::fetch_candles::{
 };
 
 #[cfg(feature = "fetch-candles")]
-use exc_core::types::{QueryCandles, QueryFirstCandles, QueryLastCandles};
+use exc_core::types::{QueryFirstCandles, QueryLastCandles};
+
+#[cfg(feature = "fetch-fills")]
+use exc_core::{
+    types::{FetchFills, FetchLastFills},
+    util::fetch_fills::{FetchFillsBackward, FetchFillsBackwardLayer},
+};
+
+#[cfg(feature = "fetch-orders")]
+use exc_core::{
+    types::{FetchLastOrders, FetchOrderHistory},
+    util::fetch_orders::{FetchOrdersBackward, FetchOrdersBackwardLayer},
+};
 
 pub use exc_core::util::*;
 
@@ -71,6 +100,74 @@ where
         )
     }
 
+    /// Convert into a [`SubscribeCandlesService`](crate::SubscribeCandlesService)
+    /// that aggregates the trades into candles of any period.
+    fn into_subscribe_candles_from_trades(
+        self,
+    ) -> Exc<TradesToCandles<Exc<C, Req>>, SubscribeCandles>
+    where
+        C: Send,
+        C::Future: Send + 'static,
+        Req: Adaptor<SubscribeTrades> + 'static,
+    {
+        Exc::new(TradesToCandlesLayer::default().layer(self.into_exc()))
+    }
+
+    /// Convert into a service that answers [`QueryCandles`] of any multiple of the `base` period
+    /// by resampling the candles of the `base` period.
+    fn into_resample_candles(self, base: Period) -> Exc<ResampleCandles<Exc<C, Req>>, QueryCandles>
+    where
+        C: Send,
+        C::Future: Send + 'static,
+        Req: Adaptor<QueryCandles> + 'static,
+    {
+        Exc::new(ResampleCandlesLayer::new(base).layer(self.into_exc()))
+    }
+
+    /// Convert into a [`SubscribeOrderBookService`](crate::SubscribeOrderBookService)
+    /// that resubscribes when the order book is out of sync.
+    fn into_subscribe_order_book(self) -> Exc<ResyncOrderBook<Exc<C, Req>>, SubscribeOrderBook>
+    where
+        C: Clone + Send + 'static,
+        C::Future: Send + 'static,
+        Req: Adaptor<SubscribeOrderBook> + 'static,
+    {
+        Exc::new(ResyncOrderBookLayer::default().layer(self.into_exc()))
+    }
+
+    /// Convert into a service that places orders idempotently
+    /// by assigning client ids and recovering the ambiguous placements.
+    #[cfg(feature = "idempotent-place")]
+    fn into_idempotent_place(
+        self,
+    ) -> Exc<
+        exc_core::util::idempotent_place::IdempotentPlace<Exc<C, Req>>,
+        exc_core::types::PlaceOrder,
+    >
+    where
+        C: Clone + Send + 'static,
+        C::Future: Send + 'static,
+        Req: Adaptor<exc_core::types::PlaceOrder> + Adaptor<exc_core::types::GetOrder> + 'static,
+    {
+        use exc_core::util::idempotent_place::IdempotentPlaceLayer;
+        Exc::new(IdempotentPlaceLayer::default().layer(self.into_exc()))
+    }
+
+    /// Convert into a [`FetchCandlesService`](crate::FetchCandlesService)
+    /// that caches the closed candles under the `root` directory.
+    #[cfg(feature = "candle-cache")]
+    fn into_candle_cache(
+        self,
+        root: impl AsRef<std::path::Path>,
+    ) -> Exc<crate::candle_cache::CandleCache<Exc<C, Req>>, QueryCandles>
+    where
+        C: Send + 'static,
+        C::Future: Send + 'static,
+        Req: Adaptor<QueryCandles> + 'static,
+    {
+        Exc::new(crate::candle_cache::CandleCacheLayer::new(root).layer(self.into_exc()))
+    }
+
     /// Convert into a [`FetchCandlesService`](crate::FetchCandlesService)
     /// # Panic
     /// Panic if `limit` is zero.
@@ -136,6 +233,35 @@ where
     {
         Exc::new(FetchCandlesBackwardLayer::new(limit, bound).layer(self.into_exc()))
     }
+
+    /// Convert into a [`FetchOrderHistoryService`](crate::FetchOrderHistoryService)
+    /// # Panic
+    /// Panic if `limit` is zero.
+    #[cfg(feature = "fetch-orders")]
+    fn into_fetch_order_history(
+        self,
+        limit: usize,
+    ) -> Exc<FetchOrdersBackward<Exc<C, Req>>, FetchOrderHistory>
+    where
+        Req: Adaptor<FetchLastOrders>,
+        C: Send,
+        C::Future: Send,
+    {
+        Exc::new(FetchOrdersBackwardLayer::with_default_bound(limit).layer(self.into_exc()))
+    }
+
+    /// Convert into a [`FetchFillsService`](crate::FetchFillsService)
+    /// # Panic
+    /// Panic if `limit` is zero.
+    #[cfg(feature = "fetch-fills")]
+    fn into_fetch_fills(self, limit: usize) -> Exc<FetchFillsBackward<Exc<C, Req>>, FetchFills>
+    where
+        Req: Adaptor<FetchLastFills>,
+        C: Send,
+        C::Future: Send,
+    {
+        Exc::new(FetchFillsBackwardLayer::with_default_bound(limit).layer(self.into_exc()))
+    }
 }
 
 impl<C, Req> ExcExt<C, Req> for Exc<C, Req>
@@ -0,0 +1,38 @@
+use futures::{future::BoxFuture, FutureExt};
+use tower::ServiceExt;
+
+use crate::{
+    core::types::{
+        candle::{CandleUpdateStream, SubscribeCandles},
+        Period,
+    },
+    ExcService,
+};
+
+/// Subscribe candles service.
+pub trait SubscribeCandlesService {
+    /// Subscribe candles.
+    fn subscribe_candles(
+        &mut self,
+        inst: &str,
+        period: Period,
+    ) -> BoxFuture<'_, crate::Result<CandleUpdateStream>>;
+}
+
+impl<S> SubscribeCandlesService for S
+where
+    S: ExcService<SubscribeCandles> + Send,
+    S::Future: Send,
+{
+    fn subscribe_candles(
+        &mut self,
+        inst: &str,
+        period: Period,
+    ) -> BoxFuture<'_, crate::Result<CandleUpdateStream>> {
+        ServiceExt::<SubscribeCandles>::oneshot(
+            self.as_service(),
+            SubscribeCandles::new(inst, period),
+        )
+        .boxed()
+    }
+}
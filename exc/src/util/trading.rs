@@ -1,15 +1,26 @@
 use exc_core::{
     types::{
-        trading::PlaceOrderOptions, Canceled, OrderStream, OrderUpdate, Placed, SubscribeOrders,
+        trading::{
+            CancelAllOrders, CancelOrders, FetchFills, FetchOpenOrders, FetchOrderHistory,
+            PlaceOrderOptions, PlaceOrders,
+        },
+        Amended, Canceled, FillStream, OrderStream, OrderUpdate, Placed, SubscribeOrders,
     },
-    Str,
+    ExchangeError, Str,
 };
-use futures::{future::BoxFuture, FutureExt, TryFutureExt};
+use futures::{
+    future::{ready, BoxFuture},
+    stream::iter,
+    FutureExt, StreamExt, TryFutureExt,
+};
+use rust_decimal::Decimal;
+use std::ops::Bound;
+use time::OffsetDateTime;
 use tower::ServiceExt;
 
-use crate::core::types::trading::{CancelOrder, GetOrder, OrderId, Place, PlaceOrder};
+use crate::core::types::trading::{AmendOrder, CancelOrder, GetOrder, OrderId, Place, PlaceOrder};
 
-use crate::ExcService;
+use crate::{ExcService, Request};
 
 /// Trading service.
 pub trait TradingService {
@@ -35,13 +46,23 @@ pub trait TradingService {
 
     /// Cancel an order.
     fn cancel(&mut self, inst: &str, id: &OrderId) -> BoxFuture<'_, crate::Result<Canceled>>;
+
+    /// Amend the size and/or the price of an order.
+    fn amend(
+        &mut self,
+        inst: &str,
+        id: &OrderId,
+        new_size: Option<Decimal>,
+        new_price: Option<Decimal>,
+    ) -> BoxFuture<'_, crate::Result<Amended>>;
 }
 
 impl<S> TradingService for S
 where
-    S: ExcService<PlaceOrder> + ExcService<CancelOrder> + Send,
+    S: ExcService<PlaceOrder> + ExcService<CancelOrder> + ExcService<AmendOrder> + Send,
     <S as ExcService<PlaceOrder>>::Future: Send,
     <S as ExcService<CancelOrder>>::Future: Send,
+    <S as ExcService<AmendOrder>>::Future: Send,
 {
     /// Place an order with options.
     fn place_with_opts(
@@ -73,6 +94,138 @@ where
             .try_flatten()
             .boxed()
     }
+
+    /// Amend the size and/or the price of an order.
+    fn amend(
+        &mut self,
+        inst: &str,
+        id: &OrderId,
+        new_size: Option<Decimal>,
+        new_price: Option<Decimal>,
+    ) -> BoxFuture<'_, crate::Result<Amended>> {
+        ServiceExt::<AmendOrder>::oneshot(
+            self.as_service(),
+            AmendOrder::new(inst, id.clone(), new_size, new_price),
+        )
+        .try_flatten()
+        .boxed()
+    }
+}
+
+/// Batch trading service.
+///
+/// Batch requests that are not supported by the exchange
+/// (e.g. too many orders or unsupported markets) are
+/// automatically fanned out to single requests.
+pub trait BatchTradingService {
+    /// Place a batch of orders.
+    fn place_batch(
+        &mut self,
+        orders: Vec<PlaceOrder>,
+    ) -> BoxFuture<'_, crate::Result<Vec<crate::Result<Placed>>>>;
+
+    /// Cancel a batch of orders.
+    fn cancel_batch(
+        &mut self,
+        orders: Vec<CancelOrder>,
+    ) -> BoxFuture<'_, crate::Result<Vec<crate::Result<Canceled>>>>;
+}
+
+fn fan_out<S, R, T>(
+    svc: &mut S,
+    reqs: Vec<R>,
+) -> BoxFuture<'_, crate::Result<Vec<crate::Result<T>>>>
+where
+    S: ExcService<R> + Send,
+    S::Future: Send,
+    R: Request<Response = BoxFuture<'static, crate::Result<T>>> + Send + 'static,
+    T: Send + 'static,
+{
+    ServiceExt::<R>::call_all(svc.as_service(), iter(reqs))
+        .then(|res| ready(res.map_err(ExchangeError::layer)).try_flatten())
+        .collect()
+        .map(Ok)
+        .boxed()
+}
+
+impl<S> BatchTradingService for S
+where
+    S: ExcService<PlaceOrders> + ExcService<PlaceOrder> + Send,
+    S: ExcService<CancelOrders> + ExcService<CancelOrder>,
+    <S as ExcService<PlaceOrders>>::Future: Send,
+    <S as ExcService<PlaceOrder>>::Future: Send,
+    <S as ExcService<CancelOrders>>::Future: Send,
+    <S as ExcService<CancelOrder>>::Future: Send,
+{
+    fn place_batch(
+        &mut self,
+        orders: Vec<PlaceOrder>,
+    ) -> BoxFuture<'_, crate::Result<Vec<crate::Result<Placed>>>> {
+        async move {
+            if orders.is_empty() {
+                return Ok(Vec::new());
+            }
+            let res =
+                ServiceExt::<PlaceOrders>::oneshot(self.as_service(), PlaceOrders(orders.clone()))
+                    .try_flatten()
+                    .await;
+            match res {
+                Err(ExchangeError::Unsupported(err)) => {
+                    tracing::debug!(%err, "batch placement is unsupported; fan out");
+                    fan_out(self, orders).await
+                }
+                res => res,
+            }
+        }
+        .boxed()
+    }
+
+    fn cancel_batch(
+        &mut self,
+        orders: Vec<CancelOrder>,
+    ) -> BoxFuture<'_, crate::Result<Vec<crate::Result<Canceled>>>> {
+        async move {
+            if orders.is_empty() {
+                return Ok(Vec::new());
+            }
+            let res = ServiceExt::<CancelOrders>::oneshot(
+                self.as_service(),
+                CancelOrders(orders.clone()),
+            )
+            .try_flatten()
+            .await;
+            match res {
+                Err(ExchangeError::Unsupported(err)) => {
+                    tracing::debug!(%err, "batch cancellation is unsupported; fan out");
+                    fan_out(self, orders).await
+                }
+                res => res,
+            }
+        }
+        .boxed()
+    }
+}
+
+/// Cancel all orders service.
+pub trait CancelAllOrdersService {
+    /// Cancel all orders (of the given instrument).
+    fn cancel_all(&mut self, inst: Option<&str>) -> BoxFuture<'_, crate::Result<()>>;
+}
+
+impl<S> CancelAllOrdersService for S
+where
+    S: ExcService<CancelAllOrders> + Send,
+    S::Future: Send,
+{
+    fn cancel_all(&mut self, inst: Option<&str>) -> BoxFuture<'_, crate::Result<()>> {
+        let req = match inst {
+            Some(inst) => CancelAllOrders::with_instrument(inst),
+            None => CancelAllOrders::new(),
+        };
+        ServiceExt::<CancelAllOrders>::oneshot(self.as_service(), req)
+            .try_flatten()
+            .boxed()
+    }
 }
 
 /// Check order service.
@@ -116,6 +269,89 @@ where
     }
 }
 
+/// Fetch orders service.
+pub trait FetchOrdersService {
+    /// Fetch the open orders (of the given instrument).
+    fn fetch_open_orders(
+        &mut self,
+        inst: Option<&str>,
+    ) -> BoxFuture<'_, crate::Result<OrderStream>>;
+}
+
+impl<S> FetchOrdersService for S
+where
+    S: ExcService<FetchOpenOrders> + Send,
+    S::Future: Send,
+{
+    fn fetch_open_orders(
+        &mut self,
+        inst: Option<&str>,
+    ) -> BoxFuture<'_, crate::Result<OrderStream>> {
+        let req = match inst {
+            Some(inst) => FetchOpenOrders::with_instrument(inst),
+            None => FetchOpenOrders::new(),
+        };
+        ServiceExt::<FetchOpenOrders>::oneshot(self.as_service(), req).boxed()
+    }
+}
+
+/// Fetch order history service.
+pub trait FetchOrderHistoryService {
+    /// Fetch the orders created in range, backward.
+    fn fetch_order_history(
+        &mut self,
+        inst: &str,
+        start: Bound<OffsetDateTime>,
+        end: Bound<OffsetDateTime>,
+    ) -> BoxFuture<'_, crate::Result<OrderStream>>;
+}
+
+impl<S> FetchOrderHistoryService for S
+where
+    S: ExcService<FetchOrderHistory> + Send,
+    S::Future: Send,
+{
+    fn fetch_order_history(
+        &mut self,
+        inst: &str,
+        start: Bound<OffsetDateTime>,
+        end: Bound<OffsetDateTime>,
+    ) -> BoxFuture<'_, crate::Result<OrderStream>> {
+        ServiceExt::<FetchOrderHistory>::oneshot(
+            self.as_service(),
+            FetchOrderHistory::new(inst, (start, end)),
+        )
+        .boxed()
+    }
+}
+
+/// Fetch fills service.
+pub trait FetchFillsService {
+    /// Fetch the fills in range, backward.
+    fn fetch_fills(
+        &mut self,
+        inst: &str,
+        start: Bound<OffsetDateTime>,
+        end: Bound<OffsetDateTime>,
+    ) -> BoxFuture<'_, crate::Result<FillStream>>;
+}
+
+impl<S> FetchFillsService for S
+where
+    S: ExcService<FetchFills> + Send,
+    S::Future: Send,
+{
+    fn fetch_fills(
+        &mut self,
+        inst: &str,
+        start: Bound<OffsetDateTime>,
+        end: Bound<OffsetDateTime>,
+    ) -> BoxFuture<'_, crate::Result<FillStream>> {
+        ServiceExt::<FetchFills>::oneshot(self.as_service(), FetchFills::new(inst, (start, end)))
+            .boxed()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -135,6 +371,21 @@ mod tests {
         std::hint::black_box(boxed_trading(okx));
     }
 
+    #[allow(dead_code)]
+    fn boxed_batch_trading<'a, S>(svc: S) -> Box<dyn BatchTradingService + 'a>
+    where
+        S: BatchTradingService + 'a,
+    {
+        Box::new(svc)
+    }
+
+    #[cfg(feature = "okx")]
+    #[tokio::test]
+    async fn test_box_batch_trading() {
+        let okx = crate::Okx::endpoint().connect_exc();
+        std::hint::black_box(boxed_batch_trading(okx));
+    }
+
     #[allow(dead_code)]
     fn boxed_check_order<'a, S>(svc: S) -> Box<dyn CheckOrderService + 'a>
     where